@@ -0,0 +1,398 @@
+use crate::search_tree::{self, Entry, SearchTree};
+use std::cmp::Ordering;
+use std::ops::RangeBounds;
+
+/// An ordered map kept balanced as an AVL tree.
+///
+/// Unlike [`BinaryTree`](crate::binary_tree::BinaryTree), which keeps the
+/// shape given by the order of the insertions, the `AvlTree` rotates nodes
+/// after every insertion and removal so that the heights of the two subtrees
+/// of any node differ by at most one. Its height stays below `1.45 log2(n)`,
+/// even for sorted insertions, so every lookup and update is `O(log n)`.
+///
+/// Each node caches its height, which the rotations keep up to date on the
+/// way back up from a write.
+///
+/// # Type Parameters
+/// - `K`: The type of the keys, which order the entries.
+/// - `V`: The type of the values.
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::avl_tree::AvlTree;
+///
+/// let mut tree = AvlTree::new();
+/// for i in 0..1000 {
+///     tree.insert(i, i * i);
+/// }
+/// assert_eq!(tree.height(), 10);
+/// assert_eq!(tree.get(&12), Some(&144));
+///
+/// assert_eq!(tree.remove(&12), Some(144));
+/// let keys: Vec<_> = tree.range(10..15).map(|(key, _)| *key).collect();
+/// assert_eq!(keys, vec![10, 11, 13, 14]);
+/// ```
+pub struct AvlTree<K, V> {
+    nodes: SearchTree<K, V, usize>,
+}
+
+/// A node of the [`AvlTree`], whose `meta` is the number of nodes on the
+/// longest path down to a leaf, `1` for a leaf.
+type Node<K, V> = search_tree::Node<K, V, usize>;
+
+/// Borrowing in-order iterator over the entries of an [`AvlTree`] within a
+/// range, from the smallest key to the greatest one.
+pub struct Range<'a, K, V>(search_tree::Range<'a, K, V, usize>);
+
+impl<K, V> Node<K, V> {
+    /// Gets the height of a subtree, `0` if it is empty.
+    unsafe fn height(node: Option<*mut Node<K, V>>) -> usize {
+        node.map_or(0, |node| (*node).meta)
+    }
+
+    /// Recomputes the height of `node` from the heights of its children.
+    unsafe fn update_height(node: *mut Node<K, V>) {
+        (*node).meta = 1 + Self::height((*node).left).max(Self::height((*node).right));
+    }
+
+    /// Gets how much taller the left subtree of `node` is than its right one.
+    unsafe fn balance(node: *mut Node<K, V>) -> isize {
+        Self::height((*node).left) as isize - Self::height((*node).right) as isize
+    }
+}
+
+impl<K: Ord, V> AvlTree<K, V> {
+    /// Creates a new empty `AvlTree`.
+    pub fn new() -> AvlTree<K, V> {
+        AvlTree {
+            nodes: SearchTree::new(),
+        }
+    }
+
+    /// Gets the amount of entries in the tree.
+    pub fn len(&self) -> usize {
+        self.nodes.length
+    }
+
+    /// Checks if the tree is empty.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.length == 0
+    }
+
+    /// Gets the height of the tree, the number of nodes on its longest path
+    /// from the root down to a leaf, `0` if it is empty.
+    ///
+    /// # Complexity
+    /// - `O(1)`, it is cached in the root
+    pub fn height(&self) -> usize {
+        unsafe { Node::height(self.nodes.root) }
+    }
+
+    /// Retrieves a reference to the value of `key`, if it is in the tree.
+    ///
+    /// # Complexity
+    /// - `O(log n)`
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.nodes
+            .find(key)
+            .map(|node| unsafe { &(*node).data.value })
+    }
+
+    /// Retrieves a mutable reference to the value of `key`, if it is in the tree.
+    ///
+    /// # Complexity
+    /// - `O(log n)`
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.nodes
+            .find(key)
+            .map(|node| unsafe { &mut (*node).data.value })
+    }
+
+    /// Checks if `key` is in the tree.
+    ///
+    /// # Complexity
+    /// - `O(log n)`
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.nodes.find(key).is_some()
+    }
+
+    /// Retrieves the entry with the smallest key, if the tree is not empty.
+    ///
+    /// # Complexity
+    /// - `O(log n)`
+    pub fn first_key_value(&self) -> Option<(&K, &V)> {
+        self.nodes
+            .first()
+            .map(|node| unsafe { (*node).data.pair() })
+    }
+
+    /// Retrieves the entry with the greatest key, if the tree is not empty.
+    ///
+    /// # Complexity
+    /// - `O(log n)`
+    pub fn last_key_value(&self) -> Option<(&K, &V)> {
+        self.nodes.last().map(|node| unsafe { (*node).data.pair() })
+    }
+
+    /// Inserts `value` under `key`, rebalancing the tree on the way back up.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(V)`: The value replaced, if `key` was already in the tree.
+    ///   The key itself is not replaced.
+    /// - `None`: If `key` was inserted.
+    ///
+    /// # Complexity
+    /// - `O(log n)`, with at most two rotations
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let place = self.nodes.search(&key);
+        unsafe {
+            if let Some((node, Ordering::Equal)) = place {
+                return Some(std::mem::replace(&mut (*node).data.value, value));
+            }
+            let node = self.nodes.link_leaf(place, key, value, 1);
+            self.retrace((*node).parent);
+        }
+        None
+    }
+
+    /// Removes `key` from the tree, rebalancing it on the way back up.
+    ///
+    /// When `key` has two children, its entry trades places with the one of
+    /// its successor, and the rebalancing starts above the successor's node.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(V)`: The value of the removed entry.
+    /// - `None`: If `key` was not in the tree.
+    ///
+    /// # Complexity
+    /// - `O(log n)`, with at most one or two rotations per level
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let node = self.nodes.find(key)?;
+        unsafe {
+            let (node, _, parent) = self.nodes.unlink(node);
+            self.retrace(parent);
+            Some(node.data.value)
+        }
+    }
+
+    /// Returns an in-order iterator over the entries with a key within
+    /// `range`, from the smallest key to the greatest one.
+    ///
+    /// A range whose start is past its end is empty.
+    ///
+    /// # Complexity
+    /// - `O(log n)` to find the range, then `O(m)` for the whole traversal,
+    ///   where `m` is the number of entries in the range
+    pub fn range<R: RangeBounds<K>>(&self, range: R) -> Range<'_, K, V> {
+        Range(self.nodes.range(range))
+    }
+
+    /// Returns an in-order iterator over every entry of the tree.
+    pub fn iter(&self) -> Range<'_, K, V> {
+        self.range(..)
+    }
+
+    /// Rotates `node` down to the left and returns the child lifted into its
+    /// place, updating the heights of both.
+    unsafe fn rotate_left(&mut self, node: *mut Node<K, V>) -> *mut Node<K, V> {
+        let right = self.nodes.rotate_left(node);
+        Node::update_height(node);
+        Node::update_height(right);
+        right
+    }
+
+    /// Rotates `node` down to the right and returns the child lifted into its
+    /// place, updating the heights of both.
+    unsafe fn rotate_right(&mut self, node: *mut Node<K, V>) -> *mut Node<K, V> {
+        let left = self.nodes.rotate_right(node);
+        Node::update_height(node);
+        Node::update_height(left);
+        left
+    }
+
+    /// Restores the balance of `node`, whose subtrees may differ in height
+    /// by two, and returns the root of its subtree afterwards.
+    unsafe fn rebalance(&mut self, node: *mut Node<K, V>) -> *mut Node<K, V> {
+        Node::update_height(node);
+        let balance = Node::balance(node);
+        if balance > 1 {
+            let left = (*node).left.expect("a taller left subtree is not empty");
+            // Left-right case: first turn it into a left-left case
+            if Node::balance(left) < 0 {
+                self.rotate_left(left);
+            }
+            return self.rotate_right(node);
+        }
+        if balance < -1 {
+            let right = (*node).right.expect("a taller right subtree is not empty");
+            // Right-left case: first turn it into a right-right case
+            if Node::balance(right) > 0 {
+                self.rotate_right(right);
+            }
+            return self.rotate_left(node);
+        }
+        node
+    }
+
+    /// Rebalances every node from `node` up to the root after a subtree of
+    /// `node` grew or shrank.
+    unsafe fn retrace(&mut self, mut node: Option<*mut Node<K, V>>) {
+        while let Some(current) = node {
+            node = (*self.rebalance(current)).parent;
+        }
+    }
+}
+
+impl<K: Ord, V> Default for AvlTree<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for AvlTree<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut tree = AvlTree::new();
+        for (key, value) in iter {
+            tree.insert(key, value);
+        }
+        tree
+    }
+}
+
+impl<'a, K: Ord, V> IntoIterator for &'a AvlTree<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Range<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V> Iterator for Range<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(Entry::pair)
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Range<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(Entry::pair)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search_tree::tests::{self as shared, TestMap};
+
+    impl TestMap for AvlTree<i32, i32> {
+        fn insert(&mut self, key: i32, value: i32) -> Option<i32> {
+            AvlTree::insert(self, key, value)
+        }
+
+        fn remove(&mut self, key: i32) -> Option<i32> {
+            AvlTree::remove(self, &key)
+        }
+
+        fn get(&mut self, key: i32) -> Option<i32> {
+            AvlTree::get(self, &key).copied()
+        }
+
+        fn first_and_last(&mut self) -> Option<(i32, i32)> {
+            Some((*self.first_key_value()?.0, *self.last_key_value()?.0))
+        }
+
+        fn len(&self) -> usize {
+            AvlTree::len(self)
+        }
+
+        fn check(&self) -> Vec<i32> {
+            assert_valid(self)
+        }
+    }
+
+    /// Checks the links, the cached heights and the AVL balance, and returns
+    /// the keys in order.
+    fn assert_valid<K: Ord + Copy, V>(tree: &AvlTree<K, V>) -> Vec<K> {
+        shared::assert_links(&tree.nodes, |node| unsafe {
+            let height = 1 + Node::height((*node).left).max(Node::height((*node).right));
+            assert_eq!((*node).meta, height);
+            assert!(Node::balance(node).abs() <= 1);
+        })
+    }
+
+    /// The largest height of an AVL tree with `length` nodes.
+    fn max_height(length: usize) -> usize {
+        (1.4405 * ((length + 2) as f64).log2() - 0.3277) as usize
+    }
+
+    fn root_key<V>(tree: &AvlTree<i32, V>) -> i32 {
+        unsafe { (*tree.nodes.root.unwrap()).data.key }
+    }
+
+    #[test]
+    fn test_rotation_cases() {
+        // Left-left, right-right, left-right and right-left all end up
+        // with the middle key at the root
+        for keys in [[3, 2, 1], [1, 2, 3], [3, 1, 2], [1, 3, 2]] {
+            let tree: AvlTree<i32, ()> = keys.into_iter().map(|key| (key, ())).collect();
+            assert_eq!(assert_valid(&tree), vec![1, 2, 3]);
+            assert_eq!(root_key(&tree), 2);
+            assert_eq!(tree.height(), 2);
+        }
+    }
+
+    #[test]
+    fn test_removal_rotations() {
+        // Removing 8 leaves 5 two levels heavier on the left, through the
+        // inner grandchild 3, which a double rotation lifts to the root
+        let mut tree: AvlTree<i32, ()> = [5, 2, 8, 3].into_iter().map(|key| (key, ())).collect();
+        tree.remove(&8);
+        assert_eq!(root_key(&tree), 3);
+        assert_eq!(assert_valid(&tree), vec![2, 3, 5]);
+
+        // The mirror case, and a single rotation for an outer grandchild
+        let mut tree: AvlTree<i32, ()> = [5, 2, 8, 7].into_iter().map(|key| (key, ())).collect();
+        tree.remove(&2);
+        assert_eq!(root_key(&tree), 7);
+        let mut tree: AvlTree<i32, ()> = [5, 2, 8, 9].into_iter().map(|key| (key, ())).collect();
+        tree.remove(&2);
+        assert_eq!(root_key(&tree), 8);
+        assert_eq!(assert_valid(&tree), vec![5, 8, 9]);
+    }
+
+    #[test]
+    fn test_map_operations() {
+        shared::check_replaced_values::<AvlTree<i32, i32>>();
+        shared::check_mixed_writes::<AvlTree<i32, i32>>();
+    }
+
+    #[test]
+    fn test_sorted_writes_stay_balanced() {
+        let tree: AvlTree<i32, i32> = shared::write_in_order(0..10_000, []);
+        assert_eq!(tree.height(), 14);
+        assert!(tree.height() <= max_height(10_000));
+
+        let tree: AvlTree<i32, i32> = shared::write_in_order(0..2048, 0..2000);
+        assert!(tree.height() <= max_height(48));
+        let tree: AvlTree<i32, i32> = shared::write_in_order((0..2048).rev(), (48..2048).rev());
+        assert!(tree.height() <= max_height(48));
+    }
+
+    #[test]
+    fn test_empty_tree() {
+        let mut tree: AvlTree<i32, i32> = AvlTree::new();
+        assert_eq!(tree.height(), 0);
+        assert_eq!(tree.remove(&1), None);
+        assert_eq!(tree.iter().next(), None);
+        tree.insert(1, 1);
+        tree.remove(&1);
+        assert_eq!(tree.height(), 0);
+        assert!(tree.is_empty());
+    }
+}
@@ -0,0 +1,128 @@
+use super::{BinaryTree, Node};
+use std::borrow::Borrow;
+use std::ops::Bound;
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Finds the node holding the smallest item within the lower `bound`.
+    ///
+    /// # Complexity
+    /// - `O(h)`, where `h` is the height of the tree
+    pub(super) fn lower_bound(&self, bound: Bound<&T>) -> Option<*mut Node<T>> {
+        unsafe { Node::lower_bound(self.root, bound) }
+    }
+
+    /// Finds the node holding the greatest item within the upper `bound`.
+    ///
+    /// # Complexity
+    /// - `O(h)`, where `h` is the height of the tree
+    pub(super) fn upper_bound(&self, bound: Bound<&T>) -> Option<*mut Node<T>> {
+        unsafe { Node::upper_bound(self.root, bound) }
+    }
+}
+
+impl<T, M> Node<T, M> {
+    /// Finds the node holding the smallest item within the lower `bound` in
+    /// the search tree rooted at `root`.
+    ///
+    /// Items are compared through [`Borrow`], so the nodes of a map can be
+    /// searched by key alone.
+    ///
+    /// # Safety
+    /// `root` and every node below it must be valid.
+    ///
+    /// # Complexity
+    /// - `O(h)`, where `h` is the height of the tree
+    pub(crate) unsafe fn lower_bound<Q>(
+        root: Option<*mut Node<T, M>>,
+        bound: Bound<&Q>,
+    ) -> Option<*mut Node<T, M>>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut found = None;
+        let mut cursor = root;
+        while let Some(node) = cursor {
+            let data = (*node).data.borrow();
+            let within = match bound {
+                Bound::Included(limit) => data >= limit,
+                Bound::Excluded(limit) => data > limit,
+                Bound::Unbounded => true,
+            };
+            // A node within the bound may still have a smaller one on its left
+            if within {
+                found = Some(node);
+                cursor = (*node).left;
+            } else {
+                cursor = (*node).right;
+            }
+        }
+        found
+    }
+
+    /// Finds the node holding the greatest item within the upper `bound` in
+    /// the search tree rooted at `root`, comparing like
+    /// [`lower_bound`](Self::lower_bound).
+    ///
+    /// # Safety
+    /// `root` and every node below it must be valid.
+    ///
+    /// # Complexity
+    /// - `O(h)`, where `h` is the height of the tree
+    pub(crate) unsafe fn upper_bound<Q>(
+        root: Option<*mut Node<T, M>>,
+        bound: Bound<&Q>,
+    ) -> Option<*mut Node<T, M>>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut found = None;
+        let mut cursor = root;
+        while let Some(node) = cursor {
+            let data = (*node).data.borrow();
+            let within = match bound {
+                Bound::Included(limit) => data <= limit,
+                Bound::Excluded(limit) => data < limit,
+                Bound::Unbounded => true,
+            };
+            // A node within the bound may still have a greater one on its right
+            if within {
+                found = Some(node);
+                cursor = (*node).right;
+            } else {
+                cursor = (*node).left;
+            }
+        }
+        found
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn data<T: Ord + Copy>(node: Option<*mut Node<T>>) -> Option<T> {
+        node.map(|node| unsafe { (*node).data })
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut tree = BinaryTree::new();
+        for i in [40, 20, 60, 10, 30, 50, 70] {
+            tree.insert(i);
+        }
+
+        assert_eq!(data(tree.lower_bound(Bound::Included(&30))), Some(30));
+        assert_eq!(data(tree.lower_bound(Bound::Excluded(&30))), Some(40));
+        assert_eq!(data(tree.lower_bound(Bound::Included(&31))), Some(40));
+        assert_eq!(data(tree.lower_bound(Bound::Excluded(&70))), None);
+        assert_eq!(data(tree.lower_bound(Bound::Unbounded)), Some(10));
+
+        assert_eq!(data(tree.upper_bound(Bound::Included(&30))), Some(30));
+        assert_eq!(data(tree.upper_bound(Bound::Excluded(&30))), Some(20));
+        assert_eq!(data(tree.upper_bound(Bound::Included(&49))), Some(40));
+        assert_eq!(data(tree.upper_bound(Bound::Excluded(&10))), None);
+        assert_eq!(data(tree.upper_bound(Bound::Unbounded)), Some(70));
+    }
+}
@@ -0,0 +1,49 @@
+use super::BinaryTree;
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Checks if the `BinaryTree` contains an item equal to `data`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// tree.insert(7);
+    ///
+    /// assert!(tree.contains(&7));
+    /// assert!(!tree.contains(&8));
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(h)`, where `h` is the height of the tree
+    /// - **Space Complexity:** `O(1)`
+    pub fn contains(&self, data: &T) -> bool {
+        self.find(data).is_some()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_contains() {
+        let mut tree = BinaryTree::new();
+        let values = [50, 30, 70, 20, 40, 60, 80];
+        for value in values {
+            tree.insert(value);
+        }
+        for value in values {
+            assert!(tree.contains(&value));
+        }
+        for value in [0, 25, 45, 65, 100] {
+            assert!(!tree.contains(&value));
+        }
+
+        tree.remove(&30);
+        assert!(!tree.contains(&30));
+        assert!(tree.contains(&20) && tree.contains(&40));
+    }
+}
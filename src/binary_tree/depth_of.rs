@@ -0,0 +1,90 @@
+use super::BinaryTree;
+use crate::queue::{ArrayQueue, Queue};
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Gets the depth of the node holding `data`, the number of edges from
+    /// the root down to it.
+    ///
+    /// The nodes are searched level by level instead of following the
+    /// ordering, so the depth is also found in trees that are not binary
+    /// search trees (see [`is_bst`](Self::is_bst)).
+    ///
+    /// # Returns
+    ///
+    /// - `Some(usize)`: The depth of the node, `0` for the root.
+    /// - `None`: If no node holds `data`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// for i in [2, 1, 3, 4] {
+    ///     tree.insert(i);
+    /// }
+    /// assert_eq!(tree.depth_of(&2), Some(0));
+    /// assert_eq!(tree.depth_of(&4), Some(2));
+    /// assert_eq!(tree.depth_of(&5), None);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(n)`
+    /// - **Space Complexity:** `O(w)`, where `w` is the width of the widest level
+    pub fn depth_of(&self, data: &T) -> Option<usize> {
+        let mut queue = ArrayQueue::new();
+        if let Some(root) = self.root {
+            queue.enqueue((root, 0));
+        }
+        while let Some((node, depth)) = queue.dequeue() {
+            unsafe {
+                if (*node).data == *data {
+                    return Some(depth);
+                }
+                for child in [(*node).left, (*node).right].into_iter().flatten() {
+                    queue.enqueue((child, depth + 1));
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_depth_of() {
+        let mut tree = BinaryTree::new();
+        assert_eq!(tree.depth_of(&1), None);
+
+        for i in [4, 2, 6, 1, 3, 5, 7] {
+            tree.insert(i);
+        }
+        let depths: Vec<_> = (1..=7).map(|i| tree.depth_of(&i)).collect();
+        assert_eq!(
+            depths,
+            vec![
+                Some(2),
+                Some(1),
+                Some(2),
+                Some(0),
+                Some(2),
+                Some(1),
+                Some(2)
+            ]
+        );
+        assert_eq!(tree.depth_of(&0), None);
+    }
+
+    #[test]
+    fn test_depth_of_in_degenerate_tree() {
+        let mut tree = BinaryTree::new();
+        for i in 0..10_000 {
+            tree.insert(i);
+        }
+        assert_eq!(tree.depth_of(&9_999), Some(9_999));
+    }
+}
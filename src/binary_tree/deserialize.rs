@@ -0,0 +1,218 @@
+use super::{quote, BinaryTree, Node};
+use crate::queue::{ArrayQueue, Queue};
+use std::str::FromStr;
+
+impl<T: std::cmp::Ord + FromStr> BinaryTree<T> {
+    /// Reads a `BinaryTree` in the level-order notation with explicit nulls
+    /// written by [`serialize`](Self::serialize), keeping its exact shape.
+    ///
+    /// The items are parsed with [`FromStr`] and spaces around them are
+    /// ignored. A quoted item is parsed without its quotes and the `\` before
+    /// the quoted characters, so `"null"` is an item and `null` is not.
+    /// Nulls after the last item may be left out or written.
+    ///
+    /// # Errors
+    ///
+    /// - `"Missing square brackets"`
+    /// - `"Invalid item"`: An item is empty, is badly quoted or could not be
+    ///   parsed.
+    /// - `"Item without a parent"`: An item comes after every slot for a
+    ///   child was taken.
+    /// - `"Items are not in order"`: The shape is not a binary search tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let tree = BinaryTree::<i32>::deserialize("[4,2,6,null,3]").unwrap();
+    /// assert_eq!(tree.pre_order().collect::<Vec<_>>(), vec![&4, &2, &3, &6]);
+    /// assert_eq!(tree.serialize(), "[4,2,6,null,3]");
+    ///
+    /// let error = BinaryTree::<i32>::deserialize("[4,6,2]");
+    /// assert_eq!(error.err(), Some("Items are not in order"));
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(n)`
+    /// - **Space Complexity:** `O(w)`, where `w` is the width of the widest level
+    pub fn deserialize(text: &str) -> Result<Self, &'static str> {
+        let inner = text
+            .trim()
+            .strip_prefix('[')
+            .and_then(|text| text.strip_suffix(']'))
+            .ok_or("Missing square brackets")?;
+
+        // Dropping the tree on an error frees the nodes linked so far
+        let mut tree = BinaryTree::new();
+        if inner.trim().is_empty() {
+            return Ok(tree);
+        }
+        let mut start = 0;
+        let ends = quote::symbols_outside_quotes(inner, &[','])
+            .map(|(position, _)| position)
+            .chain([inner.len()]);
+        let mut items = ends.map(|end| {
+            let item = inner[start..end].trim();
+            start = end + 1;
+            match item {
+                "null" => Ok(None),
+                "" => Err("Invalid item"),
+                item => quote::unquote(item)?
+                    .parse()
+                    .map(Some)
+                    .map_err(|_| "Invalid item"),
+            }
+        });
+
+        let mut parents = ArrayQueue::new();
+        if let Some(data) = items.next().transpose()?.flatten() {
+            let root = Box::into_raw(Box::new(Node::new(data, None)));
+            tree.root = Some(root);
+            tree.length = 1;
+            parents.enqueue(root);
+        }
+        while let Some(parent) = parents.dequeue() {
+            for goes_left in [true, false] {
+                let Some(item) = items.next() else {
+                    break;
+                };
+                let Some(data) = item? else {
+                    continue;
+                };
+                let child = Box::into_raw(Box::new(Node::new(data, Some(parent))));
+                unsafe {
+                    if goes_left {
+                        (*parent).left = Some(child);
+                    } else {
+                        (*parent).right = Some(child);
+                    }
+                }
+                tree.length += 1;
+                parents.enqueue(child);
+            }
+        }
+
+        for item in items {
+            if item?.is_some() {
+                return Err("Item without a parent");
+            }
+        }
+        if !tree.is_bst() {
+            return Err("Items are not in order");
+        }
+        Ok(tree)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let tree = BinaryTree::<i32>::deserialize("[]").unwrap();
+        assert!(tree.is_empty());
+        let tree = BinaryTree::<i32>::deserialize(" [ ] ").unwrap();
+        assert!(tree.is_empty());
+
+        let tree = BinaryTree::<i32>::deserialize("[4, 2, 6, 1, 3, 5, 7]").unwrap();
+        assert_eq!(tree.len(), 7);
+        assert!(tree.pre_order().copied().eq([4, 2, 1, 3, 6, 5, 7]));
+    }
+
+    #[test]
+    fn test_trailing_nulls() {
+        let tree = BinaryTree::<i32>::deserialize("[4,2,null,null,3,null,null]").unwrap();
+        assert_eq!(tree.serialize(), "[4,2,null,null,3]");
+        let tree = BinaryTree::<i32>::deserialize("[null]").unwrap();
+        assert!(tree.is_empty());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for text in [
+            "[]",
+            "[1]",
+            "[10,5,15,null,7,null,20,null,8,17]",
+            "[3,2,null,1]",
+            "[1,null,2,null,3,null,4]",
+            "[50,20,80,10,30,70,90,null,null,25,35,null,75]",
+        ] {
+            let tree = BinaryTree::<i32>::deserialize(text).unwrap();
+            assert_eq!(tree.serialize(), text);
+            assert_eq!(tree.size(), tree.len());
+        }
+    }
+
+    #[test]
+    fn test_round_trip_of_strings() {
+        let mut tree = BinaryTree::new();
+        for word in [
+            "null",
+            "",
+            "a,b",
+            "(x)",
+            " padded ",
+            "say \"hi\"",
+            "[]",
+            "back\\slash",
+            "plain",
+        ] {
+            tree.insert(word.to_string());
+        }
+        let text = tree.serialize();
+        assert_eq!(
+            text,
+            r#"["null","","say \"hi\"",null,"a,b",plain,null,"(x)",back\slash,null,null," padded ","[]"]"#
+        );
+
+        let copy = BinaryTree::<String>::deserialize(&text).unwrap();
+        assert!(copy.level_order().eq(tree.level_order()));
+        assert_eq!(copy.serialize(), text);
+
+        let tree = BinaryTree::<String>::deserialize(r#"[ "null" , null, "z z "]"#).unwrap();
+        assert!(tree.level_order().eq(["null", "z z "]));
+        assert_eq!(
+            BinaryTree::<String>::deserialize(r#"["a]"#).err(),
+            Some("Invalid item")
+        );
+        assert_eq!(
+            BinaryTree::<String>::deserialize(r#"["a"b]"#).err(),
+            Some("Invalid item")
+        );
+        assert_eq!(
+            BinaryTree::<String>::deserialize("[a,,b]").err(),
+            Some("Invalid item")
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let parse = BinaryTree::<i32>::deserialize;
+        assert_eq!(parse("4,2").err(), Some("Missing square brackets"));
+        assert_eq!(parse("[4,2").err(), Some("Missing square brackets"));
+        assert_eq!(parse("[4,two]").err(), Some("Invalid item"));
+        assert_eq!(parse("[4,,6]").err(), Some("Invalid item"));
+        assert_eq!(parse("[null,4]").err(), Some("Item without a parent"));
+        assert_eq!(
+            parse("[4,null,null,5]").err(),
+            Some("Item without a parent")
+        );
+        assert_eq!(
+            parse("[4,2,6,null,5]").err(),
+            Some("Items are not in order")
+        );
+        assert_eq!(parse("[4,4]").err(), Some("Items are not in order"));
+    }
+
+    #[test]
+    fn test_round_trip_of_degenerate_tree() {
+        let tree = BinaryTree::from_pre_order((0..10_000).collect()).unwrap();
+        let text = tree.serialize();
+        let copy = BinaryTree::<i32>::deserialize(&text).unwrap();
+        assert_eq!(copy.height(), 10_000);
+        assert_eq!(copy.serialize(), text);
+    }
+}
@@ -0,0 +1,81 @@
+use super::BinaryTree;
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Gets the diameter of the `BinaryTree`, the number of edges on the
+    /// longest path between any two nodes.
+    ///
+    /// The path does not need to go through the root.
+    ///
+    /// # Returns
+    ///
+    /// - `0` for an empty tree or a tree with only a root.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// for i in [2, 1, 3, 4] {
+    ///     tree.insert(i);
+    /// }
+    /// // From 1 up to 2 and down to 4
+    /// assert_eq!(tree.diameter(), 3);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(n)`
+    /// - **Space Complexity:** `O(h)`, where `h` is the height of the tree
+    pub fn diameter(&self) -> usize {
+        // Each subtree gives its height and the longest path inside it
+        self.fold_post_order(|_, left: Option<(usize, usize)>, right| {
+            let (left_height, left_diameter) = left.unwrap_or((0, 0));
+            let (right_height, right_diameter) = right.unwrap_or((0, 0));
+            let through = left_height + right_height;
+            (
+                1 + left_height.max(right_height),
+                through.max(left_diameter).max(right_diameter),
+            )
+        })
+        .map_or(0, |(_, diameter)| diameter)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_diameter_through_root() {
+        let mut tree = BinaryTree::new();
+        assert_eq!(tree.diameter(), 0);
+        tree.insert(4);
+        assert_eq!(tree.diameter(), 0);
+
+        for i in [2, 6, 1, 3, 5, 7] {
+            tree.insert(i);
+        }
+        assert_eq!(tree.diameter(), 4);
+    }
+
+    #[test]
+    fn test_diameter_below_root() {
+        let mut tree = BinaryTree::new();
+        // The root only has a left subtree, whose longest path runs
+        // from 1 up to 10 and down to 17
+        for i in [100, 10, 5, 3, 1, 15, 20, 17] {
+            tree.insert(i);
+        }
+        assert_eq!(tree.diameter(), 6);
+    }
+
+    #[test]
+    fn test_diameter_of_degenerate_tree() {
+        let mut tree = BinaryTree::new();
+        for i in 0..10_000 {
+            tree.insert(i);
+        }
+        assert_eq!(tree.diameter(), 9_999);
+    }
+}
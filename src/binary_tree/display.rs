@@ -0,0 +1,91 @@
+use super::{quote, BinaryTree, Node};
+use std::fmt;
+
+/// What is left to write for the parenthesized pre-order notation.
+enum Step<T: std::cmp::Ord> {
+    Node(*mut Node<T>),
+    Text(&'static str),
+}
+
+impl<T: std::cmp::Ord + fmt::Display> fmt::Display for BinaryTree<T> {
+    /// Writes the shape of the `BinaryTree` in parenthesized pre-order
+    /// notation, which [`FromStr`](std::str::FromStr) reads back.
+    ///
+    /// Each item is followed by its children as `(left,right)`, leaving out
+    /// a missing child and the parentheses of a leaf. The empty tree is
+    /// written as an empty string.
+    ///
+    /// Items are quoted like in [`serialize`](BinaryTree::serialize) when
+    /// they would be read back as something else.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// for i in [4, 2, 6, 3] {
+    ///     tree.insert(i);
+    /// }
+    /// assert_eq!(tree.to_string(), "4(2(,3),6)");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The steps wait on a stack instead of the call stack
+        let mut steps: Vec<Step<T>> = self.root.map(Step::Node).into_iter().collect();
+        while let Some(step) = steps.pop() {
+            let node = match step {
+                Step::Text(text) => {
+                    f.write_str(text)?;
+                    continue;
+                }
+                Step::Node(node) => node,
+            };
+            unsafe {
+                quote::write_item(f, &(*node).data.to_string())?;
+                if (*node).left.is_none() && (*node).right.is_none() {
+                    continue;
+                }
+                // Pushed backward so they are written forward
+                steps.push(Step::Text(")"));
+                steps.extend((*node).right.map(Step::Node));
+                steps.push(Step::Text(","));
+                steps.extend((*node).left.map(Step::Node));
+                steps.push(Step::Text("("));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let mut tree = BinaryTree::new();
+        assert_eq!(tree.to_string(), "");
+        tree.insert(4);
+        assert_eq!(tree.to_string(), "4");
+
+        for i in [2, 6, 1, 3, 5, 7] {
+            tree.insert(i);
+        }
+        assert_eq!(tree.to_string(), "4(2(1,3),6(5,7))");
+    }
+
+    #[test]
+    fn test_display_unbalanced_shapes() {
+        let mut tree = BinaryTree::new();
+        for i in [10, 5, 7, 8, 15, 20, 17] {
+            tree.insert(i);
+        }
+        assert_eq!(tree.to_string(), "10(5(,7(,8)),15(,20(17,)))");
+
+        let mut words = BinaryTree::new();
+        for word in ["pear", "apple", "plum"] {
+            words.insert(word);
+        }
+        assert_eq!(words.to_string(), "pear(apple,plum)");
+    }
+}
@@ -0,0 +1,69 @@
+use super::{BinaryTree, Node};
+
+impl<T: std::cmp::Ord> Drop for BinaryTree<T> {
+    fn drop(&mut self) {
+        unsafe { Node::free_subtree(self.root.take()) };
+        self.length = 0;
+    }
+}
+
+impl<T, M> Node<T, M> {
+    /// Deallocates `root` and every node below it.
+    ///
+    /// An explicit stack is used instead of recursion, so degenerate trees as
+    /// deep as they are long cannot overflow the call stack.
+    ///
+    /// # Safety
+    /// Every node of the subtree must come from `Box::into_raw` and must not
+    /// be used afterwards.
+    ///
+    /// # Complexity
+    /// - `O(n)`, where `n` is the size of the subtree
+    pub(crate) unsafe fn free_subtree(root: Option<*mut Node<T, M>>) {
+        let mut pending: Vec<_> = root.into_iter().collect();
+        while let Some(node) = pending.pop() {
+            // Deallocating memory from heap to avoid memory leakage
+            let node = Box::from_raw(node);
+            pending.extend(node.left);
+            pending.extend(node.right);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::binary_tree::Node;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_drop_frees_every_node() {
+        let item = Rc::new(());
+        // Wrapped in a tuple to get distinct, ordered items sharing the counter
+        let mut tree = BinaryTree::new();
+        for i in [4, 2, 6, 1, 3, 5, 7] {
+            tree.insert((i, Rc::clone(&item)));
+        }
+        assert_eq!(Rc::strong_count(&item), 8);
+
+        drop(tree);
+        assert_eq!(Rc::strong_count(&item), 1);
+    }
+
+    #[test]
+    fn test_drop_degenerate_tree() {
+        // Linked by hand, inserting sorted items would take quadratic time
+        let mut tree = BinaryTree::new();
+        let mut parent: Option<*mut Node<usize>> = None;
+        for i in 0..1_000_000 {
+            let node = Box::into_raw(Box::new(Node::new(i, parent)));
+            match parent {
+                None => tree.root = Some(node),
+                Some(parent) => unsafe { (*parent).right = Some(node) },
+            }
+            parent = Some(node);
+        }
+        tree.length = 1_000_000;
+        drop(tree);
+    }
+}
@@ -0,0 +1,57 @@
+use super::BinaryTree;
+use std::ops::Bound;
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Retrieves a reference to the greatest item of the `BinaryTree` that
+    /// is smaller than or equal to `data`.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(&T)`: `data` itself if it is in the tree, or the closest
+    ///   smaller item.
+    /// - `None`: If every item is greater than `data`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// for i in [20, 10, 30] {
+    ///     tree.insert(i);
+    /// }
+    ///
+    /// assert_eq!(tree.floor(&20), Some(&20));
+    /// assert_eq!(tree.floor(&25), Some(&20));
+    /// assert_eq!(tree.floor(&5), None);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(h)`, where `h` is the height of the tree
+    /// - **Space Complexity:** `O(1)`
+    pub fn floor(&self, data: &T) -> Option<&T> {
+        self.upper_bound(Bound::Included(data))
+            .map(|node| unsafe { &(*node).data })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_floor() {
+        let mut tree = BinaryTree::new();
+        assert_eq!(tree.floor(&1), None);
+
+        for i in [40, 20, 60, 10, 30, 50, 70] {
+            tree.insert(i);
+        }
+        assert_eq!(tree.floor(&35), Some(&30));
+        assert_eq!(tree.floor(&45), Some(&40));
+        assert_eq!(tree.floor(&100), Some(&70));
+        assert_eq!(tree.floor(&10), Some(&10));
+        assert_eq!(tree.floor(&9), None);
+    }
+}
@@ -0,0 +1,164 @@
+use super::{BinaryTree, Node};
+use crate::queue::{ArrayQueue, Queue};
+
+/// A node waiting for its children, with the exclusive bounds of its subtree.
+type Pending<T> = (*mut Node<T>, Option<*mut Node<T>>, Option<*mut Node<T>>);
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Rebuilds a `BinaryTree` from its level-order and in-order sequences.
+    ///
+    /// The tree is a binary search tree, so the in-order sequence must be
+    /// the sorted items. The nodes are linked level by level, each one
+    /// taking the next items as children if they fall in the range left for
+    /// its subtrees, and the result is checked against `in_order`.
+    ///
+    /// # Errors
+    ///
+    /// - `"Sequences have different lengths"`
+    /// - `"In-order sequence is not strictly increasing"`
+    /// - `"Not a valid level-order sequence"`: No binary search tree has
+    ///   this level-order sequence.
+    /// - `"Sequences have different items"`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let tree = BinaryTree::from_level_and_in_order(vec![2, 1, 3, 4], &[1, 2, 3, 4]).unwrap();
+    /// assert_eq!(tree.pre_order().collect::<Vec<_>>(), vec![&2, &1, &3, &4]);
+    ///
+    /// let error = BinaryTree::from_level_and_in_order(vec![2, 3, 4, 1], &[1, 2, 3, 4]);
+    /// assert_eq!(error.err(), Some("Not a valid level-order sequence"));
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(n)`
+    /// - **Space Complexity:** `O(w)`, where `w` is the width of the widest level
+    pub fn from_level_and_in_order(
+        level_order: Vec<T>,
+        in_order: &[T],
+    ) -> Result<Self, &'static str> {
+        Self::check_in_order(in_order, level_order.len())?;
+        Self::link_level_order(level_order)
+            .ok_or("Not a valid level-order sequence")?
+            .matching_in_order(in_order)
+    }
+
+    /// Links the nodes of a binary search tree given in level-order.
+    ///
+    /// Each queued node waits with the bounds of its subtree. Children come
+    /// in the same order as their parents, so the next item is either a
+    /// child of the node at the front of the queue or, if it is out of that
+    /// node's range, the node has no child on that side.
+    ///
+    /// # Returns
+    ///
+    /// - `None`: If the items are not in level-order for any tree.
+    fn link_level_order(level_order: Vec<T>) -> Option<Self> {
+        // Dropping the tree on an error frees the nodes linked so far
+        let mut tree = BinaryTree::new();
+        let mut items = level_order.into_iter().peekable();
+        let mut queue: ArrayQueue<Pending<T>> = ArrayQueue::new();
+        if let Some(data) = items.next() {
+            let root = Box::into_raw(Box::new(Node::new(data, None)));
+            tree.root = Some(root);
+            tree.length = 1;
+            queue.enqueue((root, None, None));
+        }
+
+        while let Some((node, lower, upper)) = queue.dequeue() {
+            unsafe {
+                let left = items.next_if(|data| {
+                    lower.is_none_or(|lower| (*lower).data < *data) && *data < (*node).data
+                });
+                if let Some(data) = left {
+                    let child = Box::into_raw(Box::new(Node::new(data, Some(node))));
+                    (*node).left = Some(child);
+                    tree.length += 1;
+                    queue.enqueue((child, lower, Some(node)));
+                }
+
+                let right = items.next_if(|data| {
+                    (*node).data < *data && upper.is_none_or(|upper| *data < (*upper).data)
+                });
+                if let Some(data) = right {
+                    let child = Box::into_raw(Box::new(Node::new(data, Some(node))));
+                    (*node).right = Some(child);
+                    tree.length += 1;
+                    queue.enqueue((child, Some(node), upper));
+                }
+            }
+        }
+
+        // Items left over did not fit anywhere
+        items.peek().is_none().then_some(tree)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_level_and_in_order() {
+        let level_order = vec![50, 20, 80, 10, 30, 70, 90, 25, 35, 75];
+        let mut in_order = level_order.clone();
+        in_order.sort();
+
+        let tree = BinaryTree::from_level_and_in_order(level_order.clone(), &in_order).unwrap();
+        assert!(tree.level_order().eq(&level_order));
+        assert!(tree
+            .pre_order()
+            .copied()
+            .eq([50, 20, 10, 30, 25, 35, 80, 70, 75, 90]));
+
+        let empty = BinaryTree::<i32>::from_level_and_in_order(Vec::new(), &[]).unwrap();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_one_sided_nodes() {
+        // 5 has only a right child and 15 only a left one
+        let level_order = vec![10, 5, 15, 7, 12, 6, 13];
+        let mut in_order = level_order.clone();
+        in_order.sort();
+
+        let tree = BinaryTree::from_level_and_in_order(level_order.clone(), &in_order).unwrap();
+        assert!(tree.level_order().eq(&level_order));
+        assert!(tree.pre_order().copied().eq([10, 5, 7, 6, 15, 12, 13]));
+    }
+
+    #[test]
+    fn test_inconsistent_sequences() {
+        assert_eq!(
+            BinaryTree::from_level_and_in_order(vec![2, 1], &[1, 2, 3]).err(),
+            Some("Sequences have different lengths")
+        );
+        assert_eq!(
+            BinaryTree::from_level_and_in_order(vec![2, 1, 3], &[3, 2, 1]).err(),
+            Some("In-order sequence is not strictly increasing")
+        );
+        // 1 comes after 3 but belongs to the level of 3
+        assert_eq!(
+            BinaryTree::from_level_and_in_order(vec![4, 2, 6, 3, 1], &[1, 2, 3, 4, 6]).err(),
+            Some("Not a valid level-order sequence")
+        );
+        assert_eq!(
+            BinaryTree::from_level_and_in_order(vec![2, 2], &[1, 2]).err(),
+            Some("Not a valid level-order sequence")
+        );
+        assert_eq!(
+            BinaryTree::from_level_and_in_order(vec![2, 1, 3], &[1, 2, 4]).err(),
+            Some("Sequences have different items")
+        );
+    }
+
+    #[test]
+    fn test_from_level_and_in_order_of_degenerate_tree() {
+        let items: Vec<u32> = (0..100_000).collect();
+        let tree = BinaryTree::from_level_and_in_order(items.clone(), &items).unwrap();
+        assert_eq!(tree.height(), 100_000);
+    }
+}
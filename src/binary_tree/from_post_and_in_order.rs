@@ -0,0 +1,95 @@
+use super::BinaryTree;
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Rebuilds a `BinaryTree` from its post-order and in-order sequences.
+    ///
+    /// The tree is a binary search tree, so the in-order sequence must be
+    /// the sorted items. Read backward, the post-order sequence lists each
+    /// node before its right and left subtrees, so the tree is linked from
+    /// the last item to the first one and then checked against `in_order`.
+    ///
+    /// # Errors
+    ///
+    /// - `"Sequences have different lengths"`
+    /// - `"In-order sequence is not strictly increasing"`
+    /// - `"Not a valid post-order sequence"`: No binary search tree has this
+    ///   post-order sequence.
+    /// - `"Sequences have different items"`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let tree = BinaryTree::from_post_and_in_order(vec![1, 3, 2], &[1, 2, 3]).unwrap();
+    /// assert_eq!(tree.pre_order().collect::<Vec<_>>(), vec![&2, &1, &3]);
+    ///
+    /// let error = BinaryTree::from_post_and_in_order(vec![3, 1, 2], &[1, 2, 3]);
+    /// assert_eq!(error.err(), Some("Not a valid post-order sequence"));
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(n)`
+    /// - **Space Complexity:** `O(h)`, where `h` is the height of the tree
+    pub fn from_post_and_in_order(
+        post_order: Vec<T>,
+        in_order: &[T],
+    ) -> Result<Self, &'static str> {
+        Self::check_in_order(in_order, post_order.len())?;
+        Self::link_parents_first(post_order.into_iter().rev(), true)
+            .ok_or("Not a valid post-order sequence")?
+            .matching_in_order(in_order)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_post_and_in_order() {
+        let post_order = vec![10, 25, 35, 30, 20, 75, 70, 90, 80, 50];
+        let mut in_order = post_order.clone();
+        in_order.sort();
+
+        let tree = BinaryTree::from_post_and_in_order(post_order.clone(), &in_order).unwrap();
+        assert!(tree.post_order().eq(&post_order));
+        assert!(tree
+            .pre_order()
+            .copied()
+            .eq([50, 20, 10, 30, 25, 35, 80, 70, 75, 90]));
+
+        let empty = BinaryTree::<i32>::from_post_and_in_order(Vec::new(), &[]).unwrap();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_inconsistent_sequences() {
+        assert_eq!(
+            BinaryTree::from_post_and_in_order(vec![1, 3], &[1, 2, 3]).err(),
+            Some("Sequences have different lengths")
+        );
+        assert_eq!(
+            BinaryTree::from_post_and_in_order(vec![1, 3, 2], &[2, 1, 3]).err(),
+            Some("In-order sequence is not strictly increasing")
+        );
+        assert_eq!(
+            BinaryTree::from_post_and_in_order(vec![1, 2, 2], &[1, 2, 3]).err(),
+            Some("Not a valid post-order sequence")
+        );
+        assert_eq!(
+            BinaryTree::from_post_and_in_order(vec![1, 3, 2], &[1, 2, 4]).err(),
+            Some("Sequences have different items")
+        );
+    }
+
+    #[test]
+    fn test_from_post_and_in_order_of_degenerate_tree() {
+        let items: Vec<u32> = (0..100_000).collect();
+        let tree =
+            BinaryTree::from_post_and_in_order(items.iter().rev().copied().collect(), &items)
+                .unwrap();
+        assert_eq!(tree.height(), 100_000);
+    }
+}
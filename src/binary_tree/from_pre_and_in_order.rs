@@ -0,0 +1,101 @@
+use super::BinaryTree;
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Rebuilds a `BinaryTree` from its pre-order and in-order sequences.
+    ///
+    /// The tree is a binary search tree, so the in-order sequence must be
+    /// the sorted items. It is checked against the tree rebuilt from
+    /// `pre_order`.
+    ///
+    /// # Errors
+    ///
+    /// - `"Sequences have different lengths"`
+    /// - `"In-order sequence is not strictly increasing"`
+    /// - `"Not a valid pre-order sequence"`: No binary search tree has this
+    ///   pre-order sequence.
+    /// - `"Sequences have different items"`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let tree = BinaryTree::from_pre_and_in_order(vec![2, 1, 3], &[1, 2, 3]).unwrap();
+    /// assert_eq!(tree.post_order().collect::<Vec<_>>(), vec![&1, &3, &2]);
+    ///
+    /// let error = BinaryTree::from_pre_and_in_order(vec![2, 1, 3], &[1, 2, 4]);
+    /// assert_eq!(error.err(), Some("Sequences have different items"));
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(n)`
+    /// - **Space Complexity:** `O(h)`, where `h` is the height of the tree
+    pub fn from_pre_and_in_order(pre_order: Vec<T>, in_order: &[T]) -> Result<Self, &'static str> {
+        Self::check_in_order(in_order, pre_order.len())?;
+        Self::from_pre_order(pre_order)?.matching_in_order(in_order)
+    }
+
+    /// Checks the shape of an in-order sequence of `length` items.
+    pub(super) fn check_in_order(in_order: &[T], length: usize) -> Result<(), &'static str> {
+        if in_order.len() != length {
+            return Err("Sequences have different lengths");
+        }
+        if in_order.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err("In-order sequence is not strictly increasing");
+        }
+        Ok(())
+    }
+
+    /// Returns the tree if its items are the ones of `in_order`.
+    pub(super) fn matching_in_order(self, in_order: &[T]) -> Result<Self, &'static str> {
+        if self.iter().eq(in_order) {
+            Ok(self)
+        } else {
+            Err("Sequences have different items")
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_pre_and_in_order() {
+        let pre_order = vec![50, 20, 10, 30, 25, 35, 80, 70, 75, 90];
+        let mut in_order = pre_order.clone();
+        in_order.sort();
+
+        let tree = BinaryTree::from_pre_and_in_order(pre_order.clone(), &in_order).unwrap();
+        assert!(tree.pre_order().eq(&pre_order));
+        assert!(tree.iter().eq(&in_order));
+
+        let empty = BinaryTree::<i32>::from_pre_and_in_order(Vec::new(), &[]).unwrap();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_inconsistent_sequences() {
+        assert_eq!(
+            BinaryTree::from_pre_and_in_order(vec![2, 1, 3], &[1, 2]).err(),
+            Some("Sequences have different lengths")
+        );
+        assert_eq!(
+            BinaryTree::from_pre_and_in_order(vec![2, 1, 3], &[1, 3, 2]).err(),
+            Some("In-order sequence is not strictly increasing")
+        );
+        assert_eq!(
+            BinaryTree::from_pre_and_in_order(vec![2, 2, 2], &[2, 2, 2]).err(),
+            Some("In-order sequence is not strictly increasing")
+        );
+        assert_eq!(
+            BinaryTree::from_pre_and_in_order(vec![2, 3, 1], &[1, 2, 3]).err(),
+            Some("Not a valid pre-order sequence")
+        );
+        assert_eq!(
+            BinaryTree::from_pre_and_in_order(vec![2, 1, 3], &[0, 1, 2]).err(),
+            Some("Sequences have different items")
+        );
+    }
+}
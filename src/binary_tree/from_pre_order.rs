@@ -0,0 +1,157 @@
+use super::{BinaryTree, Node};
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Rebuilds a `BinaryTree` from its pre-order sequence alone.
+    ///
+    /// Since the tree is a binary search tree, its in-order sequence is the
+    /// sorted pre-order one, so the pre-order sequence is enough to give its
+    /// shape.
+    ///
+    /// # Errors
+    ///
+    /// - `"Not a valid pre-order sequence"`: No binary search tree has this
+    ///   pre-order sequence, or it repeats an item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let tree = BinaryTree::from_pre_order(vec![4, 2, 1, 3, 6]).unwrap();
+    /// assert_eq!(tree.level_order().collect::<Vec<_>>(), vec![&4, &2, &6, &1, &3]);
+    ///
+    /// assert!(BinaryTree::from_pre_order(vec![4, 6, 2]).is_err());
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(n)`
+    /// - **Space Complexity:** `O(h)`, where `h` is the height of the tree
+    pub fn from_pre_order(pre_order: Vec<T>) -> Result<Self, &'static str> {
+        Self::link_parents_first(pre_order, false).ok_or("Not a valid pre-order sequence")
+    }
+
+    /// Links the nodes of a binary search tree given in pre-order, or in
+    /// mirrored pre-order (node, right subtree, left subtree) if `mirrored`.
+    ///
+    /// The stack holds the path of nodes that may still get a child on the
+    /// far side. An item past the top of the stack closes every subtree it
+    /// is past and hangs on the far side of the last node closed, which is
+    /// then a bound for every later item.
+    ///
+    /// # Returns
+    ///
+    /// - `None`: If the items are not in that order for any tree.
+    pub(super) fn link_parents_first(
+        items: impl IntoIterator<Item = T>,
+        mirrored: bool,
+    ) -> Option<Self> {
+        // Checks if `a` comes before `b` in the in-order of the tree or its mirror
+        let before = |a: &T, b: &T| if mirrored { a > b } else { a < b };
+
+        // Dropping the tree on an error frees the nodes linked so far
+        let mut tree = BinaryTree::new();
+        let mut stack: Vec<*mut Node<T>> = Vec::new();
+        let mut bound: Option<*mut Node<T>> = None;
+        for data in items {
+            unsafe {
+                if bound.is_some_and(|bound| !before(&(*bound).data, &data)) {
+                    return None;
+                }
+                let mut closed = None;
+                while let Some(&top) = stack.last() {
+                    if !before(&(*top).data, &data) {
+                        break;
+                    }
+                    closed = stack.pop();
+                }
+                if stack.last().is_some_and(|&top| (*top).data == data) {
+                    return None;
+                }
+
+                let node = match (closed, stack.last()) {
+                    (Some(parent), _) => {
+                        bound = Some(parent);
+                        let node = Box::into_raw(Box::new(Node::new(data, Some(parent))));
+                        if mirrored {
+                            (*parent).left = Some(node);
+                        } else {
+                            (*parent).right = Some(node);
+                        }
+                        node
+                    }
+                    (None, Some(&parent)) => {
+                        let node = Box::into_raw(Box::new(Node::new(data, Some(parent))));
+                        if mirrored {
+                            (*parent).right = Some(node);
+                        } else {
+                            (*parent).left = Some(node);
+                        }
+                        node
+                    }
+                    (None, None) => {
+                        let node = Box::into_raw(Box::new(Node::new(data, None)));
+                        tree.root = Some(node);
+                        node
+                    }
+                };
+                tree.length += 1;
+                stack.push(node);
+            }
+        }
+        Some(tree)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_pre_order() {
+        let tree =
+            BinaryTree::from_pre_order(vec![50, 20, 10, 30, 25, 35, 80, 70, 75, 90]).unwrap();
+        assert_eq!(tree.len(), 10);
+        assert!(tree.is_bst());
+        assert_eq!(tree.size(), 10);
+        assert!(tree
+            .pre_order()
+            .copied()
+            .eq([50, 20, 10, 30, 25, 35, 80, 70, 75, 90]));
+        assert!(tree
+            .level_order()
+            .copied()
+            .eq([50, 20, 80, 10, 30, 70, 90, 25, 35, 75]));
+
+        let empty = BinaryTree::<i32>::from_pre_order(Vec::new()).unwrap();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_pre_orders() {
+        // 3 comes after the right subtree of 4 was started
+        assert_eq!(
+            BinaryTree::from_pre_order(vec![4, 2, 5, 3]).err(),
+            Some("Not a valid pre-order sequence")
+        );
+        assert!(BinaryTree::from_pre_order(vec![4, 4]).is_err());
+        assert!(BinaryTree::from_pre_order(vec![4, 2, 4]).is_err());
+        assert!(BinaryTree::from_pre_order(vec![4, 2, 2]).is_err());
+    }
+
+    #[test]
+    fn test_mirrored_pre_order() {
+        // Node, right subtree, left subtree: the reversed post-order
+        let tree = BinaryTree::link_parents_first([4, 6, 7, 5, 2, 3, 1], true).unwrap();
+        assert!(tree.post_order().copied().eq([1, 3, 2, 5, 7, 6, 4]));
+        assert!(BinaryTree::link_parents_first([4, 2, 6], true).is_none());
+    }
+
+    #[test]
+    fn test_from_pre_order_of_degenerate_trees() {
+        let increasing = BinaryTree::from_pre_order((0..100_000).collect()).unwrap();
+        assert_eq!(increasing.height(), 100_000);
+        let decreasing = BinaryTree::from_pre_order((0..100_000).rev().collect()).unwrap();
+        assert!(decreasing.iter().copied().eq(0..100_000));
+    }
+}
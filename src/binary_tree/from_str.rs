@@ -0,0 +1,206 @@
+use super::{quote, BinaryTree, Node};
+use std::str::FromStr;
+
+impl<T: std::cmp::Ord + FromStr> FromStr for BinaryTree<T> {
+    type Err = &'static str;
+
+    /// Reads a `BinaryTree` in the parenthesized pre-order notation written
+    /// by its [`Display`](std::fmt::Display), keeping its exact shape.
+    ///
+    /// The items are parsed with [`FromStr`] and spaces around them are
+    /// ignored. A quoted item is parsed without its quotes and the `\` before
+    /// the quoted characters, so it may hold `(`, `,` or `)`. An empty text
+    /// is the empty tree.
+    ///
+    /// # Errors
+    ///
+    /// - `"Invalid item"`: An item is badly quoted or could not be parsed.
+    /// - `"Unexpected item"`, `"Unexpected '('"`, `"Unexpected ','"`,
+    ///   `"Unexpected ')'"`: The text does not follow the notation.
+    /// - `"Missing ')'"`
+    /// - `"Items are not in order"`: The shape is not a binary search tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let tree: BinaryTree<i32> = "4(2(,3),6)".parse().unwrap();
+    /// assert_eq!(tree.serialize(), "[4,2,6,null,3]");
+    /// assert_eq!(tree.to_string(), "4(2(,3),6)");
+    ///
+    /// assert_eq!("4(6,2)".parse::<BinaryTree<i32>>().err(), Some("Items are not in order"));
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(n)`
+    /// - **Space Complexity:** `O(h)`, where `h` is the height of the tree
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        // Dropping the tree on an error frees the nodes linked so far
+        let mut tree = BinaryTree::new();
+        // Nodes with open parentheses, and whether their right child started
+        let mut open: Vec<(*mut Node<T>, bool)> = Vec::new();
+        // The node just read, which may open parentheses
+        let mut last = None;
+        let mut expects_item = true;
+
+        let symbols = quote::symbols_outside_quotes(text, &['(', ',', ')']);
+        let mut start = 0;
+        // The end of the text closes the last item like a symbol
+        for symbol in symbols.map(Some).chain([None]) {
+            let end = symbol.map_or(text.len(), |(position, _)| position);
+            let item = text[start..end].trim();
+            start = end + 1;
+
+            if !item.is_empty() {
+                if !expects_item {
+                    return Err("Unexpected item");
+                }
+                let data = quote::unquote(item)?.parse().map_err(|_| "Invalid item")?;
+                let node = match open.last() {
+                    Some(&(parent, right)) => {
+                        let node = Box::into_raw(Box::new(Node::new(data, Some(parent))));
+                        unsafe {
+                            if right {
+                                (*parent).right = Some(node);
+                            } else {
+                                (*parent).left = Some(node);
+                            }
+                        }
+                        node
+                    }
+                    None if tree.root.is_none() => {
+                        let node = Box::into_raw(Box::new(Node::new(data, None)));
+                        tree.root = Some(node);
+                        node
+                    }
+                    None => return Err("Unexpected item"),
+                };
+                tree.length += 1;
+                last = Some(node);
+                expects_item = false;
+            }
+
+            match symbol.map(|(_, symbol)| symbol) {
+                Some('(') => {
+                    let node = last.take().ok_or("Unexpected '('")?;
+                    open.push((node, false));
+                    expects_item = true;
+                }
+                Some(',') => {
+                    match open.last_mut() {
+                        Some((_, right)) if !*right => *right = true,
+                        _ => return Err("Unexpected ','"),
+                    }
+                    last = None;
+                    expects_item = true;
+                }
+                Some(_) => {
+                    match open.pop() {
+                        Some((_, true)) => {}
+                        _ => return Err("Unexpected ')'"),
+                    }
+                    last = None;
+                    expects_item = false;
+                }
+                None if !open.is_empty() => return Err("Missing ')'"),
+                None => {}
+            }
+        }
+
+        if !tree.is_bst() {
+            return Err("Items are not in order");
+        }
+        Ok(tree)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(text: &str) -> Result<BinaryTree<i32>, &'static str> {
+        text.parse()
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert!(parse("").unwrap().is_empty());
+        assert!(parse("  ").unwrap().is_empty());
+
+        let tree = parse("4 ( 2 (1, 3), 6(5,7) )").unwrap();
+        assert_eq!(tree.len(), 7);
+        assert!(tree.level_order().copied().eq([4, 2, 6, 1, 3, 5, 7]));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for text in [
+            "",
+            "1",
+            "10(5(,7(,8)),15(,20(17,)))",
+            "3(2(1,),)",
+            "1(,2(,3(,4)))",
+            "50(20(10,30(25,35)),80(70(,75),90))",
+        ] {
+            let tree = parse(text).unwrap();
+            assert_eq!(tree.to_string(), text);
+            assert_eq!(tree.size(), tree.len());
+            assert_eq!(
+                BinaryTree::<i32>::deserialize(&tree.serialize())
+                    .unwrap()
+                    .to_string(),
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_round_trip_of_strings() {
+        let mut tree = BinaryTree::new();
+        for word in ["m", "", "a,b", "(x)", " padded ", "say \"hi\"", "z)"] {
+            tree.insert(word.to_string());
+        }
+        let text = tree.to_string();
+        assert_eq!(
+            text,
+            r#"m(""(,"a,b"("(x)"(" padded ",),)),"say \"hi\""(,"z)"))"#
+        );
+
+        let copy: BinaryTree<String> = text.parse().unwrap();
+        assert!(copy.pre_order().eq(tree.pre_order()));
+        assert_eq!(copy.to_string(), text);
+
+        assert_eq!(
+            r#"m("a"b,)"#.parse::<BinaryTree<String>>().err(),
+            Some("Invalid item")
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(parse("4(two,6)").err(), Some("Invalid item"));
+        assert_eq!(parse("4 5").err(), Some("Invalid item"));
+        assert_eq!(parse("4(2,6)7").err(), Some("Unexpected item"));
+        assert_eq!(parse("4(2 3,6)").err(), Some("Invalid item"));
+        assert_eq!(parse("(4)").err(), Some("Unexpected '('"));
+        assert_eq!(parse("4(2,6)(1,)").err(), Some("Unexpected '('"));
+        assert_eq!(parse("4,6").err(), Some("Unexpected ','"));
+        assert_eq!(parse("4(2,6,7)").err(), Some("Unexpected ','"));
+        assert_eq!(parse("4(2)").err(), Some("Unexpected ')'"));
+        assert_eq!(parse("4)").err(), Some("Unexpected ')'"));
+        assert_eq!(parse("4(2,6").err(), Some("Missing ')'"));
+        assert_eq!(parse("4(6,2)").err(), Some("Items are not in order"));
+        assert_eq!(parse("4(2(,5),6)").err(), Some("Items are not in order"));
+    }
+
+    #[test]
+    fn test_round_trip_of_degenerate_tree() {
+        let tree = BinaryTree::from_pre_order((0..10_000).rev().collect()).unwrap();
+        let text = tree.to_string();
+        let copy = parse(&text).unwrap();
+        assert_eq!(copy.height(), 10_000);
+        assert_eq!(copy.to_string(), text);
+    }
+}
@@ -0,0 +1,100 @@
+use super::{BinaryTree, Node};
+use std::cmp::Ordering;
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Retrieves a reference to the item of the `BinaryTree` equal to `data`.
+    ///
+    /// This is useful for items whose ordering only looks at part of them,
+    /// like a key.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(&T)`: A reference to the stored item.
+    /// - `None`: If no item of the tree is equal to `data`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// tree.insert(String::from("pear"));
+    ///
+    /// assert_eq!(tree.get(&String::from("pear")), Some(&String::from("pear")));
+    /// assert_eq!(tree.get(&String::from("plum")), None);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(h)`, where `h` is the height of the tree
+    /// - **Space Complexity:** `O(1)`
+    pub fn get(&self, data: &T) -> Option<&T> {
+        self.find(data).map(|node| unsafe { &(*node).data })
+    }
+
+    /// Finds the node holding the item equal to `data`.
+    pub(super) fn find(&self, data: &T) -> Option<*mut Node<T>> {
+        let mut cursor = self.root;
+        while let Some(node) = cursor {
+            unsafe {
+                cursor = match data.cmp(&(*node).data) {
+                    Ordering::Less => (*node).left,
+                    Ordering::Greater => (*node).right,
+                    Ordering::Equal => return Some(node),
+                };
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Ordered by `key` only, like a map entry.
+    #[derive(Debug, PartialEq, Eq)]
+    struct Entry {
+        key: u32,
+        value: &'static str,
+    }
+
+    impl PartialOrd for Entry {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Entry {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.key.cmp(&other.key)
+        }
+    }
+
+    #[test]
+    fn test_get_by_key() {
+        let mut tree = BinaryTree::new();
+        tree.insert(Entry {
+            key: 2,
+            value: "two",
+        });
+        tree.insert(Entry {
+            key: 1,
+            value: "one",
+        });
+        tree.insert(Entry {
+            key: 3,
+            value: "three",
+        });
+
+        let probe = Entry { key: 3, value: "" };
+        assert_eq!(tree.get(&probe).map(|entry| entry.value), Some("three"));
+        assert_eq!(tree.get(&Entry { key: 4, value: "" }), None);
+    }
+
+    #[test]
+    fn test_get_empty_tree() {
+        let tree: BinaryTree<i32> = BinaryTree::new();
+        assert_eq!(tree.get(&1), None);
+    }
+}
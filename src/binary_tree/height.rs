@@ -0,0 +1,65 @@
+use super::BinaryTree;
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Gets the height of the `BinaryTree`, the number of nodes on the
+    /// longest path from the root down to a leaf.
+    ///
+    /// # Returns
+    ///
+    /// - `0` for an empty tree, `1` for a tree with only a root, and up to
+    ///   [`len`](Self::len) for a degenerate tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// assert_eq!(tree.height(), 0);
+    ///
+    /// for i in [2, 1, 3, 4] {
+    ///     tree.insert(i);
+    /// }
+    /// assert_eq!(tree.height(), 3);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(n)`
+    /// - **Space Complexity:** `O(h)`, where `h` is the height of the tree
+    pub fn height(&self) -> usize {
+        self.fold_post_order(|_, left: Option<usize>, right| {
+            1 + left.unwrap_or(0).max(right.unwrap_or(0))
+        })
+        .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_height() {
+        let mut tree = BinaryTree::new();
+        assert_eq!(tree.height(), 0);
+        tree.insert(4);
+        assert_eq!(tree.height(), 1);
+
+        for i in [2, 6, 1, 3, 5, 7] {
+            tree.insert(i);
+        }
+        assert_eq!(tree.height(), 3);
+        tree.insert(8);
+        assert_eq!(tree.height(), 4);
+    }
+
+    #[test]
+    fn test_height_of_degenerate_tree() {
+        let mut tree = BinaryTree::new();
+        for i in 0..10_000 {
+            tree.insert(i);
+        }
+        assert_eq!(tree.height(), 10_000);
+    }
+}
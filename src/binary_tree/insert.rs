@@ -0,0 +1,117 @@
+use super::{BinaryTree, Node};
+use std::cmp::Ordering;
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Inserts an item into the `BinaryTree`, keeping it sorted.
+    ///
+    /// The tree stores each item once: inserting an item equal to one
+    /// already in the tree leaves the tree untouched.
+    ///
+    /// # Returns
+    ///
+    /// - `true`: If the item was inserted.
+    /// - `false`: If an equal item was already in the tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// assert!(tree.insert(2));
+    /// assert!(tree.insert(1));
+    /// assert!(!tree.insert(2));
+    /// assert_eq!(tree.len(), 2);
+    /// ```
+    /// # Safety
+    ///
+    /// - This method uses `unsafe` internally to link the new node into the tree.
+    /// - It is safe as long as the internal structure of the `BinaryTree` remains valid.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(h)`, where `h` is the height of the tree
+    /// - **Space Complexity:** `O(1)`
+    pub fn insert(&mut self, data: T) -> bool {
+        let mut parent = None;
+        let mut cursor = self.root;
+        let mut goes_left = false;
+        unsafe {
+            while let Some(node) = cursor {
+                parent = Some(node);
+                match data.cmp(&(*node).data) {
+                    Ordering::Less => {
+                        goes_left = true;
+                        cursor = (*node).left;
+                    }
+                    Ordering::Greater => {
+                        goes_left = false;
+                        cursor = (*node).right;
+                    }
+                    Ordering::Equal => return false,
+                }
+            }
+
+            let node = Box::into_raw(Box::new(Node::new(data, parent)));
+            match parent {
+                None => self.root = Some(node),
+                Some(parent) if goes_left => (*parent).left = Some(node),
+                Some(parent) => (*parent).right = Some(node),
+            }
+        }
+        self.length += 1;
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_insert_links_nodes() {
+        let mut tree = BinaryTree::new();
+        assert!(tree.insert(5));
+        assert!(tree.insert(3));
+        assert!(tree.insert(8));
+        assert!(tree.insert(4));
+
+        unsafe {
+            let root = tree.root.unwrap();
+            let left = (*root).left.unwrap();
+            let right = (*root).right.unwrap();
+            assert_eq!((*root).data, 5);
+            assert_eq!((*left).data, 3);
+            assert_eq!((*right).data, 8);
+            assert_eq!((*left).parent, Some(root));
+            assert_eq!((*right).parent, Some(root));
+
+            let left_right = (*left).right.unwrap();
+            assert_eq!((*left_right).data, 4);
+            assert_eq!((*left_right).parent, Some(left));
+            assert!((*left).left.is_none());
+        }
+    }
+
+    #[test]
+    fn test_insert_duplicates() {
+        let mut tree = BinaryTree::new();
+        assert!(tree.insert("b"));
+        assert!(!tree.insert("b"));
+        assert!(tree.insert("a"));
+        assert!(!tree.insert("a"));
+        assert_eq!(tree.len(), 2);
+    }
+
+    #[test]
+    fn test_insert_sorted_input() {
+        // Sorted input degenerates into a list, which must still work
+        let mut tree = BinaryTree::new();
+        for i in 0..10_000 {
+            tree.insert(i);
+        }
+        assert_eq!(tree.len(), 10_000);
+        assert_eq!(tree.min(), Some(&0));
+        assert_eq!(tree.max(), Some(&9_999));
+    }
+}
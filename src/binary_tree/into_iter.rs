@@ -0,0 +1,258 @@
+use super::{BinaryTree, Node};
+
+/// Owning iterator over the items of a [`BinaryTree`].
+///
+/// It is created by [`into_iter`](IntoIterator::into_iter) for an in-order
+/// traversal, or by [`into_pre_order`](BinaryTree::into_pre_order),
+/// [`into_post_order`](BinaryTree::into_post_order) and
+/// [`into_level_order`](BinaryTree::into_level_order). The traversal order is
+/// recorded when the iterator is created and each node is freed as its item
+/// is yielded, so it also runs backward as a [`DoubleEndedIterator`].
+pub struct IntoIter<T: std::cmp::Ord> {
+    nodes: std::vec::IntoIter<*mut Node<T>>,
+}
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Consumes the `BinaryTree`, returning its items in pre-order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// for i in [2, 1, 3] {
+    ///     tree.insert(i);
+    /// }
+    ///
+    /// assert_eq!(tree.into_pre_order().collect::<Vec<_>>(), vec![2, 1, 3]);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(n)`
+    /// - **Space Complexity:** `O(n)`, to record the order of the nodes
+    pub fn into_pre_order(self) -> IntoIter<T> {
+        let mut order = self.pre_order();
+        let nodes = std::iter::from_fn(|| order.next_node()).collect();
+        self.into_nodes(nodes)
+    }
+
+    /// Consumes the `BinaryTree`, returning its items in post-order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// for i in [2, 1, 3] {
+    ///     tree.insert(i);
+    /// }
+    ///
+    /// assert_eq!(tree.into_post_order().collect::<Vec<_>>(), vec![1, 3, 2]);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(n)`
+    /// - **Space Complexity:** `O(n)`, to record the order of the nodes
+    pub fn into_post_order(self) -> IntoIter<T> {
+        let mut order = self.post_order();
+        let nodes = std::iter::from_fn(|| order.next_node()).collect();
+        self.into_nodes(nodes)
+    }
+
+    /// Consumes the `BinaryTree`, returning its items in level-order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// for i in [2, 1, 3, 4] {
+    ///     tree.insert(i);
+    /// }
+    ///
+    /// assert_eq!(tree.into_level_order().collect::<Vec<_>>(), vec![2, 1, 3, 4]);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(n)`
+    /// - **Space Complexity:** `O(n)`, to record the order of the nodes
+    pub fn into_level_order(self) -> IntoIter<T> {
+        let mut order = self.level_order();
+        let nodes = std::iter::from_fn(|| order.next_node()).collect();
+        self.into_nodes(nodes)
+    }
+
+    /// Hands every node over to an [`IntoIter`] yielding them in the order
+    /// of `nodes`, leaving the tree empty so it frees nothing when dropped.
+    fn into_nodes(mut self, nodes: Vec<*mut Node<T>>) -> IntoIter<T> {
+        self.root = None;
+        self.length = 0;
+        IntoIter {
+            nodes: nodes.into_iter(),
+        }
+    }
+}
+
+impl<T: std::cmp::Ord> IntoIterator for BinaryTree<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// Consumes the `BinaryTree`, returning its items in order, from the
+    /// smallest to the greatest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// for i in [2, 1, 3] {
+    ///     tree.insert(i);
+    /// }
+    ///
+    /// assert_eq!(tree.into_iter().rev().collect::<Vec<_>>(), vec![3, 2, 1]);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        let mut order = self.iter();
+        let nodes = std::iter::from_fn(|| order.next_node()).collect();
+        self.into_nodes(nodes)
+    }
+}
+
+impl<T: std::cmp::Ord> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nodes
+            .next()
+            .map(|node| unsafe { Box::from_raw(node).data })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.nodes.size_hint()
+    }
+}
+
+impl<T: std::cmp::Ord> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nodes
+            .next_back()
+            .map(|node| unsafe { Box::from_raw(node).data })
+    }
+}
+
+impl<T: std::cmp::Ord> ExactSizeIterator for IntoIter<T> {}
+
+impl<T: std::cmp::Ord> Drop for IntoIter<T> {
+    /// Frees the nodes whose items were not yielded.
+    fn drop(&mut self) {
+        for node in self.nodes.by_ref() {
+            unsafe { drop(Box::from_raw(node)) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    /// Orders by `key` and counts its drops in the shared counter.
+    struct Counted {
+        key: i32,
+        drops: Rc<Cell<usize>>,
+    }
+
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    impl PartialEq for Counted {
+        fn eq(&self, other: &Self) -> bool {
+            self.key == other.key
+        }
+    }
+
+    impl Eq for Counted {}
+
+    impl PartialOrd for Counted {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Counted {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.key.cmp(&other.key)
+        }
+    }
+
+    fn sample() -> BinaryTree<i32> {
+        let mut tree = BinaryTree::new();
+        for i in [4, 2, 6, 1, 3, 5, 7] {
+            tree.insert(i);
+        }
+        tree
+    }
+
+    #[test]
+    fn test_owning_orders() {
+        assert_eq!(
+            sample().into_iter().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 5, 6, 7]
+        );
+        assert_eq!(
+            sample().into_pre_order().collect::<Vec<_>>(),
+            vec![4, 2, 1, 3, 6, 5, 7]
+        );
+        assert_eq!(
+            sample().into_post_order().collect::<Vec<_>>(),
+            vec![1, 3, 2, 5, 7, 6, 4]
+        );
+        assert_eq!(
+            sample().into_level_order().collect::<Vec<_>>(),
+            vec![4, 2, 6, 1, 3, 5, 7]
+        );
+        assert_eq!(BinaryTree::<i32>::new().into_iter().next(), None);
+    }
+
+    #[test]
+    fn test_owning_from_both_ends() {
+        let mut iter = sample().into_iter();
+        assert_eq!(iter.len(), 7);
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(7));
+        assert_eq!(iter.len(), 5);
+    }
+
+    #[test]
+    fn test_partially_consumed_iterator_drops_the_rest() {
+        let drops = Rc::new(Cell::new(0));
+        let mut tree = BinaryTree::new();
+        for key in [4, 2, 6, 1, 3] {
+            tree.insert(Counted {
+                key,
+                drops: Rc::clone(&drops),
+            });
+        }
+
+        let mut iter = tree.into_level_order();
+        let first = iter.next().unwrap();
+        assert_eq!(first.key, 4);
+        assert_eq!(drops.get(), 0);
+
+        drop(iter);
+        assert_eq!(drops.get(), 4);
+        drop(first);
+        assert_eq!(drops.get(), 5);
+    }
+}
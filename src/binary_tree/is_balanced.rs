@@ -0,0 +1,68 @@
+use super::BinaryTree;
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Checks if the `BinaryTree` is height-balanced: at every node, the
+    /// heights of the left and right subtrees differ by at most one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// for i in [2, 1, 3] {
+    ///     tree.insert(i);
+    /// }
+    /// assert!(tree.is_balanced());
+    ///
+    /// tree.insert(4);
+    /// tree.insert(5);
+    /// assert!(!tree.is_balanced());
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(n)`
+    /// - **Space Complexity:** `O(h)`, where `h` is the height of the tree
+    pub fn is_balanced(&self) -> bool {
+        // Each subtree gives its height, or `None` once a subtree is unbalanced
+        self.fold_post_order(|_, left: Option<Option<usize>>, right| {
+            let left = left.unwrap_or(Some(0))?;
+            let right = right.unwrap_or(Some(0))?;
+            (left.abs_diff(right) <= 1).then(|| 1 + left.max(right))
+        })
+        .is_none_or(|height| height.is_some())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_is_balanced() {
+        let mut tree = BinaryTree::new();
+        assert!(tree.is_balanced());
+
+        for i in [4, 2, 6, 1, 3, 5, 7] {
+            tree.insert(i);
+            assert!(tree.is_balanced());
+        }
+        tree.insert(8);
+        assert!(tree.is_balanced());
+        tree.insert(9);
+        assert!(!tree.is_balanced());
+    }
+
+    #[test]
+    fn test_unbalanced_below_root() {
+        let mut tree = BinaryTree::new();
+        // Both subtrees of the root have height 3, but the left child of
+        // the root has a missing left subtree and a right subtree of height 2
+        for i in [10, 5, 7, 8, 15, 12, 20, 11] {
+            tree.insert(i);
+        }
+        assert_eq!(tree.height(), 4);
+        assert!(!tree.is_balanced());
+    }
+}
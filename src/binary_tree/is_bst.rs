@@ -0,0 +1,71 @@
+use super::BinaryTree;
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Checks if the `BinaryTree` is a binary search tree: its items are
+    /// strictly increasing in order.
+    ///
+    /// Trees built through [`insert`](Self::insert) always are, so this is
+    /// meant to validate trees whose shape was given from outside.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// for i in [2, 1, 3] {
+    ///     tree.insert(i);
+    /// }
+    /// assert!(tree.is_bst());
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(n)`
+    /// - **Space Complexity:** `O(1)`
+    pub fn is_bst(&self) -> bool {
+        let mut items = self.iter();
+        let Some(mut previous) = items.next() else {
+            return true;
+        };
+        items.all(|item| {
+            let increasing = previous < item;
+            previous = item;
+            increasing
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_inserted_trees_are_bst() {
+        let mut tree = BinaryTree::new();
+        assert!(tree.is_bst());
+
+        for i in [50, 20, 80, 10, 30, 70, 90, 25] {
+            tree.insert(i);
+            assert!(tree.is_bst());
+        }
+        tree.remove(&20);
+        tree.remove(&50);
+        assert!(tree.is_bst());
+    }
+
+    #[test]
+    fn test_out_of_order_item_is_detected() {
+        let mut tree = BinaryTree::new();
+        for i in [4, 2, 6, 1, 3] {
+            tree.insert(i);
+        }
+        // Swap the items of the root and its right child
+        unsafe {
+            let root = tree.root.unwrap();
+            let right = (*root).right.unwrap();
+            std::mem::swap(&mut (*root).data, &mut (*right).data);
+        }
+        assert!(!tree.is_bst());
+    }
+}
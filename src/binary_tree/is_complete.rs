@@ -0,0 +1,78 @@
+use super::BinaryTree;
+use crate::queue::{ArrayQueue, Queue};
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Checks if the `BinaryTree` is complete: every level is full except
+    /// possibly the last one, whose nodes are as far left as possible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// for i in [2, 1] {
+    ///     tree.insert(i);
+    /// }
+    /// assert!(tree.is_complete());
+    ///
+    /// tree.remove(&1);
+    /// tree.insert(3);
+    /// assert!(!tree.is_complete());
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(n)`
+    /// - **Space Complexity:** `O(w)`, where `w` is the width of the widest level
+    pub fn is_complete(&self) -> bool {
+        let mut queue = ArrayQueue::new();
+        if let Some(root) = self.root {
+            queue.enqueue(root);
+        }
+        // Once a child is missing in level order, no node may follow
+        let mut gap = false;
+        while let Some(node) = queue.dequeue() {
+            unsafe {
+                for child in [(*node).left, (*node).right] {
+                    match child {
+                        Some(_) if gap => return false,
+                        Some(child) => queue.enqueue(child),
+                        None => gap = true,
+                    }
+                }
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_is_complete() {
+        let mut tree = BinaryTree::new();
+        assert!(tree.is_complete());
+
+        for i in [4, 2, 6, 1, 3, 5] {
+            tree.insert(i);
+            assert!(tree.is_complete());
+        }
+        tree.remove(&3);
+        assert!(!tree.is_complete());
+        tree.insert(3);
+        tree.insert(0);
+        assert!(!tree.is_complete());
+    }
+
+    #[test]
+    fn test_degenerate_tree_is_not_complete() {
+        let mut tree = BinaryTree::new();
+        for i in 0..10_000 {
+            tree.insert(i);
+        }
+        assert!(!tree.is_complete());
+    }
+}
@@ -0,0 +1,47 @@
+use super::BinaryTree;
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Checks if the `BinaryTree` is empty.
+    ///
+    /// # Returns
+    ///
+    /// - `true`: If the tree contains no items.
+    /// - `false`: If the tree contains one or more items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// assert!(tree.is_empty());
+    ///
+    /// tree.insert(1);
+    /// assert!(!tree.is_empty());
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(1)`
+    /// - **Space Complexity:** `O(1)`
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_is_empty_after_removing_everything() {
+        let mut tree = BinaryTree::new();
+        tree.insert('a');
+        tree.insert('b');
+        assert!(!tree.is_empty());
+
+        tree.remove(&'a');
+        tree.remove(&'b');
+        assert!(tree.is_empty());
+    }
+}
@@ -0,0 +1,53 @@
+use super::BinaryTree;
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Checks if the `BinaryTree` is full: every node has either no children
+    /// or two children.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// for i in [2, 1, 3] {
+    ///     tree.insert(i);
+    /// }
+    /// assert!(tree.is_full());
+    ///
+    /// tree.insert(4);
+    /// assert!(!tree.is_full());
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(n)`
+    /// - **Space Complexity:** `O(1)`
+    pub fn is_full(&self) -> bool {
+        let mut order = self.pre_order();
+        std::iter::from_fn(|| order.next_node())
+            .all(|node| unsafe { (*node).left.is_some() == (*node).right.is_some() })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_is_full() {
+        let mut tree = BinaryTree::new();
+        assert!(tree.is_full());
+        tree.insert(4);
+        assert!(tree.is_full());
+
+        for i in [2, 6, 1, 3] {
+            tree.insert(i);
+        }
+        assert!(tree.is_full());
+        tree.insert(7);
+        assert!(!tree.is_full());
+        tree.insert(5);
+        assert!(tree.is_full());
+    }
+}
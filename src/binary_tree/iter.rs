@@ -0,0 +1,167 @@
+use super::{BinaryTree, Node};
+use std::marker::PhantomData;
+
+/// Borrowing in-order iterator over the items of a [`BinaryTree`].
+///
+/// It yields the items from the smallest to the greatest and, as a
+/// [`DoubleEndedIterator`], from the greatest to the smallest. The next node
+/// is found through the parent links, so no stack is kept.
+pub struct Iter<'a, T: std::cmp::Ord> {
+    front: Option<*mut Node<T>>,
+    back: Option<*mut Node<T>>,
+    remaining: usize,
+    marker: PhantomData<&'a T>,
+}
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Returns an in-order iterator over the items of the `BinaryTree`, from
+    /// the smallest to the greatest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// for i in [2, 1, 3] {
+    ///     tree.insert(i);
+    /// }
+    ///
+    /// assert_eq!(tree.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(n)` for the whole traversal, `O(h)` for a single step
+    /// - **Space Complexity:** `O(1)`
+    pub fn iter(&self) -> Iter<'_, T> {
+        unsafe {
+            Iter {
+                front: self.root.map(|root| Node::leftmost(root)),
+                back: self.root.map(|root| Node::rightmost(root)),
+                remaining: self.length,
+                marker: PhantomData,
+            }
+        }
+    }
+
+    /// Returns a reverse in-order iterator over the items of the
+    /// `BinaryTree`, from the greatest to the smallest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// for i in [2, 1, 3] {
+    ///     tree.insert(i);
+    /// }
+    ///
+    /// assert_eq!(tree.iter_rev().collect::<Vec<_>>(), vec![&3, &2, &1]);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(n)` for the whole traversal, `O(h)` for a single step
+    /// - **Space Complexity:** `O(1)`
+    pub fn iter_rev(&self) -> std::iter::Rev<Iter<'_, T>> {
+        self.iter().rev()
+    }
+}
+
+impl<T: std::cmp::Ord> Iter<'_, T> {
+    /// Advances the iterator from the front, returning the node itself.
+    pub(super) fn next_node(&mut self) -> Option<*mut Node<T>> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.front.inspect(|&node| unsafe {
+            self.remaining -= 1;
+            self.front = Node::successor(node);
+        })
+    }
+}
+
+impl<'a, T: std::cmp::Ord> IntoIterator for &'a BinaryTree<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: std::cmp::Ord> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_node().map(|node| unsafe { &(*node).data })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: std::cmp::Ord> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.back.map(|node| unsafe {
+            self.remaining -= 1;
+            self.back = Node::predecessor(node);
+            &(*node).data
+        })
+    }
+}
+
+impl<T: std::cmp::Ord> ExactSizeIterator for Iter<'_, T> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_iter_is_sorted() {
+        let mut tree = BinaryTree::new();
+        assert_eq!(tree.iter().next(), None);
+
+        for i in [50, 20, 80, 10, 30, 70, 90, 25, 35, 75] {
+            tree.insert(i);
+        }
+        let items: Vec<i32> = tree.iter().copied().collect();
+        assert_eq!(items, vec![10, 20, 25, 30, 35, 50, 70, 75, 80, 90]);
+        assert_eq!(tree.iter().len(), 10);
+
+        let mut reversed = items.clone();
+        reversed.reverse();
+        assert_eq!(tree.iter_rev().copied().collect::<Vec<_>>(), reversed);
+    }
+
+    #[test]
+    fn test_iter_from_both_ends() {
+        let mut tree = BinaryTree::new();
+        for i in [4, 2, 6, 1, 3, 5, 7] {
+            tree.insert(i);
+        }
+        let mut iter = tree.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&7));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next_back(), Some(&6));
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![&3, &4, &5]);
+    }
+
+    #[test]
+    fn test_iter_on_degenerate_tree() {
+        let mut tree = BinaryTree::new();
+        for i in 0..10_000 {
+            tree.insert(i);
+        }
+        assert!(tree.iter().copied().eq(0..10_000));
+        assert!(tree.iter_rev().copied().eq((0..10_000).rev()));
+    }
+}
@@ -0,0 +1,63 @@
+use super::BinaryTree;
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Retrieves a reference to the `k`-th smallest item of the
+    /// `BinaryTree`, counting from `0` like [`Iterator::nth`].
+    ///
+    /// The nodes do not store the sizes of their subtrees, so the items are
+    /// walked in order from the smallest one through the parent links.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(&T)`: A reference to the item with `k` smaller items.
+    /// - `None`: If `k` is not below [`len`](Self::len).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// for i in [20, 10, 30] {
+    ///     tree.insert(i);
+    /// }
+    ///
+    /// assert_eq!(tree.kth_smallest(0), Some(&10));
+    /// assert_eq!(tree.kth_smallest(2), Some(&30));
+    /// assert_eq!(tree.kth_smallest(3), None);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(h + k)`, where `h` is the height of the tree
+    /// - **Space Complexity:** `O(1)`
+    pub fn kth_smallest(&self, k: usize) -> Option<&T> {
+        if k >= self.length {
+            return None;
+        }
+        self.iter().nth(k)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_kth_smallest() {
+        let mut tree = BinaryTree::new();
+        assert_eq!(tree.kth_smallest(0), None);
+
+        for i in [50, 20, 80, 10, 30, 70, 90, 25, 35, 75] {
+            tree.insert(i);
+        }
+        let items: Vec<i32> = tree.iter().copied().collect();
+        for (k, item) in items.iter().enumerate() {
+            assert_eq!(tree.kth_smallest(k), Some(item));
+        }
+        assert_eq!(tree.kth_smallest(items.len()), None);
+
+        tree.remove(&10);
+        assert_eq!(tree.kth_smallest(0), Some(&20));
+    }
+}
@@ -0,0 +1,60 @@
+use super::BinaryTree;
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Counts the leaves of the `BinaryTree`, the nodes without children.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// for i in [2, 1, 3, 4] {
+    ///     tree.insert(i);
+    /// }
+    /// assert_eq!(tree.leaf_count(), 2);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(n)`
+    /// - **Space Complexity:** `O(h)`, where `h` is the height of the tree
+    pub fn leaf_count(&self) -> usize {
+        self.fold_post_order(|_, left: Option<usize>, right| match (left, right) {
+            (None, None) => 1,
+            _ => left.unwrap_or(0) + right.unwrap_or(0),
+        })
+        .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_leaf_count() {
+        let mut tree = BinaryTree::new();
+        assert_eq!(tree.leaf_count(), 0);
+        tree.insert(4);
+        assert_eq!(tree.leaf_count(), 1);
+
+        for i in [2, 6, 1, 3, 5, 7] {
+            tree.insert(i);
+        }
+        assert_eq!(tree.leaf_count(), 4);
+
+        tree.remove(&1);
+        tree.remove(&3);
+        assert_eq!(tree.leaf_count(), 3);
+    }
+
+    #[test]
+    fn test_leaf_count_of_degenerate_tree() {
+        let mut tree = BinaryTree::new();
+        for i in 0..10_000 {
+            tree.insert(i);
+        }
+        assert_eq!(tree.leaf_count(), 1);
+    }
+}
@@ -0,0 +1,46 @@
+use super::BinaryTree;
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Returns the number of items in the `BinaryTree`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// assert_eq!(tree.len(), 0);
+    ///
+    /// tree.insert(1);
+    /// tree.insert(2);
+    /// tree.insert(2);
+    /// assert_eq!(tree.len(), 2);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(1)`
+    /// - **Space Complexity:** `O(1)`
+    pub fn len(&self) -> usize {
+        self.length
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_len_follows_insert_and_remove() {
+        let mut tree = BinaryTree::new();
+        for i in [4, 2, 6, 1, 3] {
+            tree.insert(i);
+        }
+        assert_eq!(tree.len(), 5);
+
+        tree.remove(&2);
+        assert_eq!(tree.len(), 4);
+        tree.remove(&10);
+        assert_eq!(tree.len(), 4);
+    }
+}
@@ -0,0 +1,103 @@
+use super::{BinaryTree, Node};
+use crate::queue::{ArrayQueue, Queue};
+use std::marker::PhantomData;
+
+/// Borrowing level-order iterator over the items of a [`BinaryTree`].
+///
+/// The nodes are yielded level by level from the root down, and from left to
+/// right within each level. The nodes of the next levels wait in a queue.
+pub struct LevelOrder<'a, T: std::cmp::Ord> {
+    queue: ArrayQueue<*mut Node<T>>,
+    remaining: usize,
+    marker: PhantomData<&'a T>,
+}
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Returns a level-order (breadth-first) iterator over the items of the
+    /// `BinaryTree`, from the root down and from left to right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// for i in [4, 2, 6, 1, 3] {
+    ///     tree.insert(i);
+    /// }
+    ///
+    /// assert_eq!(tree.level_order().collect::<Vec<_>>(), vec![&4, &2, &6, &1, &3]);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(n)`
+    /// - **Space Complexity:** `O(w)`, where `w` is the widest level of the tree
+    pub fn level_order(&self) -> LevelOrder<'_, T> {
+        let mut queue = ArrayQueue::new();
+        if let Some(root) = self.root {
+            queue.enqueue(root);
+        }
+        LevelOrder {
+            queue,
+            remaining: self.length,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: std::cmp::Ord> LevelOrder<'_, T> {
+    /// Advances the iterator, returning the node itself.
+    pub(super) fn next_node(&mut self) -> Option<*mut Node<T>> {
+        let node = self.queue.dequeue()?;
+        self.remaining -= 1;
+        unsafe {
+            for child in [(*node).left, (*node).right].into_iter().flatten() {
+                self.queue.enqueue(child);
+            }
+        }
+        Some(node)
+    }
+}
+
+impl<'a, T: std::cmp::Ord> Iterator for LevelOrder<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_node().map(|node| unsafe { &(*node).data })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: std::cmp::Ord> ExactSizeIterator for LevelOrder<'_, T> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_level_order() {
+        let mut tree = BinaryTree::new();
+        assert_eq!(tree.level_order().next(), None);
+
+        for i in [4, 2, 6, 1, 3, 5, 7] {
+            tree.insert(i);
+        }
+        let items: Vec<i32> = tree.level_order().copied().collect();
+        assert_eq!(items, vec![4, 2, 6, 1, 3, 5, 7]);
+        assert_eq!(tree.level_order().len(), 7);
+    }
+
+    #[test]
+    fn test_level_order_with_one_sided_nodes() {
+        let mut tree = BinaryTree::new();
+        for i in [10, 5, 3, 4, 15, 20, 17] {
+            tree.insert(i);
+        }
+        let items: Vec<i32> = tree.level_order().copied().collect();
+        assert_eq!(items, vec![10, 5, 15, 3, 20, 4, 17]);
+    }
+}
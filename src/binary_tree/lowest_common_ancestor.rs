@@ -0,0 +1,106 @@
+use super::{BinaryTree, Node};
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Retrieves a reference to the item of the lowest common ancestor of
+    /// the nodes holding `a` and `b`, the deepest node with both below it.
+    ///
+    /// A node counts as its own ancestor, so if `b` is below `a` the result
+    /// is `a`. Both nodes are found first, then the deeper one climbs the
+    /// parent links to the depth of the other and both climb together until
+    /// they meet.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(&T)`: A reference to the item of the common ancestor.
+    /// - `None`: If `a` or `b` is not in the tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// for i in [4, 2, 6, 1, 3, 5] {
+    ///     tree.insert(i);
+    /// }
+    ///
+    /// assert_eq!(tree.lowest_common_ancestor(&1, &3), Some(&2));
+    /// assert_eq!(tree.lowest_common_ancestor(&3, &5), Some(&4));
+    /// assert_eq!(tree.lowest_common_ancestor(&2, &3), Some(&2));
+    /// assert_eq!(tree.lowest_common_ancestor(&1, &7), None);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(h)`, where `h` is the height of the tree
+    /// - **Space Complexity:** `O(1)`
+    pub fn lowest_common_ancestor(&self, a: &T, b: &T) -> Option<&T> {
+        let mut a = self.find(a)?;
+        let mut b = self.find(b)?;
+        unsafe {
+            let mut a_depth = Self::depth(a);
+            let mut b_depth = Self::depth(b);
+            while a_depth > b_depth {
+                a = (*a).parent.expect("a node below the root has a parent");
+                a_depth -= 1;
+            }
+            while b_depth > a_depth {
+                b = (*b).parent.expect("a node below the root has a parent");
+                b_depth -= 1;
+            }
+            while a != b {
+                a = (*a).parent.expect("nodes of one tree meet at the root");
+                b = (*b).parent.expect("nodes of one tree meet at the root");
+            }
+            Some(&(*a).data)
+        }
+    }
+
+    /// Counts the parent links from `node` up to the root.
+    unsafe fn depth(mut node: *mut Node<T>) -> usize {
+        let mut depth = 0;
+        while let Some(parent) = (*node).parent {
+            node = parent;
+            depth += 1;
+        }
+        depth
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_lowest_common_ancestor() {
+        let mut tree = BinaryTree::new();
+        assert_eq!(tree.lowest_common_ancestor(&1, &1), None);
+
+        for i in [50, 20, 80, 10, 30, 70, 90, 25, 35, 75] {
+            tree.insert(i);
+        }
+        assert_eq!(tree.lowest_common_ancestor(&25, &35), Some(&30));
+        assert_eq!(tree.lowest_common_ancestor(&10, &35), Some(&20));
+        assert_eq!(tree.lowest_common_ancestor(&25, &75), Some(&50));
+        assert_eq!(tree.lowest_common_ancestor(&75, &90), Some(&80));
+        assert_eq!(tree.lowest_common_ancestor(&70, &75), Some(&70));
+        assert_eq!(tree.lowest_common_ancestor(&35, &35), Some(&35));
+        assert_eq!(tree.lowest_common_ancestor(&35, &36), None);
+    }
+
+    #[test]
+    fn test_lowest_common_ancestor_is_symmetric() {
+        let mut tree = BinaryTree::new();
+        for i in [8, 4, 12, 2, 6, 10, 14, 1, 3, 5, 7] {
+            tree.insert(i);
+        }
+        for a in 1..=14 {
+            for b in 1..=14 {
+                assert_eq!(
+                    tree.lowest_common_ancestor(&a, &b),
+                    tree.lowest_common_ancestor(&b, &a)
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use super::{BinaryTree, Node};
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Retrieves a reference to the greatest item of the `BinaryTree`.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(&T)`: A reference to the greatest item.
+    /// - `None`: If the tree is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// assert_eq!(tree.max(), None);
+    ///
+    /// tree.insert(3);
+    /// tree.insert(1);
+    /// tree.insert(2);
+    /// assert_eq!(tree.max(), Some(&3));
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(h)`, where `h` is the height of the tree
+    /// - **Space Complexity:** `O(1)`
+    pub fn max(&self) -> Option<&T> {
+        self.root
+            .map(|root| unsafe { &(*Node::rightmost(root)).data })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_max_follows_removals() {
+        let mut tree = BinaryTree::new();
+        for i in [5, 3, 8, 9, 7] {
+            tree.insert(i);
+        }
+        assert_eq!(tree.max(), Some(&9));
+
+        tree.remove(&9);
+        assert_eq!(tree.max(), Some(&8));
+        tree.remove(&8);
+        tree.remove(&5);
+        assert_eq!(tree.max(), Some(&7));
+    }
+}
@@ -0,0 +1,77 @@
+use super::BinaryTree;
+use crate::queue::{ArrayQueue, Queue};
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Gets the width of the widest level of the `BinaryTree`, the largest
+    /// number of nodes at the same depth.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// for i in [4, 2, 6, 1, 7] {
+    ///     tree.insert(i);
+    /// }
+    /// assert_eq!(tree.max_width(), 2);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(n)`
+    /// - **Space Complexity:** `O(w)`, where `w` is the width of the widest level
+    pub fn max_width(&self) -> usize {
+        let mut level = ArrayQueue::new();
+        if let Some(root) = self.root {
+            level.enqueue(root);
+        }
+        let mut widest = 0;
+        while !level.is_empty() {
+            let width = level.len();
+            widest = widest.max(width);
+            // Replace the current level with the next one
+            for _ in 0..width {
+                let node = level.dequeue().expect("the level has `width` nodes");
+                unsafe {
+                    for child in [(*node).left, (*node).right].into_iter().flatten() {
+                        level.enqueue(child);
+                    }
+                }
+            }
+        }
+        widest
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_max_width() {
+        let mut tree = BinaryTree::new();
+        assert_eq!(tree.max_width(), 0);
+        tree.insert(4);
+        assert_eq!(tree.max_width(), 1);
+
+        for i in [2, 6, 1, 3, 5, 7] {
+            tree.insert(i);
+        }
+        assert_eq!(tree.max_width(), 4);
+
+        for i in [1, 3, 5] {
+            tree.remove(&i);
+        }
+        assert_eq!(tree.max_width(), 2);
+    }
+
+    #[test]
+    fn test_max_width_of_degenerate_tree() {
+        let mut tree = BinaryTree::new();
+        for i in 0..10_000 {
+            tree.insert(i);
+        }
+        assert_eq!(tree.max_width(), 1);
+    }
+}
@@ -0,0 +1,53 @@
+use super::{BinaryTree, Node};
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Retrieves a reference to the smallest item of the `BinaryTree`.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(&T)`: A reference to the smallest item.
+    /// - `None`: If the tree is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// assert_eq!(tree.min(), None);
+    ///
+    /// tree.insert(3);
+    /// tree.insert(1);
+    /// tree.insert(2);
+    /// assert_eq!(tree.min(), Some(&1));
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(h)`, where `h` is the height of the tree
+    /// - **Space Complexity:** `O(1)`
+    pub fn min(&self) -> Option<&T> {
+        self.root
+            .map(|root| unsafe { &(*Node::leftmost(root)).data })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_min_follows_removals() {
+        let mut tree = BinaryTree::new();
+        for i in [5, 3, 8, 1, 4] {
+            tree.insert(i);
+        }
+        assert_eq!(tree.min(), Some(&1));
+
+        tree.remove(&1);
+        assert_eq!(tree.min(), Some(&3));
+        tree.remove(&3);
+        tree.remove(&5);
+        assert_eq!(tree.min(), Some(&4));
+    }
+}
@@ -8,9 +8,30 @@
 /// - `T`: The type of data stored in the tree nodes. This type must implement
 ///   the `Ord` trait to allow comparisons, enabling insertion and traversal.
 ///
+/// The tree is kept as a binary search tree: every item in the left subtree
+/// of a node is smaller than the node, every item in the right subtree is
+/// greater, and equal items are stored only once.
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::binary_tree::BinaryTree;
+///
+/// let mut tree = BinaryTree::new();
+/// tree.insert(5);
+/// tree.insert(2);
+/// tree.insert(8);
+///
+/// assert!(tree.contains(&2));
+/// assert_eq!(tree.min(), Some(&2));
+/// assert_eq!(tree.remove(&5), Some(5));
+/// assert_eq!(tree.len(), 2);
+/// ```
+///
 /// # Fields
 /// - `root`: A mutable pointer to the root node of the tree. If the tree is
 ///   empty, this will be `None`.
+/// - `length`: The current number of nodes in the tree.
 ///
 /// # Safety
 /// Since the `root` is a raw pointer (`*mut`), care must be taken when working
@@ -19,30 +40,89 @@
 ///
 pub struct BinaryTree<T: std::cmp::Ord> {
     root: Option<*mut Node<T>>,
+    length: usize,
 }
 
 /// A node in the [`BinaryTree`].
 ///
 /// Represents an individual element of the binary tree, storing data and
-/// maintaining a reference to its parent node.
+/// maintaining references to its children and its parent node.
+///
+/// The self-balancing trees of the crate, like
+/// [`AvlTree`](crate::avl_tree::AvlTree), are built on the same nodes, so
+/// they share the navigation through the parent links, the bounds and the
+/// [`range::Range`] iterator.
 ///
 /// # Type Parameters
-/// - `T`: The type of the data stored in the node. This type must implement
-///   the `Ord` trait to enable comparisons for tree operations.
+/// - `T`: The type of the data stored in the node. The [`BinaryTree`]
+///   requires it to implement the `Ord` trait to enable comparisons for tree
+///   operations.
+/// - `M`: The balance information a tree keeps in each node, like the height
+///   for an AVL tree. The [`BinaryTree`] keeps none, `()`.
 ///
 /// # Fields
 /// - `data`: The value stored in the node.
+/// - `meta`: The balance information of the node.
 /// - `parent`: An optional mutable pointer to the parent node. This allows
 ///   navigating upward in the tree hierarchy.
+/// - `left`: An optional mutable pointer to the left child, holding smaller items.
+/// - `right`: An optional mutable pointer to the right child, holding greater items.
 ///
 /// # Safety
-/// The use of raw pointers (`*mut`) for the `parent` field requires careful
+/// The use of raw pointers (`*mut`) for the `parent`, `left` and `right` fields requires careful
 /// handling to avoid dereferencing null or dangling pointers. Ensure proper
 /// memory management to prevent undefined behavior.
 ///
-struct Node<T: std::cmp::Ord> {
-    data: T,
-    parent: Option<*mut Node<T>>,
+pub(crate) struct Node<T, M = ()> {
+    pub(crate) data: T,
+    pub(crate) meta: M,
+    pub(crate) parent: Option<*mut Node<T, M>>,
+    pub(crate) left: Option<*mut Node<T, M>>,
+    pub(crate) right: Option<*mut Node<T, M>>,
 }
 
+pub mod bounds;
+pub mod ceiling;
+pub mod contains;
+pub mod depth_of;
+pub mod deserialize;
+pub mod diameter;
+pub mod display;
+pub mod drop;
+pub mod floor;
+pub mod from_level_and_in_order;
+pub mod from_post_and_in_order;
+pub mod from_pre_and_in_order;
+pub mod from_pre_order;
+pub mod from_str;
+pub mod get;
+pub mod height;
+pub mod insert;
+pub mod into_iter;
+pub mod is_balanced;
+pub mod is_bst;
+pub mod is_complete;
+pub mod is_empty;
+pub mod is_full;
+pub mod iter;
+pub mod kth_smallest;
+pub mod leaf_count;
+pub mod len;
+pub mod level_order;
+pub mod lowest_common_ancestor;
+pub mod max;
+pub mod max_width;
+pub mod min;
+pub mod morris;
 pub mod new;
+pub mod node;
+pub mod post_order;
+pub mod pre_order;
+pub mod predecessor;
+pub mod quote;
+pub mod range;
+pub mod remove;
+pub mod render;
+pub mod serialize;
+pub mod size;
+pub mod successor;
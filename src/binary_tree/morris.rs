@@ -0,0 +1,198 @@
+use super::{BinaryTree, Node};
+use std::marker::PhantomData;
+
+/// In-order iterator over a [`BinaryTree`] using Morris traversal.
+///
+/// Instead of a stack or the parent links, the traversal temporarily points
+/// the empty right child of each node's in-order predecessor back at the
+/// node, a "thread" it follows to climb back up once the left subtree is
+/// done. Every thread is removed on the second visit, so the tree is back to
+/// its original shape once the traversal ends.
+///
+/// While the iterator is alive the tree is mutably borrowed and looks empty.
+/// Dropping the iterator early finishes the traversal to remove the remaining
+/// threads before giving the nodes back to the tree. If the iterator is
+/// leaked instead, the tree stays empty and its nodes are leaked too.
+pub struct MorrisInOrder<'a, T: std::cmp::Ord> {
+    tree: &'a mut BinaryTree<T>,
+    root: Option<*mut Node<T>>,
+    current: Option<*mut Node<T>>,
+    length: usize,
+    remaining: usize,
+    marker: PhantomData<&'a T>,
+}
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Returns an in-order iterator over the items of the `BinaryTree` that
+    /// uses `O(1)` extra space by temporarily threading the nodes.
+    ///
+    /// See [`MorrisInOrder`] for how the threads are added and removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// for i in [2, 1, 3] {
+    ///     tree.insert(i);
+    /// }
+    ///
+    /// assert_eq!(tree.morris_in_order().collect::<Vec<_>>(), vec![&1, &2, &3]);
+    /// assert_eq!(tree.len(), 3);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(n)` for the whole traversal, as every edge
+    ///   is walked at most three times
+    /// - **Space Complexity:** `O(1)`
+    pub fn morris_in_order(&mut self) -> MorrisInOrder<'_, T> {
+        let root = self.root.take();
+        let length = std::mem::replace(&mut self.length, 0);
+        MorrisInOrder {
+            tree: self,
+            root,
+            current: root,
+            length,
+            remaining: length,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: std::cmp::Ord> Iterator for MorrisInOrder<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(node) = self.current {
+            unsafe {
+                let Some(left) = (*node).left else {
+                    self.current = (*node).right;
+                    self.remaining -= 1;
+                    return Some(&(*node).data);
+                };
+
+                let mut predecessor = left;
+                while let Some(right) = (*predecessor).right {
+                    if right == node {
+                        break;
+                    }
+                    predecessor = right;
+                }
+
+                if (*predecessor).right.is_none() {
+                    // First visit: thread the way back and go left
+                    (*predecessor).right = Some(node);
+                    self.current = Some(left);
+                } else {
+                    // Back from the left subtree: remove the thread
+                    (*predecessor).right = None;
+                    self.current = (*node).right;
+                    self.remaining -= 1;
+                    return Some(&(*node).data);
+                }
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: std::cmp::Ord> ExactSizeIterator for MorrisInOrder<'_, T> {}
+
+impl<T: std::cmp::Ord> Drop for MorrisInOrder<'_, T> {
+    /// Finishes the traversal to remove every thread, then gives the nodes
+    /// back to the tree.
+    fn drop(&mut self) {
+        while self.next().is_some() {}
+        self.tree.root = self.root;
+        self.tree.length = self.length;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Checks that every child links back to its parent.
+    fn assert_linked<T: Ord>(tree: &BinaryTree<T>) {
+        let mut stack: Vec<*mut Node<T>> = tree.root.into_iter().collect();
+        let mut count = 0;
+        while let Some(node) = stack.pop() {
+            count += 1;
+            unsafe {
+                for child in [(*node).left, (*node).right].into_iter().flatten() {
+                    assert_eq!((*child).parent, Some(node));
+                    stack.push(child);
+                }
+            }
+        }
+        assert_eq!(count, tree.len());
+    }
+
+    #[test]
+    fn test_morris_in_order() {
+        let mut tree = BinaryTree::new();
+        assert_eq!(tree.morris_in_order().next(), None);
+
+        for i in [50, 20, 80, 10, 30, 70, 90, 25, 35, 75] {
+            tree.insert(i);
+        }
+        let items: Vec<i32> = tree.morris_in_order().copied().collect();
+        assert_eq!(items, vec![10, 20, 25, 30, 35, 50, 70, 75, 80, 90]);
+        assert_linked(&tree);
+        assert!(tree.iter().copied().eq(items));
+    }
+
+    #[test]
+    fn test_early_drop_restores_the_tree() {
+        let mut tree = BinaryTree::new();
+        for i in [4, 2, 6, 1, 3, 5, 7] {
+            tree.insert(i);
+        }
+
+        let mut iter = tree.morris_in_order();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.len(), 5);
+        drop(iter);
+
+        assert_eq!(tree.len(), 7);
+        assert_linked(&tree);
+        assert!(tree.pre_order().copied().eq([4, 2, 1, 3, 6, 5, 7]));
+    }
+
+    #[test]
+    fn test_leaked_iterator_leaves_the_tree_empty() {
+        let mut tree = BinaryTree::new();
+        for i in [2, 1, 3] {
+            tree.insert(i);
+        }
+
+        let mut iter = tree.morris_in_order();
+        assert_eq!(iter.next(), Some(&1));
+        std::mem::forget(iter);
+
+        assert!(tree.is_empty());
+        assert_eq!(tree.iter().next(), None);
+    }
+
+    #[test]
+    fn test_morris_on_degenerate_trees() {
+        let mut increasing = BinaryTree::new();
+        let mut decreasing = BinaryTree::new();
+        for i in 0..10_000 {
+            increasing.insert(i);
+            decreasing.insert(-i);
+        }
+        assert!(increasing.morris_in_order().copied().eq(0..10_000));
+        assert!(decreasing
+            .morris_in_order()
+            .copied()
+            .eq((0..10_000).map(|i| i - 9_999)));
+    }
+}
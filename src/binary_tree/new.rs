@@ -6,12 +6,21 @@ impl<T: std::cmp::Ord> BinaryTree<T> {
     /// # Returns
     /// A new instance of [`BinaryTree`] with no nodes.
     pub fn new() -> BinaryTree<T> {
-        return BinaryTree { root: None };
+        BinaryTree {
+            root: None,
+            length: 0,
+        }
+    }
+}
+
+impl<T: std::cmp::Ord> Default for BinaryTree<T> {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
 impl<T: std::cmp::Ord> Node<T> {
-    /// Creates a new node with the given data and optional parent reference.
+    /// Creates a new node without children, with the given data and optional parent reference.
     ///
     /// # Arguments
     /// - `data`: The value to store in the node.
@@ -22,10 +31,14 @@ impl<T: std::cmp::Ord> Node<T> {
     ///
     /// # Safety
     /// Ensure that the `parent` pointer, if provided, is valid and points to an existing [`Node`].
-    ///
-    /// ```rust
     pub fn new(data: T, parent: Option<*mut Node<T>>) -> Node<T> {
-        Node { data, parent }
+        Node {
+            data,
+            meta: (),
+            parent,
+            left: None,
+            right: None,
+        }
     }
 }
 
@@ -41,6 +54,7 @@ mod tests {
             tree.root.is_none(),
             "Newly created binary tree should have no root"
         );
+        assert_eq!(tree.length, 0);
     }
 
     #[test]
@@ -49,6 +63,7 @@ mod tests {
         let node = Node::new(42, None);
         assert_eq!(node.data, 42, "Node data should be 42");
         assert!(node.parent.is_none(), "Node parent should be None");
+        assert!(node.left.is_none() && node.right.is_none());
     }
 
     #[test]
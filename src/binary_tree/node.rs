@@ -0,0 +1,119 @@
+use super::Node;
+
+impl<T, M> Node<T, M> {
+    /// Finds the node holding the smallest item of the subtree rooted at `node`.
+    ///
+    /// # Safety
+    /// `node` and every node below it must be valid.
+    ///
+    /// # Complexity
+    /// - `O(h)`, where `h` is the height of the subtree
+    pub(crate) unsafe fn leftmost(mut node: *mut Node<T, M>) -> *mut Node<T, M> {
+        while let Some(left) = (*node).left {
+            node = left;
+        }
+        node
+    }
+
+    /// Finds the node holding the greatest item of the subtree rooted at `node`.
+    ///
+    /// # Safety
+    /// `node` and every node below it must be valid.
+    ///
+    /// # Complexity
+    /// - `O(h)`, where `h` is the height of the subtree
+    pub(crate) unsafe fn rightmost(mut node: *mut Node<T, M>) -> *mut Node<T, M> {
+        while let Some(right) = (*node).right {
+            node = right;
+        }
+        node
+    }
+
+    /// Finds the node holding the next greater item after `node`, walking up
+    /// the parent links when `node` has no right subtree.
+    ///
+    /// # Safety
+    /// `node`, its ancestors and every node below them must be valid.
+    ///
+    /// # Complexity
+    /// - `O(h)`, where `h` is the height of the tree
+    pub(crate) unsafe fn successor(mut node: *mut Node<T, M>) -> Option<*mut Node<T, M>> {
+        if let Some(right) = (*node).right {
+            return Some(Node::leftmost(right));
+        }
+        while let Some(parent) = (*node).parent {
+            if (*parent).left == Some(node) {
+                return Some(parent);
+            }
+            node = parent;
+        }
+        None
+    }
+
+    /// Finds the node holding the next smaller item before `node`, walking up
+    /// the parent links when `node` has no left subtree.
+    ///
+    /// # Safety
+    /// `node`, its ancestors and every node below them must be valid.
+    ///
+    /// # Complexity
+    /// - `O(h)`, where `h` is the height of the tree
+    pub(crate) unsafe fn predecessor(mut node: *mut Node<T, M>) -> Option<*mut Node<T, M>> {
+        if let Some(left) = (*node).left {
+            return Some(Node::rightmost(left));
+        }
+        while let Some(parent) = (*node).parent {
+            if (*parent).right == Some(node) {
+                return Some(parent);
+            }
+            node = parent;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Node;
+
+    #[test]
+    fn test_leftmost_and_rightmost() {
+        let root = Box::into_raw(Box::new(Node::new(5, None)));
+        let left = Box::into_raw(Box::new(Node::new(2, Some(root))));
+        let right = Box::into_raw(Box::new(Node::new(8, Some(root))));
+        unsafe {
+            (*root).left = Some(left);
+            (*root).right = Some(right);
+
+            assert_eq!((*Node::leftmost(root)).data, 2);
+            assert_eq!((*Node::rightmost(root)).data, 8);
+            assert_eq!(Node::leftmost(right), right);
+
+            for node in [left, right, root] {
+                drop(Box::from_raw(node));
+            }
+        }
+    }
+
+    #[test]
+    fn test_successor_and_predecessor() {
+        let root = Box::into_raw(Box::new(Node::new(5, None)));
+        let left = Box::into_raw(Box::new(Node::new(2, Some(root))));
+        let middle = Box::into_raw(Box::new(Node::new(3, Some(left))));
+        unsafe {
+            (*root).left = Some(left);
+            (*left).right = Some(middle);
+
+            assert_eq!(Node::successor(left), Some(middle));
+            assert_eq!(Node::successor(middle), Some(root));
+            assert_eq!(Node::successor(root), None);
+            assert_eq!(Node::predecessor(root), Some(middle));
+            assert_eq!(Node::predecessor(middle), Some(left));
+            assert_eq!(Node::predecessor(left), None);
+
+            for node in [middle, left, root] {
+                drop(Box::from_raw(node));
+            }
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use super::{BinaryTree, Node};
+use std::marker::PhantomData;
+
+/// Borrowing post-order iterator over the items of a [`BinaryTree`].
+///
+/// Every node is yielded after its left and right subtrees. The next node is
+/// found through the parent links, so no stack is kept.
+pub struct PostOrder<'a, T: std::cmp::Ord> {
+    next: Option<*mut Node<T>>,
+    remaining: usize,
+    marker: PhantomData<&'a T>,
+}
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Returns a post-order iterator over the items of the `BinaryTree`: the
+    /// left subtree of each node, then its right subtree, then the node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// for i in [4, 2, 6, 1, 3] {
+    ///     tree.insert(i);
+    /// }
+    ///
+    /// assert_eq!(tree.post_order().collect::<Vec<_>>(), vec![&1, &3, &2, &6, &4]);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(n)` for the whole traversal, `O(h)` for a single step
+    /// - **Space Complexity:** `O(1)`
+    pub fn post_order(&self) -> PostOrder<'_, T> {
+        PostOrder {
+            next: self.root.map(|root| unsafe { PostOrder::first(root) }),
+            remaining: self.length,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Combines the results of the subtrees bottom-up: `combine` is called
+    /// on every node with the results of its left and right subtrees, `None`
+    /// for a missing child, and the result for the root is returned.
+    ///
+    /// The pending results wait on a stack instead of the call stack, so
+    /// degenerate trees need `O(h)` heap space and no recursion.
+    pub(super) fn fold_post_order<A>(
+        &self,
+        mut combine: impl FnMut(&T, Option<A>, Option<A>) -> A,
+    ) -> Option<A> {
+        let mut results = Vec::new();
+        let mut order = self.post_order();
+        while let Some(node) = order.next_node() {
+            unsafe {
+                // The right subtree was finished last, so its result is on top
+                let right = (*node).right.and_then(|_| results.pop());
+                let left = (*node).left.and_then(|_| results.pop());
+                results.push(combine(&(*node).data, left, right));
+            }
+        }
+        results.pop()
+    }
+}
+
+impl<T: std::cmp::Ord> PostOrder<'_, T> {
+    /// Advances the iterator, returning the node itself.
+    pub(super) fn next_node(&mut self) -> Option<*mut Node<T>> {
+        let node = self.next?;
+        self.remaining -= 1;
+        self.next = unsafe { Self::following(node) };
+        Some(node)
+    }
+
+    /// Finds the first node visited in the subtree rooted at `node`, the
+    /// leaf reached by going left whenever possible and right otherwise.
+    unsafe fn first(mut node: *mut Node<T>) -> *mut Node<T> {
+        while let Some(child) = (*node).left.or((*node).right) {
+            node = child;
+        }
+        node
+    }
+
+    /// Finds the node visited after `node`: its right sibling's subtree if
+    /// `node` is a left child, its parent otherwise.
+    unsafe fn following(node: *mut Node<T>) -> Option<*mut Node<T>> {
+        let parent = (*node).parent?;
+        match (*parent).right {
+            Some(right) if (*parent).left == Some(node) => Some(Self::first(right)),
+            _ => Some(parent),
+        }
+    }
+}
+
+impl<'a, T: std::cmp::Ord> Iterator for PostOrder<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_node().map(|node| unsafe { &(*node).data })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: std::cmp::Ord> ExactSizeIterator for PostOrder<'_, T> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_post_order() {
+        let mut tree = BinaryTree::new();
+        assert_eq!(tree.post_order().next(), None);
+
+        for i in [4, 2, 6, 1, 3, 5, 7] {
+            tree.insert(i);
+        }
+        let items: Vec<i32> = tree.post_order().copied().collect();
+        assert_eq!(items, vec![1, 3, 2, 5, 7, 6, 4]);
+        assert_eq!(tree.post_order().len(), 7);
+    }
+
+    #[test]
+    fn test_post_order_with_one_sided_nodes() {
+        let mut tree = BinaryTree::new();
+        for i in [10, 5, 3, 4, 15, 20, 17] {
+            tree.insert(i);
+        }
+        let items: Vec<i32> = tree.post_order().copied().collect();
+        assert_eq!(items, vec![4, 3, 5, 17, 20, 15, 10]);
+    }
+
+    #[test]
+    fn test_post_order_on_degenerate_tree() {
+        let mut tree = BinaryTree::new();
+        for i in 0..10_000 {
+            tree.insert(i);
+        }
+        assert!(tree.post_order().copied().eq((0..10_000).rev()));
+    }
+}
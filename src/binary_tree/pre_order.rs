@@ -0,0 +1,120 @@
+use super::{BinaryTree, Node};
+use std::marker::PhantomData;
+
+/// Borrowing pre-order iterator over the items of a [`BinaryTree`].
+///
+/// Every node is yielded before its left subtree, which comes before its
+/// right subtree. The next node is found through the parent links, so no
+/// stack is kept.
+pub struct PreOrder<'a, T: std::cmp::Ord> {
+    next: Option<*mut Node<T>>,
+    remaining: usize,
+    marker: PhantomData<&'a T>,
+}
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Returns a pre-order iterator over the items of the `BinaryTree`: each
+    /// node, then its left subtree, then its right subtree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// for i in [4, 2, 6, 1, 3] {
+    ///     tree.insert(i);
+    /// }
+    ///
+    /// assert_eq!(tree.pre_order().collect::<Vec<_>>(), vec![&4, &2, &1, &3, &6]);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(n)` for the whole traversal, `O(h)` for a single step
+    /// - **Space Complexity:** `O(1)`
+    pub fn pre_order(&self) -> PreOrder<'_, T> {
+        PreOrder {
+            next: self.root,
+            remaining: self.length,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: std::cmp::Ord> PreOrder<'_, T> {
+    /// Advances the iterator, returning the node itself.
+    pub(super) fn next_node(&mut self) -> Option<*mut Node<T>> {
+        let node = self.next?;
+        self.remaining -= 1;
+        self.next = unsafe { Self::following(node) };
+        Some(node)
+    }
+
+    /// Finds the node visited after `node`: its first child or, for a leaf,
+    /// the right sibling of its closest ancestor that has one.
+    unsafe fn following(node: *mut Node<T>) -> Option<*mut Node<T>> {
+        if let Some(child) = (*node).left.or((*node).right) {
+            return Some(child);
+        }
+        let mut child = node;
+        while let Some(parent) = (*child).parent {
+            if (*parent).left == Some(child) && (*parent).right.is_some() {
+                return (*parent).right;
+            }
+            child = parent;
+        }
+        None
+    }
+}
+
+impl<'a, T: std::cmp::Ord> Iterator for PreOrder<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_node().map(|node| unsafe { &(*node).data })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: std::cmp::Ord> ExactSizeIterator for PreOrder<'_, T> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_pre_order() {
+        let mut tree = BinaryTree::new();
+        assert_eq!(tree.pre_order().next(), None);
+
+        for i in [4, 2, 6, 1, 3, 5, 7] {
+            tree.insert(i);
+        }
+        let items: Vec<i32> = tree.pre_order().copied().collect();
+        assert_eq!(items, vec![4, 2, 1, 3, 6, 5, 7]);
+        assert_eq!(tree.pre_order().len(), 7);
+    }
+
+    #[test]
+    fn test_pre_order_with_one_sided_nodes() {
+        let mut tree = BinaryTree::new();
+        for i in [10, 5, 3, 4, 15, 20, 17] {
+            tree.insert(i);
+        }
+        let items: Vec<i32> = tree.pre_order().copied().collect();
+        assert_eq!(items, vec![10, 5, 3, 4, 15, 20, 17]);
+    }
+
+    #[test]
+    fn test_pre_order_on_degenerate_tree() {
+        let mut tree = BinaryTree::new();
+        for i in (0..10_000).rev() {
+            tree.insert(i);
+        }
+        assert!(tree.pre_order().copied().eq((0..10_000).rev()));
+    }
+}
@@ -0,0 +1,61 @@
+use super::BinaryTree;
+use std::ops::Bound;
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Retrieves a reference to the item of the `BinaryTree` right before
+    /// `data` in order, the greatest item strictly smaller than it.
+    ///
+    /// `data` does not need to be in the tree.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(&T)`: A reference to the closest smaller item.
+    /// - `None`: If no item is smaller than `data`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// for i in [20, 10, 30] {
+    ///     tree.insert(i);
+    /// }
+    ///
+    /// assert_eq!(tree.predecessor(&20), Some(&10));
+    /// assert_eq!(tree.predecessor(&25), Some(&20));
+    /// assert_eq!(tree.predecessor(&10), None);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(h)`, where `h` is the height of the tree
+    /// - **Space Complexity:** `O(1)`
+    pub fn predecessor(&self, data: &T) -> Option<&T> {
+        self.upper_bound(Bound::Excluded(data))
+            .map(|node| unsafe { &(*node).data })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_predecessor() {
+        let mut tree = BinaryTree::new();
+        assert_eq!(tree.predecessor(&1), None);
+
+        for i in [40, 20, 60, 10, 30, 50, 70] {
+            tree.insert(i);
+        }
+        // Every item is preceded by the one before it in order
+        let items: Vec<i32> = tree.iter().copied().collect();
+        for pair in items.windows(2) {
+            assert_eq!(tree.predecessor(&pair[1]), Some(&pair[0]));
+        }
+        assert_eq!(tree.predecessor(&10), None);
+        assert_eq!(tree.predecessor(&45), Some(&40));
+        assert_eq!(tree.predecessor(&100), Some(&70));
+    }
+}
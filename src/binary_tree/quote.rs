@@ -0,0 +1,132 @@
+use std::borrow::Cow;
+use std::fmt;
+
+/// Characters with a meaning in the text notations of a `BinaryTree`, which
+/// an item can only hold between quotes.
+const SYMBOLS: [char; 6] = ['(', ')', ',', '[', ']', '"'];
+
+/// Writes the text of an item for the text notations of a `BinaryTree`.
+///
+/// An item that would be read back as something else is written between
+/// double quotes, with a `\` before every `"` and `\` inside it: one that is
+/// empty, is `null`, has spaces around it or holds one of `(),[]"`.
+pub(super) fn write_item(f: &mut impl fmt::Write, item: &str) -> fmt::Result {
+    let plain =
+        !item.is_empty() && item != "null" && item.trim() == item && !item.contains(SYMBOLS);
+    if plain {
+        return f.write_str(item);
+    }
+    f.write_char('"')?;
+    for symbol in item.chars() {
+        if matches!(symbol, '"' | '\\') {
+            f.write_char('\\')?;
+        }
+        f.write_char(symbol)?;
+    }
+    f.write_char('"')
+}
+
+/// Finds the `symbols` of `text` that are not inside a quoted item, with
+/// their positions.
+///
+/// A quote only opens a quoted item when it is the first character of the
+/// item, after any spaces.
+pub(super) fn symbols_outside_quotes<'a>(
+    text: &'a str,
+    symbols: &'a [char],
+) -> impl Iterator<Item = (usize, char)> + 'a {
+    let mut quoted = false;
+    let mut escaped = false;
+    let mut item_start = true;
+    text.char_indices().filter(move |&(_, symbol)| {
+        if quoted {
+            match symbol {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => quoted = false,
+                _ => {}
+            }
+            return false;
+        }
+        if symbols.contains(&symbol) {
+            item_start = true;
+            return true;
+        }
+        if symbol == '"' && item_start {
+            quoted = true;
+        }
+        if !symbol.is_whitespace() {
+            item_start = false;
+        }
+        false
+    })
+}
+
+/// Reads back the text of an item written by [`write_item`], taking out the
+/// quotes and the `\` before the quoted characters. `item` must already be
+/// trimmed.
+///
+/// # Errors
+///
+/// - `"Invalid item"`: A quoted item is not closed, or goes on after its
+///   closing quote.
+pub(super) fn unquote(item: &str) -> Result<Cow<'_, str>, &'static str> {
+    let Some(quoted) = item.strip_prefix('"') else {
+        return Ok(Cow::Borrowed(item));
+    };
+    let mut text = String::with_capacity(quoted.len());
+    let mut symbols = quoted.chars();
+    while let Some(symbol) = symbols.next() {
+        match symbol {
+            '\\' => text.push(symbols.next().ok_or("Invalid item")?),
+            '"' if symbols.as_str().is_empty() => return Ok(Cow::Owned(text)),
+            '"' => return Err("Invalid item"),
+            symbol => text.push(symbol),
+        }
+    }
+    Err("Invalid item")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn written(item: &str) -> String {
+        let mut text = String::new();
+        write_item(&mut text, item).unwrap();
+        text
+    }
+
+    #[test]
+    fn test_write_item() {
+        assert_eq!(written("-12"), "-12");
+        assert_eq!(written("two words"), "two words");
+        assert_eq!(written("back\\slash"), "back\\slash");
+        assert_eq!(written(""), "\"\"");
+        assert_eq!(written("null"), "\"null\"");
+        assert_eq!(written(" padded"), "\" padded\"");
+        assert_eq!(written("a,b"), "\"a,b\"");
+        assert_eq!(written("say \"hi\""), "\"say \\\"hi\\\"\"");
+        assert_eq!(written("(x)\\"), "\"(x)\\\\\"");
+    }
+
+    #[test]
+    fn test_unquote() {
+        for item in ["", "null", " padded ", "a,b", "[x]", "say \"hi\"", "\\"] {
+            assert_eq!(unquote(&written(item)), Ok(Cow::Borrowed(item)));
+        }
+        assert_eq!(unquote("plain"), Ok(Cow::Borrowed("plain")));
+        assert_eq!(unquote("\"open"), Err("Invalid item"));
+        assert_eq!(unquote("\"a\"b"), Err("Invalid item"));
+        assert_eq!(unquote("\"a\\\""), Err("Invalid item"));
+    }
+
+    #[test]
+    fn test_symbols_outside_quotes() {
+        let text = r#"a,"b,\",c", d"e,f"#;
+        let positions: Vec<usize> = symbols_outside_quotes(text, &[','])
+            .map(|(position, _)| position)
+            .collect();
+        assert_eq!(positions, vec![1, 10, 15]);
+    }
+}
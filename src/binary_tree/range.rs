@@ -0,0 +1,204 @@
+use super::{BinaryTree, Node};
+use std::borrow::Borrow;
+use std::marker::PhantomData;
+use std::ops::RangeBounds;
+
+/// Borrowing in-order iterator over the items of a [`BinaryTree`] within a
+/// range.
+///
+/// The first and last nodes in the range are found up front, and the nodes
+/// between them are reached through the parent links, so no stack is kept.
+///
+/// The balance information `M` of the nodes is only there for the
+/// self-balancing trees built on the same nodes.
+pub struct Range<'a, T, M = ()> {
+    front: Option<*mut Node<T, M>>,
+    back: Option<*mut Node<T, M>>,
+    marker: PhantomData<&'a T>,
+}
+
+impl<T, M> Range<'_, T, M> {
+    /// Finds the first and last nodes within `range` in the search tree
+    /// rooted at `root`, comparing the items through [`Borrow`].
+    ///
+    /// # Safety
+    /// The tree must stay valid and unchanged while the iterator is used.
+    ///
+    /// # Complexity
+    /// - `O(h)`, where `h` is the height of the tree
+    pub(crate) unsafe fn new<Q, R>(root: Option<*mut Node<T, M>>, range: R) -> Self
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let front = Node::lower_bound(root, range.start_bound());
+        let back = Node::upper_bound(root, range.end_bound());
+        match (front, back) {
+            (Some(first), Some(last)) if (*first).data.borrow() <= (*last).data.borrow() => Range {
+                front,
+                back,
+                marker: PhantomData,
+            },
+            _ => Range {
+                front: None,
+                back: None,
+                marker: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Returns an in-order iterator over the items of the `BinaryTree`
+    /// within `range`, from the smallest to the greatest.
+    ///
+    /// Any kind of range works, like `lo..hi`, `lo..=hi`, `..hi` or `lo..`.
+    /// A range whose start is past its end is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// for i in [40, 20, 60, 10, 30, 50] {
+    ///     tree.insert(i);
+    /// }
+    ///
+    /// assert_eq!(tree.range(20..50).collect::<Vec<_>>(), vec![&20, &30, &40]);
+    /// assert_eq!(tree.range(25..).rev().collect::<Vec<_>>(), vec![&60, &50, &40, &30]);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(h)` to find the range, then `O(m)` for the
+    ///   whole traversal, where `m` is the number of items in the range
+    /// - **Space Complexity:** `O(1)`
+    pub fn range<R: RangeBounds<T>>(&self, range: R) -> Range<'_, T> {
+        unsafe { Range::new(self.root, range) }
+    }
+
+    /// Counts the items of the `BinaryTree` within `range`.
+    ///
+    /// The nodes do not store the sizes of their subtrees, so the items in
+    /// the range are walked one by one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// for i in [40, 20, 60, 10, 30, 50] {
+    ///     tree.insert(i);
+    /// }
+    ///
+    /// assert_eq!(tree.count_in_range(15..=40), 3);
+    /// assert_eq!(tree.count_in_range(..), tree.len());
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(h + m)`, where `m` is the number of items in the range
+    /// - **Space Complexity:** `O(1)`
+    pub fn count_in_range<R: RangeBounds<T>>(&self, range: R) -> usize {
+        self.range(range).count()
+    }
+}
+
+impl<'a, T, M> Iterator for Range<'a, T, M> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.front?;
+        if self.front == self.back {
+            self.front = None;
+            self.back = None;
+        } else {
+            self.front = unsafe { Node::successor(node) };
+        }
+        Some(unsafe { &(*node).data })
+    }
+}
+
+impl<T, M> DoubleEndedIterator for Range<'_, T, M> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let node = self.back?;
+        if self.front == self.back {
+            self.front = None;
+            self.back = None;
+        } else {
+            self.back = unsafe { Node::predecessor(node) };
+        }
+        Some(unsafe { &(*node).data })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::ops::Bound;
+
+    fn sample() -> BinaryTree<i32> {
+        let mut tree = BinaryTree::new();
+        for i in [50, 20, 80, 10, 30, 70, 90, 25, 35, 75] {
+            tree.insert(i);
+        }
+        tree
+    }
+
+    #[test]
+    fn test_range_bounds() {
+        let tree = sample();
+        let collect = |range: Range<'_, i32>| range.copied().collect::<Vec<_>>();
+
+        assert_eq!(collect(tree.range(25..75)), vec![25, 30, 35, 50, 70]);
+        assert_eq!(collect(tree.range(25..=75)), vec![25, 30, 35, 50, 70, 75]);
+        assert_eq!(collect(tree.range(26..74)), vec![30, 35, 50, 70]);
+        assert_eq!(collect(tree.range(..30)), vec![10, 20, 25]);
+        assert_eq!(collect(tree.range(80..)), vec![80, 90]);
+        assert_eq!(collect(tree.range(..)), collect(tree.range(0..100)));
+    }
+
+    #[test]
+    fn test_empty_ranges() {
+        let tree = sample();
+        assert_eq!(tree.range(36..50).next(), None);
+        assert_eq!(tree.range(91..).next(), None);
+        assert_eq!(
+            tree.range((Bound::Included(70), Bound::Excluded(30)))
+                .next(),
+            None
+        );
+        assert_eq!(tree.range(50..50).next(), None);
+        assert_eq!(BinaryTree::<i32>::new().range(..).next(), None);
+    }
+
+    #[test]
+    fn test_range_from_both_ends() {
+        let tree = sample();
+        let mut range = tree.range(20..=75);
+        assert_eq!(range.next(), Some(&20));
+        assert_eq!(range.next_back(), Some(&75));
+        assert_eq!(range.next_back(), Some(&70));
+        assert_eq!(range.next(), Some(&25));
+        assert_eq!(range.collect::<Vec<_>>(), vec![&30, &35, &50]);
+
+        // Meeting in the middle yields every item once
+        let mut range = tree.range(30..=35);
+        assert_eq!(range.next_back(), Some(&35));
+        assert_eq!(range.next(), Some(&30));
+        assert_eq!(range.next(), None);
+        assert_eq!(range.next_back(), None);
+    }
+
+    #[test]
+    fn test_count_in_range() {
+        let tree = sample();
+        assert_eq!(tree.count_in_range(..), 10);
+        assert_eq!(tree.count_in_range(20..=35), 4);
+        assert_eq!(tree.count_in_range(36..50), 0);
+        assert_eq!(tree.count_in_range(100..), 0);
+    }
+}
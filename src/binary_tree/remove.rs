@@ -0,0 +1,167 @@
+use super::{BinaryTree, Node};
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Removes the item equal to `data` from the `BinaryTree` and returns it.
+    ///
+    /// A node with two children is replaced by its in-order successor, the
+    /// smallest item of its right subtree, so the tree stays sorted.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(T)`: The removed item.
+    /// - `None`: If no item of the tree is equal to `data`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// for i in [5, 3, 8, 7, 9] {
+    ///     tree.insert(i);
+    /// }
+    ///
+    /// assert_eq!(tree.remove(&5), Some(5));
+    /// assert_eq!(tree.remove(&5), None);
+    /// assert_eq!(tree.len(), 4);
+    /// ```
+    /// # Safety
+    ///
+    /// - This method uses `unsafe` internally to relink the nodes and take back
+    ///   ownership of the removed one.
+    /// - It is safe as long as the internal structure of the `BinaryTree` remains valid.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(h)`, where `h` is the height of the tree
+    /// - **Space Complexity:** `O(1)`
+    pub fn remove(&mut self, data: &T) -> Option<T> {
+        let node = self.find(data)?;
+        unsafe {
+            match ((*node).left, (*node).right) {
+                (None, right) => self.transplant(node, right),
+                (left, None) => self.transplant(node, left),
+                (Some(left), Some(right)) => {
+                    let successor = Node::leftmost(right);
+                    if successor != right {
+                        // Detach the successor, it has no left child
+                        self.transplant(successor, (*successor).right);
+                        (*successor).right = Some(right);
+                        (*right).parent = Some(successor);
+                    }
+                    self.transplant(node, Some(successor));
+                    (*successor).left = Some(left);
+                    (*left).parent = Some(successor);
+                }
+            }
+            self.length -= 1;
+            Some(Box::from_raw(node).data)
+        }
+    }
+
+    /// Replaces the subtree rooted at `node` with the one rooted at
+    /// `replacement` in the eyes of `node`'s parent.
+    ///
+    /// The children of `replacement` and the links of `node` are left untouched.
+    ///
+    /// # Safety
+    /// `node` and `replacement` must be valid nodes of the tree.
+    pub(super) unsafe fn transplant(
+        &mut self,
+        node: *mut Node<T>,
+        replacement: Option<*mut Node<T>>,
+    ) {
+        match (*node).parent {
+            None => self.root = replacement,
+            Some(parent) if (*parent).left == Some(node) => (*parent).left = replacement,
+            Some(parent) => (*parent).right = replacement,
+        }
+        if let Some(replacement) = replacement {
+            (*replacement).parent = (*node).parent;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Collects the items in order, checking the ordering and the parent links.
+    fn checked_items(tree: &BinaryTree<i32>) -> Vec<i32> {
+        fn walk(
+            node: Option<*mut Node<i32>>,
+            parent: Option<*mut Node<i32>>,
+            items: &mut Vec<i32>,
+        ) {
+            if let Some(node) = node {
+                unsafe {
+                    assert_eq!((*node).parent, parent);
+                    walk((*node).left, Some(node), items);
+                    items.push((*node).data);
+                    walk((*node).right, Some(node), items);
+                }
+            }
+        }
+        let mut items = Vec::new();
+        walk(tree.root, None, &mut items);
+        assert!(items.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(items.len(), tree.len());
+        items
+    }
+
+    fn tree_of(values: &[i32]) -> BinaryTree<i32> {
+        let mut tree = BinaryTree::new();
+        for &value in values {
+            tree.insert(value);
+        }
+        tree
+    }
+
+    #[test]
+    fn test_remove_leaf() {
+        let mut tree = tree_of(&[5, 3, 8]);
+        assert_eq!(tree.remove(&3), Some(3));
+        assert_eq!(checked_items(&tree), vec![5, 8]);
+    }
+
+    #[test]
+    fn test_remove_node_with_one_child() {
+        let mut tree = tree_of(&[5, 3, 8, 9]);
+        assert_eq!(tree.remove(&8), Some(8));
+        assert_eq!(checked_items(&tree), vec![3, 5, 9]);
+
+        let mut tree = tree_of(&[5, 3, 2]);
+        assert_eq!(tree.remove(&3), Some(3));
+        assert_eq!(checked_items(&tree), vec![2, 5]);
+    }
+
+    #[test]
+    fn test_remove_node_with_two_children() {
+        // The successor is the right child
+        let mut tree = tree_of(&[5, 3, 8, 9]);
+        assert_eq!(tree.remove(&5), Some(5));
+        assert_eq!(checked_items(&tree), vec![3, 8, 9]);
+        assert_eq!(unsafe { (*tree.root.unwrap()).data }, 8);
+
+        // The successor is deeper and has a right child
+        let mut tree = tree_of(&[5, 3, 10, 7, 12, 6, 8, 9]);
+        assert_eq!(tree.remove(&5), Some(5));
+        assert_eq!(checked_items(&tree), vec![3, 6, 7, 8, 9, 10, 12]);
+        assert_eq!(unsafe { (*tree.root.unwrap()).data }, 6);
+        assert_eq!(tree.remove(&7), Some(7));
+        assert_eq!(checked_items(&tree), vec![3, 6, 8, 9, 10, 12]);
+    }
+
+    #[test]
+    fn test_remove_everything() {
+        let values: Vec<i32> = (0..200).map(|i| (i * 37) % 200).collect();
+        let mut tree = tree_of(&values);
+        for (removed, value) in values.iter().rev().enumerate() {
+            assert_eq!(tree.remove(value), Some(*value));
+            assert_eq!(tree.remove(value), None);
+            assert_eq!(checked_items(&tree).len(), values.len() - removed - 1);
+        }
+        assert!(tree.is_empty());
+        assert_eq!(tree.remove(&0), None);
+    }
+}
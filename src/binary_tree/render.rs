@@ -0,0 +1,494 @@
+use super::{BinaryTree, Node};
+use std::fmt;
+use std::ops::Range;
+
+/// Drawn in place of a missing child next to an existing one, so a lone
+/// child still shows its side.
+const MISSING: &str = "∅";
+
+/// Where a subtree goes in the top-down drawing, with its columns counted
+/// from the left edge of the subtree.
+struct Layout {
+    label: String,
+    width: usize,
+    /// Column of the center of the root label, where the branch from the
+    /// parent arrives.
+    root: usize,
+    /// Lines taken by the subtree, two per level but the last one.
+    lines: usize,
+    /// Index of the layout of each child, with the column where it starts.
+    left: Option<(usize, usize)>,
+    right: Option<(usize, usize)>,
+}
+
+/// The lines of the top-down drawing, holding only their first `max_width`
+/// characters.
+struct Canvas {
+    lines: Vec<Vec<char>>,
+    /// Whether each line has something past `max_width`.
+    cut: Vec<bool>,
+    max_width: usize,
+}
+
+impl<T: std::cmp::Ord + fmt::Display> BinaryTree<T> {
+    /// Draws the `BinaryTree` sideways, one node per line with its children
+    /// below it, left before right, joined by `├──` and `└──` branches.
+    ///
+    /// Each node is labeled with its [`Display`](fmt::Display). A node with
+    /// only one child shows `∅` for the other, so the side stays visible.
+    /// Lines longer than `max_width` characters are cut and end with `…`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// for i in [4, 2, 6, 1, 3, 7] {
+    ///     tree.insert(i);
+    /// }
+    ///
+    /// let expected = "\
+    /// 4
+    /// ├── 2
+    /// │   ├── 1
+    /// │   └── 3
+    /// └── 6
+    ///     ├── ∅
+    ///     └── 7";
+    /// assert_eq!(tree.render_sideways(80), expected);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(n * min(h, w))`, where `w` is `max_width`:
+    ///   the indentation of a line is only built up to `w` columns
+    /// - **Space Complexity:** `O(n * w)` for the text
+    pub fn render_sideways(&self, max_width: usize) -> String {
+        let mut lines = Vec::new();
+        // Nodes to draw, `None` for a missing child, with their depth, their
+        // branch and the indentation they add for their children
+        let mut stack: Vec<(Option<*mut Node<T>>, usize, &str, &str)> = Vec::new();
+        // The indentation added by each ancestor of the node being drawn
+        let mut indents: Vec<&str> = Vec::new();
+        if let Some(root) = self.root {
+            stack.push((Some(root), 0, "", ""));
+        }
+        while let Some((node, depth, branch, below)) = stack.pop() {
+            indents.truncate(depth);
+            // Nothing past `max_width` is kept, so the line stops being built
+            // once it is longer
+            let mut line = String::new();
+            let mut width = 0;
+            for piece in indents.iter().chain([&branch]) {
+                if width > max_width {
+                    break;
+                }
+                line.push_str(piece);
+                width += piece.chars().count();
+            }
+            let Some(node) = node else {
+                if width <= max_width {
+                    line.push_str(MISSING);
+                }
+                lines.push(fit(&line, max_width));
+                continue;
+            };
+            unsafe {
+                if width <= max_width {
+                    line += &(*node).data.to_string();
+                }
+                lines.push(fit(&line, max_width));
+                if (*node).left.is_none() && (*node).right.is_none() {
+                    continue;
+                }
+                indents.push(below);
+                // Pushed backward so the left child is drawn first
+                stack.push(((*node).right, depth + 1, "└── ", "    "));
+                stack.push(((*node).left, depth + 1, "├── ", "│   "));
+            }
+        }
+        lines.join("\n")
+    }
+
+    /// Draws the `BinaryTree` top-down, each label centered above the
+    /// branches to its children, `┌` on the left and `┐` on the right.
+    ///
+    /// Each node is labeled with its [`Display`](fmt::Display). Lines longer
+    /// than `max_width` characters are cut and end with `…`, which hides the
+    /// right side of wide trees. Subtrees that start past `max_width` are
+    /// never drawn, so the limit also bounds the work for large trees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// for i in [4, 2, 6, 1, 3, 7] {
+    ///     tree.insert(i);
+    /// }
+    ///
+    /// let expected = "  4
+    ///  ┌┴─┐
+    ///  2  6
+    /// ┌┴┐ └─┐
+    /// 1 3   7";
+    /// assert_eq!(tree.render_top_down(80), expected);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(n + h * w)`, where `w` is `max_width`: every
+    ///   node is measured once, but only the first `w` columns of each of the
+    ///   `2h - 1` lines are drawn
+    /// - **Space Complexity:** `O(n + h * w)`, for the measures and the text
+    pub fn render_top_down(&self, max_width: usize) -> String {
+        // The measures wait in a flat list, so a deep tree is not dropped recursively
+        let mut layouts = Vec::new();
+        let Some(root) = self.fold_post_order(|data, left, right| {
+            Self::lay_out(&mut layouts, data.to_string(), left, right)
+        }) else {
+            return String::new();
+        };
+
+        let mut canvas = Canvas {
+            lines: vec![Vec::new(); layouts[root].lines],
+            cut: vec![false; layouts[root].lines],
+            max_width,
+        };
+        // Subtrees to draw, with their first line and column
+        let mut pending = vec![(root, 0, 0)];
+        while let Some((index, line, start)) = pending.pop() {
+            let layout = &layouts[index];
+            if start >= max_width {
+                // Every line of a subtree holds part of it
+                canvas.cut[line..line + layout.lines].fill(true);
+                continue;
+            }
+            Self::draw_node(&mut canvas, &layouts, index, line, start);
+            for (child, at) in layout.left.into_iter().chain(layout.right) {
+                pending.push((child, line + 2, start + at));
+            }
+        }
+        canvas.into_text()
+    }
+
+    /// Measures the subtree whose root has `label`, given the measures of
+    /// its children, and returns the index of its layout in `layouts`.
+    fn lay_out(
+        layouts: &mut Vec<Layout>,
+        label: String,
+        left: Option<usize>,
+        right: Option<usize>,
+    ) -> usize {
+        let label_width = label.chars().count();
+        let half = label_width / 2;
+        let measures = |child: Option<usize>| {
+            child.map(|index| {
+                let layout: &Layout = &layouts[index];
+                (index, layout.width, layout.root, layout.lines)
+            })
+        };
+        let (left, right) = (measures(left), measures(right));
+
+        // Columns where the label is centered and where each child starts
+        let (mut center, mut left_at, mut right_at) = match (left, right) {
+            (None, None) => (half, 0, 0),
+            (Some((_, width, root, _)), Some((_, _, right_root, _))) => {
+                let right_at = width + 1;
+                ((root + right_at + right_root) / 2, 0, right_at)
+            }
+            (Some((_, _, root, _)), None) => (root + 2, 0, 0),
+            (None, Some((_, _, root, _))) => (half, 0, (half + 2).saturating_sub(root)),
+        };
+        // Make room for the left half of a wide label
+        let shift = half.saturating_sub(center);
+        center += shift;
+        left_at += shift;
+        right_at += shift;
+
+        let mut width = center - half + label_width;
+        let mut lines = 1;
+        for (at, child) in [(left_at, left), (right_at, right)] {
+            if let Some((_, child_width, _, child_lines)) = child {
+                width = width.max(at + child_width);
+                lines = lines.max(child_lines + 2);
+            }
+        }
+        layouts.push(Layout {
+            label,
+            width,
+            root: center,
+            lines,
+            left: left.map(|(index, ..)| (index, left_at)),
+            right: right.map(|(index, ..)| (index, right_at)),
+        });
+        layouts.len() - 1
+    }
+
+    /// Draws the label of `layouts[index]` from its first line and column,
+    /// and the branches below it.
+    fn draw_node(canvas: &mut Canvas, layouts: &[Layout], index: usize, line: usize, start: usize) {
+        let layout = &layouts[index];
+        let half = layout.label.chars().count() / 2;
+        let center = start + layout.root;
+        for (offset, symbol) in layout.label.chars().enumerate() {
+            canvas.draw(line, center - half + offset, symbol);
+        }
+        if layout.left.is_none() && layout.right.is_none() {
+            return;
+        }
+
+        let line = line + 1;
+        if let Some((child, at)) = layout.left {
+            let branch = start + at + layouts[child].root;
+            canvas.draw_run(line, branch..center, '─');
+            canvas.draw(line, branch, '┌');
+        }
+        if let Some((child, at)) = layout.right {
+            let branch = start + at + layouts[child].root;
+            canvas.draw_run(line, center..branch, '─');
+            canvas.draw(line, branch, '┐');
+        }
+        let joint = match (layout.left, layout.right) {
+            (Some(_), Some(_)) => '┴',
+            (Some(_), None) => '┘',
+            _ => '└',
+        };
+        canvas.draw(line, center, joint);
+    }
+}
+
+impl Canvas {
+    /// Puts `symbol` at `column` of `line`, or only records that the line
+    /// goes on past `max_width`.
+    fn draw(&mut self, line: usize, column: usize, symbol: char) {
+        if column >= self.max_width {
+            self.cut[line] |= !symbol.is_whitespace();
+            return;
+        }
+        let text = &mut self.lines[line];
+        if text.len() <= column {
+            text.resize(column + 1, ' ');
+        }
+        text[column] = symbol;
+    }
+
+    /// Puts `symbol` at every column of `columns` in `line`.
+    fn draw_run(&mut self, line: usize, columns: Range<usize>, symbol: char) {
+        let shown = columns.start..columns.end.min(self.max_width);
+        if shown.len() < columns.len() {
+            // The rest of the run is past `max_width`
+            self.draw(line, columns.end - 1, symbol);
+        }
+        for column in shown {
+            self.draw(line, column, symbol);
+        }
+    }
+
+    /// Joins the lines, each cut line ending with `…` at `max_width`.
+    fn into_text(self) -> String {
+        let keep = self.max_width.saturating_sub(1);
+        let lines: Vec<String> = self
+            .lines
+            .into_iter()
+            .zip(self.cut)
+            .map(|(text, cut)| {
+                let line: String = text.into_iter().collect();
+                if !cut {
+                    return line.trim_end().to_string();
+                }
+                let mut line: String = line
+                    .chars()
+                    .chain(std::iter::repeat(' '))
+                    .take(keep)
+                    .collect();
+                if self.max_width > 0 {
+                    line.push('…');
+                }
+                line
+            })
+            .collect();
+        lines.join("\n")
+    }
+}
+
+/// Cuts `line` to `max_width` characters, ending it with `…` if it was
+/// longer.
+fn fit(line: &str, max_width: usize) -> String {
+    if line.chars().count() <= max_width {
+        return line.to_string();
+    }
+    let mut cut: String = line.chars().take(max_width.saturating_sub(1)).collect();
+    if max_width > 0 {
+        cut.push('…');
+    }
+    cut
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn tree_of(items: &[i32]) -> BinaryTree<i32> {
+        let mut tree = BinaryTree::new();
+        for &i in items {
+            tree.insert(i);
+        }
+        tree
+    }
+
+    #[test]
+    fn test_render_sideways() {
+        assert_eq!(BinaryTree::<i32>::new().render_sideways(80), "");
+        assert_eq!(tree_of(&[1]).render_sideways(80), "1");
+
+        let expected = "\
+10
+├── 5
+│   ├── ∅
+│   └── 7
+│       ├── ∅
+│       └── 8
+└── 15
+    ├── ∅
+    └── 20
+        ├── 17
+        └── ∅";
+        assert_eq!(
+            tree_of(&[10, 5, 7, 8, 15, 20, 17]).render_sideways(80),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_render_top_down() {
+        assert_eq!(BinaryTree::<i32>::new().render_top_down(80), "");
+        assert_eq!(tree_of(&[1]).render_top_down(80), "1");
+
+        let expected = "   4
+ ┌─┴─┐
+ 2   6
+┌┴┐ ┌┴┐
+1 3 5 7";
+        assert_eq!(
+            tree_of(&[4, 2, 6, 1, 3, 5, 7]).render_top_down(80),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_render_top_down_lone_children() {
+        let expected = "    3
+  ┌─┘
+  2
+┌─┘
+1";
+        assert_eq!(tree_of(&[3, 2, 1]).render_top_down(80), expected);
+
+        let expected = "1
+└─┐
+  2
+  └─┐
+    3";
+        assert_eq!(tree_of(&[1, 2, 3]).render_top_down(80), expected);
+    }
+
+    #[test]
+    fn test_render_top_down_wide_labels() {
+        let mut tree = BinaryTree::new();
+        for word in ["mango", "fig", "watermelon"] {
+            tree.insert(word);
+        }
+        let expected = "   mango
+ ┌───┴───┐
+fig watermelon";
+        assert_eq!(tree.render_top_down(80), expected);
+
+        let mut tree = BinaryTree::new();
+        tree.insert("banana");
+        tree.insert("apple");
+        let expected = " banana
+  ┌─┘
+apple";
+        assert_eq!(tree.render_top_down(80), expected);
+    }
+
+    #[test]
+    fn test_width_limit() {
+        let tree = tree_of(&[4, 2, 6, 1, 3, 5, 7]);
+        let expected = "   4
+ ┌─…
+ 2 …
+┌┴┐…
+1 3…";
+        assert_eq!(tree.render_top_down(4), expected);
+
+        let deep = BinaryTree::from_pre_order((0..100).collect()).unwrap();
+        let sideways = deep.render_sideways(12);
+        assert_eq!(sideways.lines().count(), 199);
+        assert!(sideways.lines().all(|line| line.chars().count() <= 12));
+        assert_eq!(sideways.lines().last(), Some("           …"));
+        assert_eq!(fit("abc", 0), "");
+    }
+
+    #[test]
+    fn test_width_limit_cuts_like_the_full_drawing() {
+        let mut tree = tree_of(&[50, 20, 80, 10, 30, 70, 90, 25, 35, 75, 5, 95, 1]);
+        tree.insert(1000);
+        tree.insert(-200);
+        let full = tree.render_top_down(usize::MAX);
+        for max_width in 0..40 {
+            let cut: Vec<String> = full.lines().map(|line| fit(line, max_width)).collect();
+            assert_eq!(tree.render_top_down(max_width), cut.join("\n"));
+        }
+
+        let full = tree.render_sideways(usize::MAX);
+        for max_width in 0..40 {
+            let cut: Vec<String> = full.lines().map(|line| fit(line, max_width)).collect();
+            assert_eq!(tree.render_sideways(max_width), cut.join("\n"));
+        }
+    }
+
+    #[test]
+    fn test_sideways_width_limit_of_large_trees() {
+        // Indenting every line of the chain in full would take quadratic time
+        let deep = BinaryTree::from_pre_order((0..20_000).collect()).unwrap();
+        let drawing = deep.render_sideways(80);
+        assert_eq!(drawing.lines().count(), 39_999);
+        assert!(drawing.lines().all(|line| line.chars().count() <= 80));
+        assert_eq!(
+            drawing.lines().last(),
+            Some(&*format!("{}…", " ".repeat(79)))
+        );
+        assert_eq!(drawing.lines().nth(1), Some("├── ∅"));
+    }
+
+    #[test]
+    fn test_width_limit_of_large_trees() {
+        // Drawing the whole chain would take quadratic time
+        let deep = BinaryTree::from_pre_order((0..20_000).collect()).unwrap();
+        let drawing = deep.render_top_down(80);
+        assert_eq!(drawing.lines().count(), 39_999);
+        assert!(drawing.lines().all(|line| line.chars().count() <= 80));
+        assert_eq!(
+            drawing.lines().last().map(|line| line.ends_with('…')),
+            Some(true)
+        );
+
+        let mut wide = BinaryTree::new();
+        for step in (0..17).rev() {
+            for i in (1 << step..1 << 17).step_by(2 << step) {
+                wide.insert(i);
+            }
+        }
+        let drawing = wide.render_top_down(80);
+        assert_eq!(drawing.lines().count(), 33);
+        assert!(drawing.lines().all(|line| line.chars().count() <= 80));
+        assert_eq!(
+            drawing.lines().next(),
+            Some(&*format!("{}…", " ".repeat(79)))
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use super::{quote, BinaryTree};
+use crate::queue::{ArrayQueue, Queue};
+use std::fmt;
+
+impl<T: std::cmp::Ord + fmt::Display> BinaryTree<T> {
+    /// Writes the shape of the `BinaryTree` in level-order notation with
+    /// explicit nulls, which [`deserialize`](Self::deserialize) reads back.
+    ///
+    /// The items are listed level by level between square brackets and
+    /// separated by commas, with `null` for every missing child of a node
+    /// that is listed. The nulls after the last item are left out.
+    ///
+    /// Items are written with their [`Display`](fmt::Display). An item that
+    /// would be read back as something else is written between double
+    /// quotes, with a `\` before every `"` and `\` inside it: one that is
+    /// empty, is `null`, has spaces around it or holds one of `(),[]"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// for i in [4, 2, 6, 3] {
+    ///     tree.insert(i);
+    /// }
+    /// assert_eq!(tree.serialize(), "[4,2,6,null,3]");
+    /// assert_eq!(BinaryTree::<i32>::new().serialize(), "[]");
+    ///
+    /// let mut words = BinaryTree::new();
+    /// for word in ["null", "a,b"] {
+    ///     words.insert(word);
+    /// }
+    /// assert_eq!(words.serialize(), r#"["null","a,b"]"#);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(n)`
+    /// - **Space Complexity:** `O(n)` for the text
+    pub fn serialize(&self) -> String {
+        let mut text = String::from("[");
+        // Missing children are only written once an item follows them
+        let mut nulls = 0;
+        let mut slots = ArrayQueue::new();
+        slots.enqueue(self.root);
+        while let Some(slot) = slots.dequeue() {
+            let Some(node) = slot else {
+                nulls += 1;
+                continue;
+            };
+            for _ in 0..nulls {
+                text.push_str("null,");
+            }
+            nulls = 0;
+            unsafe {
+                quote::write_item(&mut text, &(*node).data.to_string())
+                    .expect("writing to a String cannot fail");
+                text.push(',');
+                slots.enqueue((*node).left);
+                slots.enqueue((*node).right);
+            }
+        }
+        if text.ends_with(',') {
+            text.pop();
+        }
+        text.push(']');
+        text
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_serialize() {
+        let mut tree = BinaryTree::new();
+        assert_eq!(tree.serialize(), "[]");
+        tree.insert(4);
+        assert_eq!(tree.serialize(), "[4]");
+
+        for i in [2, 6, 1, 3, 5, 7] {
+            tree.insert(i);
+        }
+        assert_eq!(tree.serialize(), "[4,2,6,1,3,5,7]");
+    }
+
+    #[test]
+    fn test_serialize_unbalanced_shapes() {
+        let mut tree = BinaryTree::new();
+        for i in [10, 5, 7, 8, 15, 20, 17] {
+            tree.insert(i);
+        }
+        assert_eq!(tree.serialize(), "[10,5,15,null,7,null,20,null,8,17]");
+
+        let mut chain = BinaryTree::new();
+        for i in (1..=3).rev() {
+            chain.insert(i);
+        }
+        assert_eq!(chain.serialize(), "[3,2,null,1]");
+    }
+}
@@ -0,0 +1,53 @@
+use super::BinaryTree;
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Counts the nodes of the `BinaryTree` by walking it.
+    ///
+    /// Unlike [`len`](Self::len), which reads the stored length, the nodes
+    /// are counted one by one, so comparing both checks the bookkeeping.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// for i in [2, 1, 3] {
+    ///     tree.insert(i);
+    /// }
+    /// assert_eq!(tree.size(), 3);
+    /// assert_eq!(tree.size(), tree.len());
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(n)`
+    /// - **Space Complexity:** `O(h)`, where `h` is the height of the tree
+    pub fn size(&self) -> usize {
+        self.fold_post_order(|_, left: Option<usize>, right| {
+            1 + left.unwrap_or(0) + right.unwrap_or(0)
+        })
+        .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_size_matches_len() {
+        let mut tree = BinaryTree::new();
+        assert_eq!(tree.size(), 0);
+
+        for i in [50, 20, 80, 10, 30, 70, 90, 25] {
+            tree.insert(i);
+            assert_eq!(tree.size(), tree.len());
+        }
+        for i in [20, 50, 90] {
+            tree.remove(&i);
+            assert_eq!(tree.size(), tree.len());
+        }
+        assert_eq!(tree.size(), 5);
+    }
+}
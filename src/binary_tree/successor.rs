@@ -0,0 +1,61 @@
+use super::BinaryTree;
+use std::ops::Bound;
+
+impl<T: std::cmp::Ord> BinaryTree<T> {
+    /// Retrieves a reference to the item of the `BinaryTree` right after
+    /// `data` in order, the smallest item strictly greater than it.
+    ///
+    /// `data` does not need to be in the tree.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(&T)`: A reference to the closest greater item.
+    /// - `None`: If no item is greater than `data`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::binary_tree::BinaryTree;
+    ///
+    /// let mut tree = BinaryTree::new();
+    /// for i in [20, 10, 30] {
+    ///     tree.insert(i);
+    /// }
+    ///
+    /// assert_eq!(tree.successor(&20), Some(&30));
+    /// assert_eq!(tree.successor(&15), Some(&20));
+    /// assert_eq!(tree.successor(&30), None);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(h)`, where `h` is the height of the tree
+    /// - **Space Complexity:** `O(1)`
+    pub fn successor(&self, data: &T) -> Option<&T> {
+        self.lower_bound(Bound::Excluded(data))
+            .map(|node| unsafe { &(*node).data })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_successor() {
+        let mut tree = BinaryTree::new();
+        assert_eq!(tree.successor(&1), None);
+
+        for i in [40, 20, 60, 10, 30, 50, 70] {
+            tree.insert(i);
+        }
+        // Every item is followed by the one after it in order
+        let items: Vec<i32> = tree.iter().copied().collect();
+        for pair in items.windows(2) {
+            assert_eq!(tree.successor(&pair[0]), Some(&pair[1]));
+        }
+        assert_eq!(tree.successor(&70), None);
+        assert_eq!(tree.successor(&45), Some(&50));
+        assert_eq!(tree.successor(&0), Some(&10));
+    }
+}
@@ -0,0 +1,463 @@
+use crate::hazard_pointer::Domain;
+use std::mem::MaybeUninit;
+use std::ptr;
+use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+
+/// A lock-free FIFO queue that can be shared between threads (Michael–Scott queue).
+///
+/// The queue is a singly linked list whose first node is a sentinel: `head`
+/// points to the sentinel and `tail` to the last node. Enqueuing links a node
+/// after the last one and then swings `tail`; dequeuing moves `head` to the
+/// next node, which becomes the new sentinel. Any thread seeing `tail` lag
+/// behind helps advancing it, so no thread ever waits for another.
+///
+/// Removed nodes are reclaimed through hazard pointers, like in
+/// [`crate::concurrent_stack::ConcurrentStack`].
+///
+/// # Type Parameters
+/// - `T`: The type of elements stored in the queue.
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::concurrent_queue::ConcurrentQueue;
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// let queue = Arc::new(ConcurrentQueue::new());
+/// let producer = {
+///     let queue = Arc::clone(&queue);
+///     thread::spawn(move || {
+///         for i in 0..3 {
+///             queue.enqueue(i);
+///         }
+///     })
+/// };
+/// producer.join().unwrap();
+///
+/// assert_eq!(queue.len(), 3);
+/// assert_eq!(queue.dequeue(), Some(0));
+/// assert_eq!(queue.dequeue(), Some(1));
+/// ```
+pub struct ConcurrentQueue<T> {
+    head: AtomicPtr<Node<T>>,
+    tail: AtomicPtr<Node<T>>,
+    length: AtomicUsize,
+    domain: Domain,
+}
+
+/// A node of the [`ConcurrentQueue`].
+///
+/// The data of the sentinel is either uninitialized, for the first one, or
+/// already moved out by the thread that dequeued it, so it is never dropped
+/// along with the node.
+struct Node<T> {
+    data: MaybeUninit<T>,
+    next: AtomicPtr<Node<T>>,
+}
+
+impl<T> Node<T> {
+    fn new(data: MaybeUninit<T>) -> *mut Node<T> {
+        Box::into_raw(Box::new(Node {
+            data,
+            next: AtomicPtr::new(ptr::null_mut()),
+        }))
+    }
+}
+
+impl<T> ConcurrentQueue<T> {
+    /// Creates a new empty `ConcurrentQueue`.
+    pub fn new() -> ConcurrentQueue<T> {
+        let sentinel = Node::new(MaybeUninit::uninit());
+        ConcurrentQueue {
+            head: AtomicPtr::new(sentinel),
+            tail: AtomicPtr::new(sentinel),
+            length: AtomicUsize::new(0),
+            domain: Domain::new(),
+        }
+    }
+
+    /// Adds an item at the back of the queue.
+    ///
+    /// # Complexity
+    /// - `O(1)` when uncontended, retried while other threads change the tail
+    pub fn enqueue(&self, data: T) {
+        let node = Node::new(MaybeUninit::new(data));
+        // Counted before it is linked, so a concurrent dequeue of the item
+        // never makes the counter go below zero
+        self.length.fetch_add(1, Ordering::Relaxed);
+        let hazard = self.domain.hazard_pointer();
+        loop {
+            let tail = hazard.protect(&self.tail);
+            // The hazard pointer keeps `tail` alive while it is read
+            let next = unsafe { (*tail).next.load(Ordering::Acquire) };
+            if tail != self.tail.load(Ordering::Acquire) {
+                continue;
+            }
+
+            if next.is_null() {
+                let linked = unsafe {
+                    (*tail).next.compare_exchange(
+                        ptr::null_mut(),
+                        node,
+                        Ordering::Release,
+                        Ordering::Relaxed,
+                    )
+                };
+                if linked.is_ok() {
+                    // Failing means another thread already advanced the tail
+                    let _ = self.tail.compare_exchange(
+                        tail,
+                        node,
+                        Ordering::Release,
+                        Ordering::Relaxed,
+                    );
+                    return;
+                }
+            } else {
+                // The tail lags behind, help advancing it before retrying
+                let _ =
+                    self.tail
+                        .compare_exchange(tail, next, Ordering::Release, Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Removes the item at the front of the queue and returns it, or `None`
+    /// if the queue is empty.
+    ///
+    /// # Complexity
+    /// - `O(1)` when uncontended, retried while other threads change the head
+    pub fn dequeue(&self) -> Option<T> {
+        let head_hazard = self.domain.hazard_pointer();
+        let next_hazard = self.domain.hazard_pointer();
+        loop {
+            let head = head_hazard.protect(&self.head);
+            let tail = self.tail.load(Ordering::Acquire);
+            let next = next_hazard.protect(unsafe { &(*head).next });
+            // While `head` is still the sentinel, `next` cannot have been
+            // dequeued, so the hazard pointer was set before it could be retired
+            if head != self.head.load(Ordering::Acquire) {
+                continue;
+            }
+            if next.is_null() {
+                return None;
+            }
+            if head == tail {
+                // Never let the head pass the tail, or the tail could point
+                // to a retired node
+                let _ =
+                    self.tail
+                        .compare_exchange(tail, next, Ordering::Release, Ordering::Relaxed);
+                continue;
+            }
+
+            if self
+                .head
+                .compare_exchange(head, next, Ordering::AcqRel, Ordering::Relaxed)
+                .is_ok()
+            {
+                self.length.fetch_sub(1, Ordering::Relaxed);
+                unsafe {
+                    // `next` is the new sentinel: its data is moved out exactly
+                    // once, by the thread that won the CAS, while still protected
+                    let data = (*next).data.assume_init_read();
+                    head_hazard.reset();
+                    next_hazard.reset();
+                    self.domain.retire(head);
+                    return Some(data);
+                }
+            }
+        }
+    }
+
+    /// Gets the amount of items in the queue.
+    ///
+    /// The value is exact when no other thread is modifying the queue, and a
+    /// snapshot otherwise.
+    pub fn len(&self) -> usize {
+        self.length.load(Ordering::Relaxed)
+    }
+
+    /// Checks if the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        let hazard = self.domain.hazard_pointer();
+        let head = hazard.protect(&self.head);
+        unsafe { (*head).next.load(Ordering::Acquire).is_null() }
+    }
+}
+
+impl<T> Default for ConcurrentQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for ConcurrentQueue<T> {
+    fn drop(&mut self) {
+        unsafe {
+            // The sentinel holds no data
+            let sentinel = Box::from_raw(*self.head.get_mut());
+            let mut cursor = sentinel.next.load(Ordering::Relaxed);
+            while !cursor.is_null() {
+                let mut node = Box::from_raw(cursor);
+                cursor = node.next.load(Ordering::Relaxed);
+                node.data.assume_init_drop();
+            }
+        }
+    }
+}
+
+// Items are moved between threads by `enqueue` and `dequeue`, so only `T: Send` is needed.
+unsafe impl<T: Send> Send for ConcurrentQueue<T> {}
+unsafe impl<T: Send> Sync for ConcurrentQueue<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::sync::atomic::AtomicBool;
+    use std::sync::{Arc, Barrier};
+    use std::thread;
+
+    struct DropCounter(Arc<AtomicUsize>);
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    #[test]
+    fn test_enqueue_dequeue_fifo() {
+        let queue = ConcurrentQueue::new();
+        assert!(queue.is_empty());
+        assert_eq!(queue.dequeue(), None);
+
+        for i in 0..10 {
+            queue.enqueue(i);
+        }
+        assert_eq!(queue.len(), 10);
+        assert!(!queue.is_empty());
+        for i in 0..10 {
+            assert_eq!(queue.dequeue(), Some(i));
+        }
+        assert!(queue.is_empty());
+        assert_eq!(queue.dequeue(), None);
+
+        queue.enqueue(10);
+        assert_eq!(queue.dequeue(), Some(10));
+    }
+
+    #[test]
+    fn test_drop_frees_every_item() {
+        let drops = Arc::new(AtomicUsize::new(0));
+        let queue = ConcurrentQueue::new();
+        for _ in 0..1000 {
+            queue.enqueue(DropCounter(drops.clone()));
+        }
+        for _ in 0..500 {
+            drop(queue.dequeue());
+        }
+        assert_eq!(drops.load(Ordering::Relaxed), 500);
+
+        drop(queue);
+        assert_eq!(drops.load(Ordering::Relaxed), 1000);
+    }
+
+    #[test]
+    fn test_concurrent_producers_and_consumers() {
+        let producers = 4;
+        let consumers = 4;
+        let per_producer = 20_000;
+        let queue = Arc::new(ConcurrentQueue::new());
+        let done = Arc::new(AtomicUsize::new(0));
+        let barrier = Arc::new(Barrier::new(producers + consumers));
+
+        let producer_handles: Vec<_> = (0..producers)
+            .map(|p| {
+                let queue = Arc::clone(&queue);
+                let done = Arc::clone(&done);
+                let barrier = Arc::clone(&barrier);
+                thread::spawn(move || {
+                    barrier.wait();
+                    for i in 0..per_producer {
+                        queue.enqueue((p, i));
+                    }
+                    done.fetch_add(1, Ordering::Release);
+                })
+            })
+            .collect();
+        let consumer_handles: Vec<_> = (0..consumers)
+            .map(|_| {
+                let queue = Arc::clone(&queue);
+                let done = Arc::clone(&done);
+                let barrier = Arc::clone(&barrier);
+                thread::spawn(move || {
+                    barrier.wait();
+                    let mut received = Vec::new();
+                    loop {
+                        // Read before dequeuing, so an empty queue after every
+                        // producer finished means nothing is left
+                        let finished = done.load(Ordering::Acquire) == producers;
+                        match queue.dequeue() {
+                            Some(item) => received.push(item),
+                            None if finished => return received,
+                            None => thread::yield_now(),
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        for handle in producer_handles {
+            handle.join().unwrap();
+        }
+        let mut seen = HashSet::new();
+        for handle in consumer_handles {
+            // FIFO order: each consumer sees the items of every producer in
+            // the order they were enqueued
+            let mut last = vec![None; producers];
+            for (producer, i) in handle.join().unwrap() {
+                assert!(last[producer] < Some(i));
+                last[producer] = Some(i);
+                assert!(seen.insert((producer, i)), "item dequeued twice");
+            }
+        }
+        assert_eq!(seen.len(), producers * per_producer);
+        assert!(queue.is_empty());
+        assert_eq!(queue.len(), 0);
+    }
+
+    #[test]
+    fn test_concurrent_mixed_operations() {
+        let threads = 8;
+        let per_thread = 20_000;
+        let drops = Arc::new(AtomicUsize::new(0));
+        let queue = Arc::new(ConcurrentQueue::new());
+        let barrier = Arc::new(Barrier::new(threads));
+
+        let handles: Vec<_> = (0..threads)
+            .map(|_| {
+                let queue = Arc::clone(&queue);
+                let drops = Arc::clone(&drops);
+                let barrier = Arc::clone(&barrier);
+                thread::spawn(move || {
+                    barrier.wait();
+                    let mut dequeued = 0;
+                    for i in 0..per_thread {
+                        queue.enqueue(DropCounter(drops.clone()));
+                        if i % 2 == 0 && queue.dequeue().is_some() {
+                            dequeued += 1;
+                        }
+                    }
+                    dequeued
+                })
+            })
+            .collect();
+
+        let dequeued: usize = handles.into_iter().map(|h| h.join().unwrap()).sum();
+        assert_eq!(drops.load(Ordering::Relaxed), dequeued);
+        assert_eq!(queue.len(), threads * per_thread - dequeued);
+
+        drop(Arc::try_unwrap(queue).ok().unwrap());
+        assert_eq!(drops.load(Ordering::Relaxed), threads * per_thread);
+    }
+
+    #[test]
+    fn test_reclaim_during_dequeues() {
+        // Small enough for Miri, which reports any read of a freed node
+        let (consumers, items) = if cfg!(miri) { (3, 200) } else { (4, 100_000) };
+        let queue = ConcurrentQueue::new();
+        for i in 0..items {
+            queue.enqueue(Box::new(i));
+        }
+        // Idle records make every pass scan a long list of hazard pointers
+        let idle: Vec<_> = (0..256).map(|_| queue.domain.hazard_pointer()).collect();
+        let done = AtomicBool::new(false);
+        let freed = Arc::new(AtomicUsize::new(0));
+
+        let mut dequeued: Vec<usize> = thread::scope(|scope| {
+            // Keeps reclamation passes running while the `next` of the
+            // sentinel is read by the consumers
+            scope.spawn(|| {
+                while !done.load(Ordering::Relaxed) {
+                    let node = Box::into_raw(Box::new(DropCounter(freed.clone())));
+                    unsafe { queue.domain.retire(node) };
+                }
+            });
+
+            let handles: Vec<_> = (0..consumers)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut taken = Vec::new();
+                        while let Some(item) = queue.dequeue() {
+                            taken.push(*item);
+                        }
+                        taken
+                    })
+                })
+                .collect();
+            let dequeued = handles
+                .into_iter()
+                .flat_map(|handle| handle.join().unwrap())
+                .collect();
+            done.store(true, Ordering::Relaxed);
+            dequeued
+        });
+        drop(idle);
+
+        dequeued.sort();
+        assert_eq!(dequeued, (0..items).collect::<Vec<_>>());
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_single_thread_order_under_contention() {
+        // One thread enqueues increasing values while others churn the
+        // queue: every thread must get the values back in FIFO order, and
+        // all of them together exactly once
+        let queue = Arc::new(ConcurrentQueue::new());
+        let barrier = Arc::new(Barrier::new(4));
+
+        fn take_ordered(value: Option<usize>, last: &mut Option<usize>, taken: &mut Vec<usize>) {
+            if let Some(value) = value.filter(|&v| v != usize::MAX) {
+                assert!(*last < Some(value));
+                *last = Some(value);
+                taken.push(value);
+            }
+        }
+
+        let churners: Vec<_> = (0..3)
+            .map(|_| {
+                let queue = Arc::clone(&queue);
+                let barrier = Arc::clone(&barrier);
+                thread::spawn(move || {
+                    barrier.wait();
+                    let (mut last, mut taken) = (None, Vec::new());
+                    for _ in 0..20_000 {
+                        queue.enqueue(usize::MAX);
+                        take_ordered(queue.dequeue(), &mut last, &mut taken);
+                    }
+                    taken
+                })
+            })
+            .collect();
+
+        barrier.wait();
+        let (mut last, mut taken) = (None, Vec::new());
+        for i in 0..20_000 {
+            queue.enqueue(i);
+            take_ordered(queue.dequeue(), &mut last, &mut taken);
+        }
+        for handle in churners {
+            taken.extend(handle.join().unwrap());
+        }
+        while let Some(value) = queue.dequeue() {
+            take_ordered(Some(value), &mut last, &mut taken);
+        }
+
+        taken.sort();
+        assert_eq!(taken, (0..20_000).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,289 @@
+use crate::hazard_pointer::Domain;
+use std::mem::ManuallyDrop;
+use std::ptr;
+use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+
+/// A lock-free LIFO stack that can be shared between threads (Treiber stack).
+///
+/// The stack is a singly linked list, like [`crate::linked_list`], whose head
+/// is swapped with compare-and-swap. Popped nodes are reclaimed through
+/// hazard pointers, so a thread never reads a node that another thread freed.
+///
+/// # Type Parameters
+/// - `T`: The type of elements stored in the stack.
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::concurrent_stack::ConcurrentStack;
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// let stack = Arc::new(ConcurrentStack::new());
+/// let handles: Vec<_> = (0..4)
+///     .map(|i| {
+///         let stack = Arc::clone(&stack);
+///         thread::spawn(move || stack.push(i))
+///     })
+///     .collect();
+/// for handle in handles {
+///     handle.join().unwrap();
+/// }
+///
+/// assert_eq!(stack.len(), 4);
+/// let mut values: Vec<_> = std::iter::from_fn(|| stack.pop()).collect();
+/// values.sort();
+/// assert_eq!(values, vec![0, 1, 2, 3]);
+/// ```
+pub struct ConcurrentStack<T> {
+    head: AtomicPtr<Node<T>>,
+    length: AtomicUsize,
+    domain: Domain,
+}
+
+/// A node of the [`ConcurrentStack`].
+///
+/// The data is moved out by the thread that pops the node, while the node
+/// itself may still be read by others until it is reclaimed; `ManuallyDrop`
+/// keeps the reclamation from dropping the data a second time.
+struct Node<T> {
+    data: ManuallyDrop<T>,
+    next: *mut Node<T>,
+}
+
+impl<T> ConcurrentStack<T> {
+    /// Creates a new empty `ConcurrentStack`.
+    pub fn new() -> ConcurrentStack<T> {
+        ConcurrentStack {
+            head: AtomicPtr::new(ptr::null_mut()),
+            length: AtomicUsize::new(0),
+            domain: Domain::new(),
+        }
+    }
+
+    /// Pushes an item on top of the stack.
+    ///
+    /// # Complexity
+    /// - `O(1)` when uncontended, retried while other threads change the head
+    pub fn push(&self, data: T) {
+        let node = Box::into_raw(Box::new(Node {
+            data: ManuallyDrop::new(data),
+            next: ptr::null_mut(),
+        }));
+        // Counted before it is published, so a concurrent pop of the item
+        // never makes the counter go below zero
+        self.length.fetch_add(1, Ordering::Relaxed);
+
+        let mut head = self.head.load(Ordering::Relaxed);
+        loop {
+            // The node is not shared yet, so it can be written freely
+            unsafe { (*node).next = head };
+            match self
+                .head
+                .compare_exchange_weak(head, node, Ordering::Release, Ordering::Relaxed)
+            {
+                Ok(_) => break,
+                Err(current) => head = current,
+            }
+        }
+    }
+
+    /// Removes the item on top of the stack and returns it, or `None` if the
+    /// stack is empty.
+    ///
+    /// # Complexity
+    /// - `O(1)` when uncontended, retried while other threads change the head
+    pub fn pop(&self) -> Option<T> {
+        let hazard = self.domain.hazard_pointer();
+        loop {
+            let head = hazard.protect(&self.head);
+            if head.is_null() {
+                return None;
+            }
+
+            // The hazard pointer keeps `head` alive, and it cannot be reused
+            // by another push while protected, so the CAS is free of ABA.
+            let next = unsafe { (*head).next };
+            if self
+                .head
+                .compare_exchange(head, next, Ordering::AcqRel, Ordering::Relaxed)
+                .is_ok()
+            {
+                hazard.reset();
+                self.length.fetch_sub(1, Ordering::Relaxed);
+                unsafe {
+                    let data = ManuallyDrop::take(&mut (*head).data);
+                    self.domain.retire(head);
+                    return Some(data);
+                }
+            }
+        }
+    }
+
+    /// Gets the amount of items in the stack.
+    ///
+    /// The value is exact when no other thread is modifying the stack, and a
+    /// snapshot otherwise, which may already count items still being pushed.
+    pub fn len(&self) -> usize {
+        self.length.load(Ordering::Relaxed)
+    }
+
+    /// Checks if the stack is empty.
+    pub fn is_empty(&self) -> bool {
+        self.head.load(Ordering::Acquire).is_null()
+    }
+}
+
+impl<T> Default for ConcurrentStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for ConcurrentStack<T> {
+    fn drop(&mut self) {
+        let mut cursor = *self.head.get_mut();
+        while !cursor.is_null() {
+            unsafe {
+                let mut node = Box::from_raw(cursor);
+                cursor = node.next;
+                ManuallyDrop::drop(&mut node.data);
+            }
+        }
+    }
+}
+
+// Items are moved between threads by `push` and `pop`, so only `T: Send` is needed.
+unsafe impl<T: Send> Send for ConcurrentStack<T> {}
+unsafe impl<T: Send> Sync for ConcurrentStack<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::sync::{Arc, Barrier};
+    use std::thread;
+
+    struct DropCounter(Arc<AtomicUsize>);
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    #[test]
+    fn test_push_pop_lifo() {
+        let stack = ConcurrentStack::new();
+        assert!(stack.is_empty());
+        assert_eq!(stack.pop(), None);
+
+        for i in 0..10 {
+            stack.push(i);
+        }
+        assert_eq!(stack.len(), 10);
+        for i in (0..10).rev() {
+            assert_eq!(stack.pop(), Some(i));
+        }
+        assert!(stack.is_empty());
+        assert_eq!(stack.len(), 0);
+    }
+
+    #[test]
+    fn test_drop_frees_every_item() {
+        let drops = Arc::new(AtomicUsize::new(0));
+        let stack = ConcurrentStack::new();
+        for _ in 0..1000 {
+            stack.push(DropCounter(drops.clone()));
+        }
+        for _ in 0..500 {
+            drop(stack.pop());
+        }
+        assert_eq!(drops.load(Ordering::Relaxed), 500);
+
+        drop(stack);
+        assert_eq!(drops.load(Ordering::Relaxed), 1000);
+    }
+
+    #[test]
+    fn test_concurrent_push_then_pop() {
+        let threads = 8;
+        let per_thread = 10_000;
+        let stack = Arc::new(ConcurrentStack::new());
+
+        let handles: Vec<_> = (0..threads)
+            .map(|t| {
+                let stack = Arc::clone(&stack);
+                thread::spawn(move || {
+                    for i in 0..per_thread {
+                        stack.push(t * per_thread + i);
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(stack.len(), threads * per_thread);
+
+        let handles: Vec<_> = (0..threads)
+            .map(|_| {
+                let stack = Arc::clone(&stack);
+                thread::spawn(move || {
+                    let mut popped = Vec::new();
+                    while let Some(value) = stack.pop() {
+                        popped.push(value);
+                    }
+                    popped
+                })
+            })
+            .collect();
+
+        let mut seen = HashSet::new();
+        for handle in handles {
+            for value in handle.join().unwrap() {
+                assert!(seen.insert(value), "value {} popped twice", value);
+            }
+        }
+        assert_eq!(seen.len(), threads * per_thread);
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn test_concurrent_mixed_operations() {
+        let threads = 8;
+        let per_thread = 20_000;
+        let drops = Arc::new(AtomicUsize::new(0));
+        let stack = Arc::new(ConcurrentStack::new());
+        let barrier = Arc::new(Barrier::new(threads));
+
+        let handles: Vec<_> = (0..threads)
+            .map(|_| {
+                let stack = Arc::clone(&stack);
+                let drops = Arc::clone(&drops);
+                let barrier = Arc::clone(&barrier);
+                thread::spawn(move || {
+                    barrier.wait();
+                    let mut popped = 0;
+                    for i in 0..per_thread {
+                        stack.push(DropCounter(drops.clone()));
+                        if i % 2 == 0 && stack.pop().is_some() {
+                            popped += 1;
+                        }
+                        // A pop racing with the push of its item must not
+                        // wrap the counter around
+                        assert!(stack.len() <= threads * per_thread);
+                    }
+                    popped
+                })
+            })
+            .collect();
+
+        let popped: usize = handles.into_iter().map(|h| h.join().unwrap()).sum();
+        assert_eq!(drops.load(Ordering::Relaxed), popped);
+        assert_eq!(stack.len(), threads * per_thread - popped);
+
+        drop(Arc::try_unwrap(stack).ok().unwrap());
+        assert_eq!(drops.load(Ordering::Relaxed), threads * per_thread);
+    }
+}
@@ -3,9 +3,9 @@ use std::fmt;
 
 impl<T: fmt::Display> fmt::Display for DoublyLinkedList<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.len() == 0 {
+        if self.is_empty() {
             write!(f, "EMPTY")?;
-            return Ok({});
+            return Ok(());
         }
         let mut cursor = self.head;
         write!(f, "START -> ")?;
@@ -19,7 +19,7 @@ impl<T: fmt::Display> fmt::Display for DoublyLinkedList<T> {
             }
         }
         write!(f, "-> NULL")?;
-        Ok({})
+        Ok(())
     }
 }
 
@@ -30,7 +30,7 @@ impl<T: fmt::Display> fmt::Display for ListNode<T> {
         result.push_str(&(self.data.to_string()));
         result.push_str(" ]");
 
-        return write!(f, "{}", result);
+        write!(f, "{}", result)
     }
 }
 
@@ -6,8 +6,8 @@ impl<T> Drop for DoublyLinkedList<T> {
         while let Some(node) = cursor {
             unsafe {
                 // Deallocating memory from heap to avoid memory leakage
-                let _ = Box::from_raw(node);
-                cursor = (*node).next;
+                let boxed = Box::from_raw(node);
+                cursor = boxed.next;
             }
         }
     }
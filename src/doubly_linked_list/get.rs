@@ -43,9 +43,9 @@ impl<T> DoublyLinkedList<T> {
         }
 
         if pos < (self.len() / 2) {
-            return self.get_from_start(pos);
+            self.get_from_start(pos)
         } else {
-            return self.get_from_end(pos);
+            self.get_from_end(pos)
         }
     }
 
@@ -63,7 +63,7 @@ impl<T> DoublyLinkedList<T> {
                 counter += 1;
             }
         }
-        return None;
+        None
     }
 
     fn get_from_end(&self, pos: usize) -> Option<&T> {
@@ -80,7 +80,7 @@ impl<T> DoublyLinkedList<T> {
                 counter -= 1;
             }
         }
-        return None;
+        None
     }
 }
 
@@ -92,7 +92,7 @@ mod test {
     fn test_at_zero() {
         let mut list: DoublyLinkedList<i32> = DoublyLinkedList::new();
         let value = 69;
-        let _ = list.insert_at_beginning(value);
+        list.insert_at_beginning(value);
         let first = list.get(0).unwrap();
         assert_eq!(&value, first);
     }
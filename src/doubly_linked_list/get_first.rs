@@ -25,7 +25,7 @@ impl<T> DoublyLinkedList<T> {
     ///
     pub fn get_first(&self) -> Option<&T> {
         match self.head {
-            Some(head) => unsafe { return Some(&(*head).data) },
+            Some(head) => unsafe { Some(&(*head).data) },
             None => None,
         }
     }
@@ -24,9 +24,9 @@ impl<T> DoublyLinkedList<T> {
     /// # Complexity
     /// - `O(1)`
     ///
-    pub fn get_first_mut(&self) -> Option<&mut T> {
+    pub fn get_first_mut(&mut self) -> Option<&mut T> {
         match self.head {
-            Some(head) => unsafe { return Some(&mut (*head).data) },
+            Some(head) => unsafe { Some(&mut (*head).data) },
             None => None,
         }
     }
@@ -38,7 +38,7 @@ mod test {
 
     #[test]
     fn test_get_first_empty_list() {
-        let list: DoublyLinkedList<usize> = DoublyLinkedList::new();
+        let mut list: DoublyLinkedList<usize> = DoublyLinkedList::new();
         let i = list.get_first_mut();
 
         assert!(i.is_none());
@@ -47,7 +47,7 @@ mod test {
     #[test]
     fn test_get_first() {
         let value = 10;
-        let list: DoublyLinkedList<usize> = DoublyLinkedList::with_value(value);
+        let mut list: DoublyLinkedList<usize> = DoublyLinkedList::with_value(value);
         let i = list.get_first_mut();
         assert!(i.is_some());
 
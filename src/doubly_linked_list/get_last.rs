@@ -26,7 +26,7 @@ impl<T> DoublyLinkedList<T> {
     ///
     pub fn get_last(&self) -> Option<&T> {
         match self.tail {
-            Some(tail) => unsafe { return Some(&(*tail).data) },
+            Some(tail) => unsafe { Some(&(*tail).data) },
             None => None,
         }
     }
@@ -29,7 +29,7 @@ impl<T> DoublyLinkedList<T> {
     /// assert_eq!(list.get_mut(1), Some(&mut 20)); // Get the second element
     /// assert_eq!(list.get_mut(2), Some(&mut 30)); // Get the last element
     /// ```
-    pub fn get_mut(&self, pos: usize) -> Option<&mut T> {
+    pub fn get_mut(&mut self, pos: usize) -> Option<&mut T> {
         assert!(pos < self.len());
 
         // Get first and last are more efficient
@@ -42,13 +42,13 @@ impl<T> DoublyLinkedList<T> {
         }
 
         if pos < (self.len() / 2) {
-            return self.get_from_start_mut(pos);
+            self.get_from_start_mut(pos)
         } else {
-            return self.get_from_end_mut(pos);
+            self.get_from_end_mut(pos)
         }
     }
 
-    fn get_from_start_mut(&self, pos: usize) -> Option<&mut T> {
+    fn get_from_start_mut(&mut self, pos: usize) -> Option<&mut T> {
         let mut cursor = self.head;
         let mut counter = 0;
 
@@ -65,7 +65,7 @@ impl<T> DoublyLinkedList<T> {
         unreachable!()
     }
 
-    fn get_from_end_mut(&self, pos: usize) -> Option<&mut T> {
+    fn get_from_end_mut(&mut self, pos: usize) -> Option<&mut T> {
         let mut cursor = self.tail;
         let mut counter = self.len() - 1;
 
@@ -91,7 +91,7 @@ mod test {
     fn test_at_zero() {
         let mut list: DoublyLinkedList<i32> = DoublyLinkedList::new();
         let mut value = 69;
-        let _ = list.insert_at_beginning(value);
+        list.insert_at_beginning(value);
         let first = list.get_mut(0).unwrap();
         assert_eq!(&mut value, first);
     }
@@ -109,7 +109,7 @@ mod test {
     #[test]
     #[should_panic]
     fn test_at_non_zero_out_of_bounds() {
-        let list: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        let mut list: DoublyLinkedList<i32> = DoublyLinkedList::new();
         let _ = list.get_mut(20).unwrap();
     }
 
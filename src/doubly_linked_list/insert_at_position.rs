@@ -49,20 +49,20 @@ impl<T> DoublyLinkedList<T> {
         // Insert at beginning and insert at end are more efficient
         if pos == 0 {
             self.insert_at_beginning(data);
-            return Ok({});
+            return Ok(());
         }
 
         if pos == self.len() {
             self.insert_at_end(data);
-            return Ok({});
+            return Ok(());
         }
 
         if pos < (self.len() / 2) {
             self.insert_at_position_from_start(data, pos);
-            return Ok({});
+            Ok(())
         } else {
             self.insert_at_position_from_end(data, pos);
-            return Ok({});
+            Ok(())
         }
     }
 
@@ -139,7 +139,7 @@ mod test {
     #[should_panic]
     fn test_at_non_zero_out_of_bounds() {
         let mut list: DoublyLinkedList<i32> = DoublyLinkedList::new();
-        let _ = list.insert_at_position(1, 20).unwrap();
+        list.insert_at_position(1, 20).unwrap();
     }
 
     #[test]
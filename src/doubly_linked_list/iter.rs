@@ -0,0 +1,126 @@
+use super::{DoublyLinkedList, ListNode};
+use std::marker::PhantomData;
+
+/// Borrowing iterator over the elements of a [`DoublyLinkedList`].
+///
+/// It walks from the head forward and, as a [`DoubleEndedIterator`], from the
+/// tail backward.
+pub struct Iter<'a, T> {
+    head: Option<*mut ListNode<T>>,
+    tail: Option<*mut ListNode<T>>,
+    remaining: usize,
+    marker: PhantomData<&'a T>,
+}
+
+impl<T> DoublyLinkedList<T> {
+    /// Returns an iterator over the elements of the `DoublyLinkedList`, from
+    /// the first to the last.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list = DoublyLinkedList::new();
+    /// list.insert_at_end(1);
+    /// list.insert_at_end(2);
+    /// list.insert_at_end(3);
+    ///
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+    /// assert_eq!(list.iter().rev().collect::<Vec<_>>(), vec![&3, &2, &1]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            head: self.head,
+            tail: self.tail,
+            remaining: self.length,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a DoublyLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.head.map(|node| unsafe {
+            self.remaining -= 1;
+            self.head = (*node).next;
+            &(*node).data
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.tail.map(|node| unsafe {
+            self.remaining -= 1;
+            self.tail = (*node).prev;
+            &(*node).data
+        })
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_iter_empty_list() {
+        let list: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        assert_eq!(list.iter().next(), None);
+        assert_eq!(list.iter().next_back(), None);
+    }
+
+    #[test]
+    fn test_iter_both_ends() {
+        let mut list = DoublyLinkedList::new();
+        for i in 0..5 {
+            list.insert_at_end(i);
+        }
+
+        let mut iter = list.iter();
+        assert_eq!(iter.len(), 5);
+        assert_eq!(iter.next(), Some(&0));
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&3));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_for_loop() {
+        let mut list = DoublyLinkedList::new();
+        list.insert_at_beginning(2);
+        list.insert_at_beginning(1);
+
+        let mut sum = 0;
+        for value in &list {
+            sum += value;
+        }
+        assert_eq!(sum, 3);
+    }
+}
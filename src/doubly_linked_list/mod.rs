@@ -22,7 +22,6 @@
 ///
 /// - `head`: A pointer to the first node in the list. This is `None` when the list is empty.
 /// - `length`: The current number of elements in the list.
-
 pub struct DoublyLinkedList<T> {
     head: Option<*mut ListNode<T>>,
     tail: Option<*mut ListNode<T>>,
@@ -52,6 +51,10 @@ struct ListNode<T> {
     data: T,
 }
 
+// The list owns its nodes, so moving it to another thread moves the items with
+// it. It is not `Sync`: the `get_*_mut` methods hand out `&mut T` from `&self`.
+unsafe impl<T: Send> Send for DoublyLinkedList<T> {}
+
 pub mod delete;
 pub mod delete_first;
 pub mod delete_last;
@@ -67,6 +70,9 @@ pub mod insert_at_beginning;
 pub mod insert_at_end;
 pub mod insert_at_position;
 pub mod is_empty;
+pub mod iter;
 pub mod len;
 pub mod new;
+pub mod remove_first;
+pub mod remove_last;
 pub mod with_value;
@@ -30,6 +30,12 @@ impl<T> DoublyLinkedList<T> {
     }
 }
 
+impl<T> Default for DoublyLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
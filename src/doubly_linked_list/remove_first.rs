@@ -0,0 +1,88 @@
+use super::DoublyLinkedList;
+
+impl<T> DoublyLinkedList<T> {
+    /// Removes the first element from the `DoublyLinkedList` and returns it.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(T)`: The element that was at the start of the list.
+    /// - `None`: If the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list = DoublyLinkedList::new();
+    /// list.insert_at_end(1);
+    /// list.insert_at_end(2);
+    ///
+    /// assert_eq!(list.remove_first(), Some(1));
+    /// assert_eq!(list.remove_first(), Some(2));
+    /// assert_eq!(list.remove_first(), None);
+    /// ```
+    /// # Safety
+    ///
+    /// - This method uses `unsafe` internally to take back ownership of the removed node.
+    /// - It is safe as long as the internal structure of the `DoublyLinkedList` remains valid.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(1)`
+    /// - **Space Complexity:** `O(1)`
+    pub fn remove_first(&mut self) -> Option<T> {
+        self.head.map(|head| unsafe {
+            let node = Box::from_raw(head);
+            self.head = node.next;
+            match node.next {
+                Some(next) => (*next).prev = None,
+                // One item lists
+                None => self.tail = None,
+            }
+            self.length -= 1;
+            node.data
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_remove_first_empty_list() {
+        let mut list: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        assert_eq!(list.remove_first(), None);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_remove_first_until_empty() {
+        let mut list = DoublyLinkedList::new();
+        for i in 0..3 {
+            list.insert_at_end(i);
+        }
+
+        assert_eq!(list.remove_first(), Some(0));
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.get_first(), Some(&1));
+        assert_eq!(list.get_last(), Some(&2));
+
+        assert_eq!(list.remove_first(), Some(1));
+        assert_eq!(list.remove_first(), Some(2));
+        assert!(list.is_empty());
+        assert_eq!(list.get_last(), None);
+
+        list.insert_at_end(3);
+        assert_eq!(list.get_first(), Some(&3));
+        assert_eq!(list.get_last(), Some(&3));
+    }
+
+    #[test]
+    fn test_remove_first_owned_values() {
+        let mut list = DoublyLinkedList::new();
+        list.insert_at_end(String::from("a"));
+        list.insert_at_end(String::from("b"));
+        assert_eq!(list.remove_first(), Some(String::from("a")));
+    }
+}
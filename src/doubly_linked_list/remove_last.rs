@@ -0,0 +1,90 @@
+use super::DoublyLinkedList;
+
+impl<T> DoublyLinkedList<T> {
+    /// Removes the last element from the `DoublyLinkedList` and returns it.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(T)`: The element that was at the end of the list.
+    /// - `None`: If the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::doubly_linked_list::DoublyLinkedList;
+    ///
+    /// let mut list = DoublyLinkedList::new();
+    /// list.insert_at_end(1);
+    /// list.insert_at_end(2);
+    ///
+    /// assert_eq!(list.remove_last(), Some(2));
+    /// assert_eq!(list.remove_last(), Some(1));
+    /// assert_eq!(list.remove_last(), None);
+    /// ```
+    /// # Safety
+    ///
+    /// - This method uses `unsafe` internally to take back ownership of the removed node.
+    /// - It is safe as long as the internal structure of the `DoublyLinkedList` remains valid.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(1)`
+    /// - **Space Complexity:** `O(1)`
+    pub fn remove_last(&mut self) -> Option<T> {
+        self.tail.map(|tail| unsafe {
+            let node = Box::from_raw(tail);
+            self.tail = node.prev;
+            match node.prev {
+                Some(prev) => (*prev).next = None,
+                // One item lists
+                None => self.head = None,
+            }
+            self.length -= 1;
+            node.data
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_remove_last_empty_list() {
+        let mut list: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        assert_eq!(list.remove_last(), None);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_remove_last_until_empty() {
+        let mut list = DoublyLinkedList::new();
+        for i in 0..3 {
+            list.insert_at_end(i);
+        }
+
+        assert_eq!(list.remove_last(), Some(2));
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.get_first(), Some(&0));
+        assert_eq!(list.get_last(), Some(&1));
+
+        assert_eq!(list.remove_last(), Some(1));
+        assert_eq!(list.remove_last(), Some(0));
+        assert!(list.is_empty());
+        assert_eq!(list.get_first(), None);
+
+        list.insert_at_beginning(3);
+        assert_eq!(list.get_first(), Some(&3));
+        assert_eq!(list.get_last(), Some(&3));
+    }
+
+    #[test]
+    fn test_remove_last_mixed_with_remove_first() {
+        let mut list = DoublyLinkedList::new();
+        list.insert_at_end(String::from("a"));
+        list.insert_at_end(String::from("b"));
+        assert_eq!(list.remove_last(), Some(String::from("b")));
+        assert_eq!(list.remove_first(), Some(String::from("a")));
+        assert_eq!(list.remove_last(), None);
+    }
+}
@@ -0,0 +1,94 @@
+use super::{Bracket, ExpressionError, TokenKind};
+use crate::stack::{ArrayStack, Stack};
+
+/// Checks that every bracket of `input` is closed by a bracket of the same
+/// kind, in the right order. Characters other than brackets are ignored, so
+/// any text can be checked, not only expressions.
+///
+/// # Errors
+///
+/// - [`ExpressionError::UnmatchedClosingBracket`] for a closing bracket that was never opened.
+/// - [`ExpressionError::MismatchedBracket`] for a pair like `(]`.
+/// - [`ExpressionError::UnclosedBracket`] for the innermost bracket left open.
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::expression::{check_balanced, ExpressionError};
+///
+/// assert!(check_balanced("fn main() { let v = [1, 2]; }").is_ok());
+/// assert_eq!(
+///     check_balanced("{ ( }"),
+///     Err(ExpressionError::MismatchedBracket { open: 2, close: 4 })
+/// );
+/// ```
+///
+/// # Complexity
+/// - `O(n)`
+pub fn check_balanced(input: &str) -> Result<(), ExpressionError> {
+    let mut open: ArrayStack<(Bracket, usize)> = ArrayStack::new();
+
+    for (position, character) in input.char_indices() {
+        match Bracket::token_kind(character) {
+            Some(TokenKind::Open(bracket)) => open.push((bracket, position)),
+            Some(TokenKind::Close(bracket)) => check_closing(&mut open, bracket, position)?,
+            _ => {}
+        }
+    }
+
+    match open.pop() {
+        Some((_, position)) => Err(ExpressionError::UnclosedBracket { position }),
+        None => Ok(()),
+    }
+}
+
+/// Pops the bracket closed by `bracket` at `position`, failing if it is not
+/// of the same kind.
+fn check_closing<S: Stack<(Bracket, usize)>>(
+    open: &mut S,
+    bracket: Bracket,
+    position: usize,
+) -> Result<(), ExpressionError> {
+    match open.pop() {
+        Some((expected, _)) if expected == bracket => Ok(()),
+        Some((_, opened)) => Err(ExpressionError::MismatchedBracket {
+            open: opened,
+            close: position,
+        }),
+        None => Err(ExpressionError::UnmatchedClosingBracket { position }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_balanced() {
+        assert!(check_balanced("").is_ok());
+        assert!(check_balanced("no brackets").is_ok());
+        assert!(check_balanced("()[]{}").is_ok());
+        assert!(check_balanced("{[()()]}").is_ok());
+        assert!(check_balanced("a(b[c]{d}e)f").is_ok());
+    }
+
+    #[test]
+    fn test_unbalanced() {
+        assert_eq!(
+            check_balanced("())"),
+            Err(ExpressionError::UnmatchedClosingBracket { position: 2 })
+        );
+        assert_eq!(
+            check_balanced("([)]"),
+            Err(ExpressionError::MismatchedBracket { open: 1, close: 2 })
+        );
+        assert_eq!(
+            check_balanced("((()"),
+            Err(ExpressionError::UnclosedBracket { position: 1 })
+        );
+        assert_eq!(
+            check_balanced("]"),
+            Err(ExpressionError::UnmatchedClosingBracket { position: 0 })
+        );
+    }
+}
@@ -0,0 +1,221 @@
+use super::{evaluate_postfix_tokens, tokenize, ExpressionError, Operator, Token, TokenKind};
+use crate::stack::{ArrayStack, Stack};
+
+/// Converts an infix expression to postfix notation (shunting-yard).
+///
+/// Operators wait on a stack until an operator of lower precedence, or of
+/// equal precedence when left associative, arrives or a bracket closes.
+/// A `-` found where an operand is expected becomes [`Operator::Negate`].
+///
+/// # Errors
+///
+/// Besides the errors of [`tokenize`](super::tokenize):
+/// - [`ExpressionError::Empty`] if the input has no tokens.
+/// - [`ExpressionError::UnexpectedToken`] for tokens out of place, such as `1 2` or `(*`.
+/// - [`ExpressionError::UnexpectedEnd`] if the input ends after an operator.
+/// - [`ExpressionError::UnmatchedClosingBracket`], [`ExpressionError::MismatchedBracket`]
+///   and [`ExpressionError::UnclosedBracket`] for unbalanced brackets.
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::expression::{to_postfix, to_string};
+///
+/// let postfix = to_postfix("3 + 4 * 2 / (1 - 5) ^ 2 ^ 3").unwrap();
+/// assert_eq!(to_string(&postfix), "3 4 2 * 1 5 - 2 3 ^ ^ / +");
+/// ```
+///
+/// # Complexity
+/// - `O(n)`
+pub fn to_postfix(input: &str) -> Result<Vec<Token>, ExpressionError> {
+    let tokens = tokenize(input)?;
+    if tokens.is_empty() {
+        return Err(ExpressionError::Empty);
+    }
+
+    let mut output = Vec::with_capacity(tokens.len());
+    let mut pending: ArrayStack<Token> = ArrayStack::new();
+    // Whether the next token must start an operand: a number, an opening
+    // bracket or a unary operator
+    let mut expect_operand = true;
+
+    for token in tokens {
+        let position = token.position;
+        match token.kind {
+            TokenKind::Number(_) if expect_operand => {
+                output.push(token);
+                expect_operand = false;
+            }
+            TokenKind::Open(_) if expect_operand => pending.push(token),
+            TokenKind::Close(bracket) if !expect_operand => loop {
+                match pending.pop() {
+                    Some(Token {
+                        kind: TokenKind::Open(open),
+                        position: opened,
+                    }) => {
+                        if open != bracket {
+                            return Err(ExpressionError::MismatchedBracket {
+                                open: opened,
+                                close: position,
+                            });
+                        }
+                        break;
+                    }
+                    Some(operator) => output.push(operator),
+                    None => return Err(ExpressionError::UnmatchedClosingBracket { position }),
+                }
+            },
+            TokenKind::Operator(Operator::Subtract | Operator::Negate) if expect_operand => {
+                pending.push(Token {
+                    kind: TokenKind::Operator(Operator::Negate),
+                    position,
+                });
+            }
+            TokenKind::Operator(operator) if !expect_operand && operator.arity() == 2 => {
+                while let Some(&Token {
+                    kind: TokenKind::Operator(top),
+                    ..
+                }) = pending.peek()
+                {
+                    let binds_tighter = top.precedence() > operator.precedence()
+                        || (top.precedence() == operator.precedence()
+                            && !operator.is_right_associative());
+                    if !binds_tighter {
+                        break;
+                    }
+                    output.extend(pending.pop());
+                }
+                pending.push(token);
+                expect_operand = true;
+            }
+            _ => return Err(ExpressionError::UnexpectedToken { position }),
+        }
+    }
+
+    if expect_operand {
+        return Err(ExpressionError::UnexpectedEnd {
+            position: input.len(),
+        });
+    }
+
+    while let Some(token) = pending.pop() {
+        if let TokenKind::Open(_) = token.kind {
+            return Err(ExpressionError::UnclosedBracket {
+                position: token.position,
+            });
+        }
+        output.push(token);
+    }
+    Ok(output)
+}
+
+/// Evaluates an infix expression.
+///
+/// # Errors
+///
+/// The errors of [`to_postfix`], and [`ExpressionError::DivisionByZero`].
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::expression::{evaluate, ExpressionError};
+///
+/// assert_eq!(evaluate("{[1 + 2] * 3} % 4"), Ok(1.0));
+/// assert_eq!(evaluate("2 ^ 3 ^ 2"), Ok(512.0));
+/// assert_eq!(
+///     evaluate("1 / (2 - 2)"),
+///     Err(ExpressionError::DivisionByZero { position: 2 })
+/// );
+/// ```
+pub fn evaluate(input: &str) -> Result<f64, ExpressionError> {
+    evaluate_postfix_tokens(&to_postfix(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::to_string;
+    use super::*;
+
+    fn postfix(input: &str) -> String {
+        to_string(&to_postfix(input).unwrap())
+    }
+
+    #[test]
+    fn test_precedence() {
+        assert_eq!(postfix("1 + 2 * 3"), "1 2 3 * +");
+        assert_eq!(postfix("1 * 2 + 3"), "1 2 * 3 +");
+        assert_eq!(postfix("(1 + 2) * 3"), "1 2 + 3 *");
+        assert_eq!(postfix("1 + 2 % 3 - 4 / 5"), "1 2 3 % + 4 5 / -");
+    }
+
+    #[test]
+    fn test_associativity() {
+        assert_eq!(postfix("1 - 2 - 3"), "1 2 - 3 -");
+        assert_eq!(postfix("8 / 4 / 2"), "8 4 / 2 /");
+        assert_eq!(postfix("2 ^ 3 ^ 2"), "2 3 2 ^ ^");
+    }
+
+    #[test]
+    fn test_unary_minus() {
+        assert_eq!(postfix("-3"), "3 ~");
+        assert_eq!(postfix("-2 ^ 2"), "2 2 ^ ~");
+        assert_eq!(postfix("2 ^ -1"), "2 1 ~ ^");
+        assert_eq!(postfix("1 - -1"), "1 1 ~ -");
+        assert_eq!(postfix("-(1 + 2) * 3"), "1 2 + ~ 3 *");
+        assert_eq!(postfix("~~4"), "4 ~ ~");
+    }
+
+    #[test]
+    fn test_evaluate() {
+        assert_eq!(evaluate("42"), Ok(42.0));
+        assert_eq!(evaluate("1 + 2 * 3"), Ok(7.0));
+        assert_eq!(evaluate("(1 + 2) * 3"), Ok(9.0));
+        assert_eq!(evaluate("10 - 4 - 3"), Ok(3.0));
+        assert_eq!(evaluate("2 ^ 3 ^ 2"), Ok(512.0));
+        assert_eq!(evaluate("-2 ^ 2"), Ok(-4.0));
+        assert_eq!(evaluate("(-2) ^ 2"), Ok(4.0));
+        assert_eq!(evaluate("7 % 4 * 2"), Ok(6.0));
+        assert_eq!(evaluate("1.5 * [2 + {0.5}]"), Ok(3.75));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(evaluate(""), Err(ExpressionError::Empty));
+        assert_eq!(
+            evaluate("1 2"),
+            Err(ExpressionError::UnexpectedToken { position: 2 })
+        );
+        assert_eq!(
+            evaluate("(* 2)"),
+            Err(ExpressionError::UnexpectedToken { position: 1 })
+        );
+        assert_eq!(
+            evaluate("()"),
+            Err(ExpressionError::UnexpectedToken { position: 1 })
+        );
+        assert_eq!(
+            evaluate("2 (3)"),
+            Err(ExpressionError::UnexpectedToken { position: 2 })
+        );
+        assert_eq!(
+            evaluate("1 +"),
+            Err(ExpressionError::UnexpectedEnd { position: 3 })
+        );
+        assert_eq!(
+            evaluate("1 + 2)"),
+            Err(ExpressionError::UnmatchedClosingBracket { position: 5 })
+        );
+        assert_eq!(
+            evaluate("[1 + 2)"),
+            Err(ExpressionError::MismatchedBracket { open: 0, close: 6 })
+        );
+        assert_eq!(
+            evaluate("((1 + 2)"),
+            Err(ExpressionError::UnclosedBracket { position: 0 })
+        );
+        assert_eq!(
+            evaluate("4 % 0"),
+            Err(ExpressionError::DivisionByZero { position: 2 })
+        );
+    }
+}
@@ -0,0 +1,303 @@
+//! Parsing and evaluation of arithmetic expressions.
+//!
+//! Expressions are first split into [`Token`]s, each remembering its byte
+//! offset in the input so errors can point at the offending character.
+//! Infix expressions are converted to postfix notation with the
+//! shunting-yard algorithm and then evaluated; postfix and prefix
+//! expressions can be evaluated directly. All algorithms keep their
+//! intermediate state in the crate's own [`ArrayStack`](crate::stack::ArrayStack).
+//!
+//! Supported operators, from lowest to highest precedence:
+//! - `+` and `-`: addition and subtraction, left associative.
+//! - `*`, `/` and `%`: multiplication, division and remainder, left associative.
+//! - unary `-`: negation. In postfix and prefix input it is written `~`.
+//! - `^`: exponentiation, right associative.
+//!
+//! Sub-expressions can be grouped with `()`, `[]` or `{}`.
+//!
+//! # Examples
+//!
+//! ```
+//! use villa01_data_structures::expression::{self, ExpressionError};
+//!
+//! assert_eq!(expression::evaluate("2 * (3 + 4) ^ 2"), Ok(98.0));
+//! assert_eq!(expression::evaluate("-2 ^ 2"), Ok(-4.0));
+//! assert_eq!(expression::evaluate_postfix("2 3 4 + *"), Ok(14.0));
+//! assert_eq!(expression::evaluate_prefix("* 2 + 3 4"), Ok(14.0));
+//!
+//! let postfix = expression::to_postfix("1 - 2 - 3").unwrap();
+//! assert_eq!(expression::to_string(&postfix), "1 2 - 3 -");
+//!
+//! assert_eq!(
+//!     expression::evaluate("(1 + 2]"),
+//!     Err(ExpressionError::MismatchedBracket { open: 0, close: 6 })
+//! );
+//! ```
+
+use std::error::Error;
+use std::fmt;
+
+pub mod brackets;
+pub mod infix;
+pub mod postfix;
+pub mod prefix;
+pub mod tokenize;
+
+pub use brackets::check_balanced;
+pub use infix::{evaluate, to_postfix};
+pub use postfix::{evaluate_postfix, evaluate_postfix_tokens};
+pub use prefix::{evaluate_prefix, evaluate_prefix_tokens};
+pub use tokenize::tokenize;
+
+/// A piece of an expression together with its byte offset in the input.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub position: usize,
+}
+
+/// The kinds of [`Token`] an expression is made of.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TokenKind {
+    Number(f64),
+    Operator(Operator),
+    Open(Bracket),
+    Close(Bracket),
+}
+
+/// The arithmetic operators understood by the expression module.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+    Power,
+    Negate,
+}
+
+/// The bracket pairs that can group sub-expressions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Bracket {
+    /// `(` and `)`
+    Round,
+    /// `[` and `]`
+    Square,
+    /// `{` and `}`
+    Curly,
+}
+
+/// The errors reported while parsing or evaluating an expression.
+///
+/// Positions are byte offsets in the input string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExpressionError {
+    /// The input holds no token at all.
+    Empty,
+    /// A character that is not part of any token.
+    UnexpectedCharacter { character: char, position: usize },
+    /// A run of digits and dots that is not a valid number, like `1.2.3`.
+    InvalidNumber { position: usize },
+    /// A token that cannot appear where it is, like `*` right after `(`.
+    UnexpectedToken { position: usize },
+    /// The expression ends where an operand was expected.
+    UnexpectedEnd { position: usize },
+    /// A closing bracket with no opening bracket before it.
+    UnmatchedClosingBracket { position: usize },
+    /// An opening bracket closed by a bracket of another kind.
+    MismatchedBracket { open: usize, close: usize },
+    /// An opening bracket that is never closed.
+    UnclosedBracket { position: usize },
+    /// An operator without enough operands to apply it to.
+    MissingOperand { position: usize },
+    /// An operand left over once every operator was applied.
+    ExtraOperand { position: usize },
+    /// A division or remainder by zero.
+    DivisionByZero { position: usize },
+}
+
+impl Operator {
+    /// Binding strength of the operator: higher binds tighter.
+    pub fn precedence(&self) -> u8 {
+        match self {
+            Operator::Add | Operator::Subtract => 1,
+            Operator::Multiply | Operator::Divide | Operator::Remainder => 2,
+            Operator::Negate => 3,
+            Operator::Power => 4,
+        }
+    }
+
+    /// Whether `a op b op c` groups as `a op (b op c)`.
+    pub fn is_right_associative(&self) -> bool {
+        matches!(self, Operator::Power | Operator::Negate)
+    }
+
+    /// The amount of operands the operator takes.
+    pub fn arity(&self) -> usize {
+        match self {
+            Operator::Negate => 1,
+            _ => 2,
+        }
+    }
+
+    /// The character the operator is written with in postfix and prefix notation.
+    pub fn symbol(&self) -> char {
+        match self {
+            Operator::Add => '+',
+            Operator::Subtract => '-',
+            Operator::Multiply => '*',
+            Operator::Divide => '/',
+            Operator::Remainder => '%',
+            Operator::Power => '^',
+            Operator::Negate => '~',
+        }
+    }
+
+    /// Applies the operator found at `position` to its operands.
+    ///
+    /// For unary operators `rhs` is ignored.
+    fn apply(&self, lhs: f64, rhs: f64, position: usize) -> Result<f64, ExpressionError> {
+        match self {
+            Operator::Add => Ok(lhs + rhs),
+            Operator::Subtract => Ok(lhs - rhs),
+            Operator::Multiply => Ok(lhs * rhs),
+            Operator::Divide | Operator::Remainder if rhs == 0.0 => {
+                Err(ExpressionError::DivisionByZero { position })
+            }
+            Operator::Divide => Ok(lhs / rhs),
+            Operator::Remainder => Ok(lhs % rhs),
+            Operator::Power => Ok(lhs.powf(rhs)),
+            Operator::Negate => Ok(-lhs),
+        }
+    }
+}
+
+impl Bracket {
+    /// The token `character` stands for, if it is a bracket.
+    fn token_kind(character: char) -> Option<TokenKind> {
+        match character {
+            '(' => Some(TokenKind::Open(Bracket::Round)),
+            '[' => Some(TokenKind::Open(Bracket::Square)),
+            '{' => Some(TokenKind::Open(Bracket::Curly)),
+            ')' => Some(TokenKind::Close(Bracket::Round)),
+            ']' => Some(TokenKind::Close(Bracket::Square)),
+            '}' => Some(TokenKind::Close(Bracket::Curly)),
+            _ => None,
+        }
+    }
+
+    fn open_symbol(&self) -> char {
+        match self {
+            Bracket::Round => '(',
+            Bracket::Square => '[',
+            Bracket::Curly => '{',
+        }
+    }
+
+    fn close_symbol(&self) -> char {
+        match self {
+            Bracket::Round => ')',
+            Bracket::Square => ']',
+            Bracket::Curly => '}',
+        }
+    }
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            TokenKind::Number(value) => write!(f, "{}", value),
+            TokenKind::Operator(operator) => write!(f, "{}", operator.symbol()),
+            TokenKind::Open(bracket) => write!(f, "{}", bracket.open_symbol()),
+            TokenKind::Close(bracket) => write!(f, "{}", bracket.close_symbol()),
+        }
+    }
+}
+
+impl fmt::Display for ExpressionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExpressionError::Empty => write!(f, "empty expression"),
+            ExpressionError::UnexpectedCharacter {
+                character,
+                position,
+            } => write!(f, "unexpected character '{}' at {}", character, position),
+            ExpressionError::InvalidNumber { position } => {
+                write!(f, "invalid number at {}", position)
+            }
+            ExpressionError::UnexpectedToken { position } => {
+                write!(f, "unexpected token at {}", position)
+            }
+            ExpressionError::UnexpectedEnd { position } => {
+                write!(f, "expected an operand at {}", position)
+            }
+            ExpressionError::UnmatchedClosingBracket { position } => {
+                write!(f, "closing bracket at {} was never opened", position)
+            }
+            ExpressionError::MismatchedBracket { open, close } => write!(
+                f,
+                "bracket opened at {} does not match bracket closed at {}",
+                open, close
+            ),
+            ExpressionError::UnclosedBracket { position } => {
+                write!(f, "bracket opened at {} is never closed", position)
+            }
+            ExpressionError::MissingOperand { position } => {
+                write!(f, "operator at {} is missing an operand", position)
+            }
+            ExpressionError::ExtraOperand { position } => {
+                write!(f, "operand at {} is not used by any operator", position)
+            }
+            ExpressionError::DivisionByZero { position } => {
+                write!(f, "division by zero at {}", position)
+            }
+        }
+    }
+}
+
+impl Error for ExpressionError {}
+
+/// Joins tokens with single spaces, e.g. to show a postfix expression.
+pub fn to_string(tokens: &[Token]) -> String {
+    tokens
+        .iter()
+        .map(Token::to_string)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operator_properties() {
+        assert!(Operator::Power.precedence() > Operator::Negate.precedence());
+        assert!(Operator::Negate.precedence() > Operator::Multiply.precedence());
+        assert!(Operator::Multiply.precedence() > Operator::Add.precedence());
+        assert!(Operator::Power.is_right_associative());
+        assert!(!Operator::Subtract.is_right_associative());
+        assert_eq!(Operator::Negate.arity(), 1);
+        assert_eq!(Operator::Remainder.arity(), 2);
+    }
+
+    #[test]
+    fn test_apply() {
+        assert_eq!(Operator::Remainder.apply(7.0, 4.0, 0), Ok(3.0));
+        assert_eq!(Operator::Power.apply(2.0, 10.0, 0), Ok(1024.0));
+        assert_eq!(
+            Operator::Divide.apply(1.0, 0.0, 5),
+            Err(ExpressionError::DivisionByZero { position: 5 })
+        );
+    }
+
+    #[test]
+    fn test_error_display() {
+        let error = ExpressionError::UnexpectedCharacter {
+            character: '$',
+            position: 3,
+        };
+        assert_eq!(error.to_string(), "unexpected character '$' at 3");
+    }
+}
@@ -0,0 +1,116 @@
+use super::{tokenize, ExpressionError, Token, TokenKind};
+use crate::stack::{ArrayStack, Stack};
+
+/// Evaluates an expression written in postfix notation, such as `2 3 4 + *`.
+///
+/// Negation is written `~`, as `-` always takes two operands here.
+///
+/// # Errors
+///
+/// Besides the errors of [`tokenize`](super::tokenize), those of
+/// [`evaluate_postfix_tokens`].
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::expression::evaluate_postfix;
+///
+/// assert_eq!(evaluate_postfix("5 1 2 + 4 * + 3 -"), Ok(14.0));
+/// assert_eq!(evaluate_postfix("2 ~ 3 *"), Ok(-6.0));
+/// ```
+pub fn evaluate_postfix(input: &str) -> Result<f64, ExpressionError> {
+    evaluate_postfix_tokens(&tokenize(input)?)
+}
+
+/// Evaluates tokens in postfix order, e.g. the output of [`to_postfix`](super::to_postfix).
+///
+/// Operands wait on a stack until an operator consumes them.
+///
+/// # Errors
+///
+/// - [`ExpressionError::Empty`] if there are no tokens.
+/// - [`ExpressionError::UnexpectedToken`] for brackets, which postfix notation never needs.
+/// - [`ExpressionError::MissingOperand`] for an operator applied to too few operands.
+/// - [`ExpressionError::ExtraOperand`] if operands are left once all operators are applied.
+/// - [`ExpressionError::DivisionByZero`].
+///
+/// # Complexity
+/// - `O(n)`
+pub fn evaluate_postfix_tokens(tokens: &[Token]) -> Result<f64, ExpressionError> {
+    // Every value remembers where it comes from, to point at leftovers
+    let mut values: ArrayStack<(f64, usize)> = ArrayStack::new();
+
+    for token in tokens {
+        let position = token.position;
+        match token.kind {
+            TokenKind::Number(value) => values.push((value, position)),
+            TokenKind::Operator(operator) => {
+                let missing = ExpressionError::MissingOperand { position };
+                let (rhs, _) = values.pop().ok_or(missing)?;
+                let result = if operator.arity() == 1 {
+                    operator.apply(rhs, 0.0, position)?
+                } else {
+                    let (lhs, _) = values.pop().ok_or(missing)?;
+                    operator.apply(lhs, rhs, position)?
+                };
+                values.push((result, position));
+            }
+            TokenKind::Open(_) | TokenKind::Close(_) => {
+                return Err(ExpressionError::UnexpectedToken { position })
+            }
+        }
+    }
+
+    let (result, _) = values.pop().ok_or(ExpressionError::Empty)?;
+    match values.pop() {
+        Some((_, position)) => Err(ExpressionError::ExtraOperand { position }),
+        None => Ok(result),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expression::to_postfix;
+
+    #[test]
+    fn test_evaluate_postfix() {
+        assert_eq!(evaluate_postfix("3"), Ok(3.0));
+        assert_eq!(evaluate_postfix("2 3 +"), Ok(5.0));
+        assert_eq!(evaluate_postfix("10 2 8 * + 3 -"), Ok(23.0));
+        assert_eq!(evaluate_postfix("2 3 2 ^ ^"), Ok(512.0));
+        assert_eq!(evaluate_postfix("7 2 %"), Ok(1.0));
+        assert_eq!(evaluate_postfix("4 ~ ~"), Ok(4.0));
+    }
+
+    #[test]
+    fn test_matches_infix() {
+        let tokens = to_postfix("(5 + 3) * -2 / 4").unwrap();
+        assert_eq!(evaluate_postfix_tokens(&tokens), Ok(-4.0));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(evaluate_postfix(""), Err(ExpressionError::Empty));
+        assert_eq!(
+            evaluate_postfix("1 +"),
+            Err(ExpressionError::MissingOperand { position: 2 })
+        );
+        assert_eq!(
+            evaluate_postfix("~"),
+            Err(ExpressionError::MissingOperand { position: 0 })
+        );
+        assert_eq!(
+            evaluate_postfix("1 2 3 +"),
+            Err(ExpressionError::ExtraOperand { position: 0 })
+        );
+        assert_eq!(
+            evaluate_postfix("1 ( 2 +"),
+            Err(ExpressionError::UnexpectedToken { position: 2 })
+        );
+        assert_eq!(
+            evaluate_postfix("1 0 /"),
+            Err(ExpressionError::DivisionByZero { position: 4 })
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use super::{tokenize, ExpressionError, Token, TokenKind};
+use crate::stack::{ArrayStack, Stack};
+
+/// Evaluates an expression written in prefix notation, such as `* 2 + 3 4`.
+///
+/// Negation is written `~`, as `-` always takes two operands here.
+///
+/// # Errors
+///
+/// Besides the errors of [`tokenize`](super::tokenize), those of
+/// [`evaluate_prefix_tokens`].
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::expression::evaluate_prefix;
+///
+/// assert_eq!(evaluate_prefix("- + 5 * 2 3 1"), Ok(10.0));
+/// assert_eq!(evaluate_prefix("^ 2 ~ 1"), Ok(0.5));
+/// ```
+pub fn evaluate_prefix(input: &str) -> Result<f64, ExpressionError> {
+    evaluate_prefix_tokens(&tokenize(input)?)
+}
+
+/// Evaluates tokens in prefix order.
+///
+/// The tokens are read from right to left, so every operator finds its
+/// operands on top of the stack, the first operand above the second.
+///
+/// # Errors
+///
+/// - [`ExpressionError::Empty`] if there are no tokens.
+/// - [`ExpressionError::UnexpectedToken`] for brackets, which prefix notation never needs.
+/// - [`ExpressionError::MissingOperand`] for an operator applied to too few operands.
+/// - [`ExpressionError::ExtraOperand`] if operands are left once all operators are applied.
+/// - [`ExpressionError::DivisionByZero`].
+///
+/// # Complexity
+/// - `O(n)`
+pub fn evaluate_prefix_tokens(tokens: &[Token]) -> Result<f64, ExpressionError> {
+    let mut values: ArrayStack<(f64, usize)> = ArrayStack::new();
+
+    for token in tokens.iter().rev() {
+        let position = token.position;
+        match token.kind {
+            TokenKind::Number(value) => values.push((value, position)),
+            TokenKind::Operator(operator) => {
+                let missing = ExpressionError::MissingOperand { position };
+                let (lhs, _) = values.pop().ok_or(missing)?;
+                let result = if operator.arity() == 1 {
+                    operator.apply(lhs, 0.0, position)?
+                } else {
+                    let (rhs, _) = values.pop().ok_or(missing)?;
+                    operator.apply(lhs, rhs, position)?
+                };
+                values.push((result, position));
+            }
+            TokenKind::Open(_) | TokenKind::Close(_) => {
+                return Err(ExpressionError::UnexpectedToken { position })
+            }
+        }
+    }
+
+    let (result, _) = values.pop().ok_or(ExpressionError::Empty)?;
+    match values.pop() {
+        Some((_, position)) => Err(ExpressionError::ExtraOperand { position }),
+        None => Ok(result),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evaluate_prefix() {
+        assert_eq!(evaluate_prefix("3"), Ok(3.0));
+        assert_eq!(evaluate_prefix("- 10 4"), Ok(6.0));
+        assert_eq!(evaluate_prefix("/ 8 2"), Ok(4.0));
+        assert_eq!(evaluate_prefix("+ * 2 3 * 4 5"), Ok(26.0));
+        assert_eq!(evaluate_prefix("^ 2 ^ 3 2"), Ok(512.0));
+        assert_eq!(evaluate_prefix("~ - 1 3"), Ok(2.0));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(evaluate_prefix(" "), Err(ExpressionError::Empty));
+        assert_eq!(
+            evaluate_prefix("+ 1"),
+            Err(ExpressionError::MissingOperand { position: 0 })
+        );
+        assert_eq!(
+            evaluate_prefix("+ 1 2 3"),
+            Err(ExpressionError::ExtraOperand { position: 6 })
+        );
+        assert_eq!(
+            evaluate_prefix("[+ 1 2]"),
+            Err(ExpressionError::UnexpectedToken { position: 6 })
+        );
+        assert_eq!(
+            evaluate_prefix("% 3 0"),
+            Err(ExpressionError::DivisionByZero { position: 0 })
+        );
+    }
+}
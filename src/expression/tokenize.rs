@@ -0,0 +1,132 @@
+use super::{Bracket, ExpressionError, Operator, Token, TokenKind};
+
+/// Splits an expression into tokens, skipping whitespace.
+///
+/// The tokenizer does not know about context, so `-` is always
+/// [`Operator::Subtract`]; the infix parser turns it into a negation where an
+/// operand is expected. `~` always stands for [`Operator::Negate`].
+///
+/// # Errors
+///
+/// - [`ExpressionError::UnexpectedCharacter`] for characters outside of any token.
+/// - [`ExpressionError::InvalidNumber`] for malformed numbers such as `1.2.3`.
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::expression::{tokenize, Operator, TokenKind};
+///
+/// let tokens = tokenize("12 + 3.5").unwrap();
+/// assert_eq!(tokens[0].kind, TokenKind::Number(12.0));
+/// assert_eq!(tokens[1].kind, TokenKind::Operator(Operator::Add));
+/// assert_eq!(tokens[2].position, 5);
+/// ```
+pub fn tokenize(input: &str) -> Result<Vec<Token>, ExpressionError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+
+    while let Some((position, character)) = chars.next() {
+        let kind = match character {
+            c if c.is_whitespace() => continue,
+            c if c.is_ascii_digit() || c == '.' => {
+                let mut end = position + c.len_utf8();
+                while let Some(&(next_position, next)) = chars.peek() {
+                    if !(next.is_ascii_digit() || next == '.') {
+                        break;
+                    }
+                    end = next_position + next.len_utf8();
+                    chars.next();
+                }
+                let value = input[position..end]
+                    .parse()
+                    .map_err(|_| ExpressionError::InvalidNumber { position })?;
+                TokenKind::Number(value)
+            }
+            '+' => TokenKind::Operator(Operator::Add),
+            '-' => TokenKind::Operator(Operator::Subtract),
+            '*' => TokenKind::Operator(Operator::Multiply),
+            '/' => TokenKind::Operator(Operator::Divide),
+            '%' => TokenKind::Operator(Operator::Remainder),
+            '^' => TokenKind::Operator(Operator::Power),
+            '~' => TokenKind::Operator(Operator::Negate),
+            character => {
+                Bracket::token_kind(character).ok_or(ExpressionError::UnexpectedCharacter {
+                    character,
+                    position,
+                })?
+            }
+        };
+        tokens.push(Token { kind, position });
+    }
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(input: &str) -> Vec<TokenKind> {
+        tokenize(input)
+            .unwrap()
+            .into_iter()
+            .map(|token| token.kind)
+            .collect()
+    }
+
+    #[test]
+    fn test_tokenize() {
+        assert_eq!(
+            kinds("(1.5+2)*{3}-[.5]"),
+            vec![
+                TokenKind::Open(Bracket::Round),
+                TokenKind::Number(1.5),
+                TokenKind::Operator(Operator::Add),
+                TokenKind::Number(2.0),
+                TokenKind::Close(Bracket::Round),
+                TokenKind::Operator(Operator::Multiply),
+                TokenKind::Open(Bracket::Curly),
+                TokenKind::Number(3.0),
+                TokenKind::Close(Bracket::Curly),
+                TokenKind::Operator(Operator::Subtract),
+                TokenKind::Open(Bracket::Square),
+                TokenKind::Number(0.5),
+                TokenKind::Close(Bracket::Square),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_positions() {
+        let positions: Vec<usize> = tokenize("  10 ^  2")
+            .unwrap()
+            .iter()
+            .map(|token| token.position)
+            .collect();
+        assert_eq!(positions, vec![2, 5, 8]);
+    }
+
+    #[test]
+    fn test_empty_input() {
+        assert!(tokenize("").unwrap().is_empty());
+        assert!(tokenize(" \t\n").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            tokenize("1 + x"),
+            Err(ExpressionError::UnexpectedCharacter {
+                character: 'x',
+                position: 4
+            })
+        );
+        assert_eq!(
+            tokenize("2 * 1.2.3"),
+            Err(ExpressionError::InvalidNumber { position: 4 })
+        );
+        assert_eq!(
+            tokenize("."),
+            Err(ExpressionError::InvalidNumber { position: 0 })
+        );
+    }
+}
@@ -0,0 +1,333 @@
+//! Hazard pointers for the lock-free structures of the crate.
+//!
+//! A thread announces the node it is about to dereference by storing its
+//! address in a hazard pointer. Nodes removed from a structure are *retired*
+//! instead of freed, and a retired node is only deallocated once no hazard
+//! pointer holds its address. This also rules out the ABA problem, since an
+//! address cannot be reused while a thread still compares against it.
+//!
+//! Every structure owns its own [`Domain`], so all retired nodes are freed at
+//! the latest when the structure itself is dropped.
+
+use std::ptr;
+use std::sync::atomic::{fence, AtomicBool, AtomicPtr, AtomicUsize, Ordering};
+
+/// Amount of retired nodes that triggers a reclamation pass.
+const SCAN_THRESHOLD: usize = 64;
+
+/// A slot a thread uses to announce the node it is reading.
+///
+/// Records are never freed while the domain is alive; a released record is
+/// reused by the next thread asking for one.
+struct HazardRecord {
+    active: AtomicBool,
+    pointer: AtomicPtr<()>,
+    next: *mut HazardRecord,
+}
+
+/// A node waiting to be freed.
+struct Retired {
+    pointer: *mut (),
+    deleter: unsafe fn(*mut ()),
+    next: *mut Retired,
+}
+
+/// The set of hazard pointers and retired nodes of one data structure.
+pub(crate) struct Domain {
+    hazards: AtomicPtr<HazardRecord>,
+    retired: AtomicPtr<Retired>,
+    retired_count: AtomicUsize,
+}
+
+/// A hazard pointer borrowed from a [`Domain`]. It is released when dropped.
+pub(crate) struct HazardPointer<'a> {
+    record: &'a HazardRecord,
+}
+
+impl Domain {
+    /// Creates a new domain without hazard pointers nor retired nodes.
+    pub(crate) fn new() -> Domain {
+        Domain {
+            hazards: AtomicPtr::new(ptr::null_mut()),
+            retired: AtomicPtr::new(ptr::null_mut()),
+            retired_count: AtomicUsize::new(0),
+        }
+    }
+
+    /// Borrows a hazard pointer, reusing a released record when possible.
+    pub(crate) fn hazard_pointer(&self) -> HazardPointer<'_> {
+        let mut cursor = self.hazards.load(Ordering::Acquire);
+        while !cursor.is_null() {
+            let record = unsafe { &*cursor };
+            if !record.active.load(Ordering::Relaxed)
+                && record
+                    .active
+                    .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+                    .is_ok()
+            {
+                return HazardPointer { record };
+            }
+            cursor = record.next;
+        }
+
+        // Every record is in use, publish a new one
+        let record = Box::into_raw(Box::new(HazardRecord {
+            active: AtomicBool::new(true),
+            pointer: AtomicPtr::new(ptr::null_mut()),
+            next: ptr::null_mut(),
+        }));
+        let mut head = self.hazards.load(Ordering::Relaxed);
+        loop {
+            unsafe { (*record).next = head };
+            match self.hazards.compare_exchange_weak(
+                head,
+                record,
+                Ordering::Release,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => break,
+                Err(current) => head = current,
+            }
+        }
+        HazardPointer {
+            record: unsafe { &*record },
+        }
+    }
+
+    /// Hands a node over to the domain, which frees it once it is not protected.
+    ///
+    /// # Safety
+    ///
+    /// `pointer` must come from `Box::into_raw`, must already be unreachable
+    /// from the data structure, and must not be retired twice.
+    pub(crate) unsafe fn retire<T>(&self, pointer: *mut T) {
+        unsafe fn drop_box<T>(pointer: *mut ()) {
+            drop(Box::from_raw(pointer as *mut T));
+        }
+
+        let entry = Box::into_raw(Box::new(Retired {
+            pointer: pointer as *mut (),
+            deleter: drop_box::<T>,
+            next: ptr::null_mut(),
+        }));
+        // Counted before it is published, so a concurrent pass freeing the
+        // entry right away never makes the counter go below zero
+        let count = self.retired_count.fetch_add(1, Ordering::Relaxed) + 1;
+        self.push_retired(entry);
+
+        if count >= SCAN_THRESHOLD {
+            self.reclaim();
+        }
+    }
+
+    fn push_retired(&self, entry: *mut Retired) {
+        let mut head = self.retired.load(Ordering::Relaxed);
+        loop {
+            unsafe { (*entry).next = head };
+            match self.retired.compare_exchange_weak(
+                head,
+                entry,
+                Ordering::Release,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return,
+                Err(current) => head = current,
+            }
+        }
+    }
+
+    /// Frees every retired node that no hazard pointer protects.
+    ///
+    /// The whole retired list is taken at once, so concurrent passes never
+    /// look at the same entry. Entries still protected are pushed back.
+    fn reclaim(&self) {
+        // The list is taken before the hazard pointers are read: a node
+        // retired after the scan started stays for the next pass, instead of
+        // being checked against hazard pointers read before it was unlinked.
+        let mut cursor = self.retired.swap(ptr::null_mut(), Ordering::Acquire);
+
+        // Pairs with the fence of `HazardPointer::protect`: a thread either
+        // sees the node unlinked, or its hazard pointer is visible here.
+        fence(Ordering::SeqCst);
+
+        let mut protected = Vec::new();
+        let mut hazard = self.hazards.load(Ordering::Acquire);
+        while !hazard.is_null() {
+            let record = unsafe { &*hazard };
+            let pointer = record.pointer.load(Ordering::SeqCst);
+            if !pointer.is_null() {
+                protected.push(pointer);
+            }
+            hazard = record.next;
+        }
+
+        while !cursor.is_null() {
+            let entry = cursor;
+            unsafe {
+                cursor = (*entry).next;
+                if protected.contains(&(*entry).pointer) {
+                    self.push_retired(entry);
+                } else {
+                    let entry = Box::from_raw(entry);
+                    (entry.deleter)(entry.pointer);
+                    self.retired_count.fetch_sub(1, Ordering::Relaxed);
+                }
+            }
+        }
+    }
+}
+
+impl Drop for Domain {
+    fn drop(&mut self) {
+        // No thread can hold a hazard pointer anymore, free everything
+        let mut cursor = *self.retired.get_mut();
+        while !cursor.is_null() {
+            unsafe {
+                let entry = Box::from_raw(cursor);
+                cursor = entry.next;
+                (entry.deleter)(entry.pointer);
+            }
+        }
+
+        let mut cursor = *self.hazards.get_mut();
+        while !cursor.is_null() {
+            unsafe {
+                let record = Box::from_raw(cursor);
+                cursor = record.next;
+            }
+        }
+    }
+}
+
+// Raw pointers in the lists are only followed through atomics or in `drop`.
+unsafe impl Send for Domain {}
+unsafe impl Sync for Domain {}
+
+impl HazardPointer<'_> {
+    /// Loads `source` and protects the loaded node from being freed.
+    ///
+    /// The load is repeated until the announced pointer is still the one in
+    /// `source`, otherwise the node could have been retired in between.
+    pub(crate) fn protect<T>(&self, source: &AtomicPtr<T>) -> *mut T {
+        let mut pointer = source.load(Ordering::Relaxed);
+        loop {
+            self.record
+                .pointer
+                .store(pointer as *mut (), Ordering::SeqCst);
+            fence(Ordering::SeqCst);
+
+            let current = source.load(Ordering::Acquire);
+            if current == pointer {
+                return pointer;
+            }
+            pointer = current;
+        }
+    }
+
+    /// Stops protecting the current node.
+    pub(crate) fn reset(&self) {
+        self.record.pointer.store(ptr::null_mut(), Ordering::Release);
+    }
+}
+
+impl Drop for HazardPointer<'_> {
+    fn drop(&mut self) {
+        self.reset();
+        self.record.active.store(false, Ordering::Release);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::Arc;
+
+    struct DropCounter(Arc<AtomicUsize>);
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    #[test]
+    fn test_hazard_pointers_are_reused() {
+        let domain = Domain::new();
+        let first = domain.hazard_pointer().record as *const HazardRecord;
+        let second = domain.hazard_pointer().record as *const HazardRecord;
+        assert_eq!(first, second);
+
+        let a = domain.hazard_pointer();
+        let b = domain.hazard_pointer();
+        assert!(!ptr::eq(a.record, b.record));
+    }
+
+    #[test]
+    fn test_protected_node_is_not_freed() {
+        let protected_drops = Arc::new(AtomicUsize::new(0));
+        let other_drops = Arc::new(AtomicUsize::new(0));
+        let domain = Domain::new();
+
+        let node = Box::into_raw(Box::new(DropCounter(protected_drops.clone())));
+        let source = AtomicPtr::new(node);
+        let hazard = domain.hazard_pointer();
+        assert_eq!(hazard.protect(&source), node);
+
+        source.store(ptr::null_mut(), Ordering::SeqCst);
+        unsafe { domain.retire(node) };
+        for _ in 0..SCAN_THRESHOLD {
+            let other = Box::into_raw(Box::new(DropCounter(other_drops.clone())));
+            unsafe { domain.retire(other) };
+        }
+        // A reclamation pass ran, but skipped the protected node
+        assert!(other_drops.load(Ordering::Relaxed) > 0);
+        assert_eq!(protected_drops.load(Ordering::Relaxed), 0);
+
+        drop(hazard);
+        drop(domain);
+        assert_eq!(protected_drops.load(Ordering::Relaxed), 1);
+        assert_eq!(other_drops.load(Ordering::Relaxed), SCAN_THRESHOLD);
+    }
+
+    #[test]
+    fn test_nodes_retired_during_a_pass_stay_protected() {
+        // Small enough for Miri, which reports any use after free
+        let rounds = if cfg!(miri) { 200 } else { 200_000 };
+        let domain = Domain::new();
+        // Idle records make every pass scan a long list of hazard pointers
+        let idle: Vec<_> = (0..4 * SCAN_THRESHOLD)
+            .map(|_| domain.hazard_pointer())
+            .collect();
+        let done = AtomicBool::new(false);
+        let freed = Arc::new(AtomicUsize::new(0));
+
+        std::thread::scope(|scope| {
+            // Keeps reclamation passes running concurrently with the reader
+            scope.spawn(|| {
+                while !done.load(Ordering::Relaxed) {
+                    let node = Box::into_raw(Box::new(DropCounter(freed.clone())));
+                    unsafe { domain.retire(node) };
+                }
+            });
+
+            let hazard = domain.hazard_pointer();
+            let source = AtomicPtr::new(ptr::null_mut());
+            for round in 0..rounds {
+                let node = Box::into_raw(Box::new(round));
+                source.store(node, Ordering::SeqCst);
+                assert_eq!(hazard.protect(&source), node);
+
+                // Unlinked and retired while protected, possibly in the middle
+                // of a pass that scanned the hazard pointers before `protect`
+                source.store(ptr::null_mut(), Ordering::SeqCst);
+                unsafe {
+                    domain.retire(node);
+                    assert_eq!(*node, round);
+                }
+            }
+            done.store(true, Ordering::Relaxed);
+        });
+        drop(idle);
+    }
+}
@@ -1,3 +1,17 @@
 pub mod linked_list; 
 pub mod doubly_linked_list;
+pub mod expression;
 pub mod binary_tree;
+pub mod avl_tree;
+pub mod concurrent_queue;
+pub mod concurrent_stack;
+pub mod persistent_list;
+pub mod priority_queue;
+pub mod queue;
+pub mod red_black_tree;
+pub mod shared_linked_list;
+pub mod splay_tree;
+pub mod stack;
+
+mod hazard_pointer;
+mod search_tree;
@@ -1,43 +1,188 @@
-use std::fmt::Display;
+use std::fmt::{self, Display};
+use std::io;
 
-pub struct ListNode<T: Display> {
+pub struct ListNode<T> {
     pub data: T,
     pub next: Option<Box<ListNode<T>>>,
 }
 
-impl<T: Display> ListNode<T> {
+impl<T> ListNode<T> {
     pub fn new(value: T) -> ListNode<T> {
         ListNode {
             data: value,
             next: None,
         }
     }
+}
 
+impl<T: Display> ListNode<T> {
     fn _to_string_debug(&self) -> String {
         format!("[{:p}]", self)
     }
 
+    #[allow(clippy::inherent_to_string)]
     fn to_string(&self) -> String {
         format!("[{}]", self.data)
     }
 }
 
-pub fn print<T: Display>(head: &ListNode<T>) {
-    let mut cur = Some(head);
+/// An owning handle to a chain of nodes, which drops it iteratively.
+///
+/// Dropping a chain of [`ListNode`]s the default way recurses once per node,
+/// which overflows the stack on long chains. Keeping the head in a `List`
+/// unlinks the nodes one by one instead.
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::linked_list::{insert_at_beginning, length, List};
+///
+/// let mut list = List::new();
+/// for i in 0..100_000 {
+///     list.head = Some(insert_at_beginning(list.head.take(), i));
+/// }
+/// assert_eq!(list.head.as_deref().map(length), Some(100_000));
+/// ```
+pub struct List<T> {
+    pub head: Option<Box<ListNode<T>>>,
+}
 
-    while let Some(node) = cur {
-        print!("{} -> ", node.to_string());
-        cur = node.next.as_deref();
+impl<T> List<T> {
+    pub fn new() -> List<T> {
+        List { head: None }
+    }
+}
+
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<Box<ListNode<T>>> for List<T> {
+    fn from(head: Box<ListNode<T>>) -> Self {
+        List { head: Some(head) }
+    }
+}
+
+impl<T> Drop for List<T> {
+    fn drop(&mut self) {
+        let mut next = self.head.take();
+        while let Some(mut node) = next {
+            next = node.next.take();
+        }
+    }
+}
+
+/// Separators used when writing a chain of nodes.
+///
+/// Every node is written as `[data]` followed by `separator`, and the chain
+/// is closed by `end`. The default produces `[1] -> [2] -> None`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ListFormat<'a> {
+    pub separator: &'a str,
+    pub end: &'a str,
+}
+
+impl Default for ListFormat<'_> {
+    fn default() -> Self {
+        ListFormat {
+            separator: " -> ",
+            end: "None",
+        }
+    }
+}
+
+/// A displayable view over the chain of nodes starting at a head.
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::linked_list::{insert_at_beginning, Chain, ListFormat};
+///
+/// let mut head = insert_at_beginning(None, 2);
+/// head = insert_at_beginning(Some(head), 1);
+///
+/// assert_eq!(Chain::new(&head).to_string(), "[1] -> [2] -> None");
+///
+/// let format = ListFormat { separator: ", ", end: "" };
+/// assert_eq!(Chain::with_format(&head, format).to_string(), "[1], [2], ");
+/// assert_eq!(format!("{:?}", Chain::new(&head)), "[1, 2]");
+/// ```
+pub struct Chain<'a, T> {
+    head: &'a ListNode<T>,
+    format: ListFormat<'a>,
+}
+
+impl<'a, T> Chain<'a, T> {
+    pub fn new(head: &'a ListNode<T>) -> Chain<'a, T> {
+        Chain::with_format(head, ListFormat::default())
+    }
+
+    pub fn with_format(head: &'a ListNode<T>, format: ListFormat<'a>) -> Chain<'a, T> {
+        Chain { head, format }
+    }
+
+    fn nodes(&self) -> impl Iterator<Item = &'a ListNode<T>> {
+        std::iter::successors(Some(self.head), |node| node.next.as_deref())
+    }
+}
+
+impl<T: Display> fmt::Display for Chain<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for node in self.nodes() {
+            write!(f, "{}{}", node.to_string(), self.format.separator)?;
+        }
+        write!(f, "{}", self.format.end)
     }
-    println!("None");
+}
+
+impl<T: fmt::Debug> fmt::Debug for Chain<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.nodes().map(|node| &node.data))
+            .finish()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ListNode<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&Chain::new(self), f)
+    }
+}
+
+/// Writes the chain starting at `head` to `out`, followed by a newline.
+pub fn write_to<T: Display, W: io::Write>(head: &ListNode<T>, out: &mut W) -> io::Result<()> {
+    write_with_format(head, out, ListFormat::default())
+}
+
+/// Writes the chain starting at `head` to `out` using `format`, followed by a newline.
+pub fn write_with_format<T: Display, W: io::Write>(
+    head: &ListNode<T>,
+    out: &mut W,
+    format: ListFormat<'_>,
+) -> io::Result<()> {
+    writeln!(out, "{}", Chain::with_format(head, format))
+}
+
+/// Writes the chain starting at `head` to `out`, followed by its length.
+pub fn write_summary_to<T: Display, W: io::Write>(
+    head: &ListNode<T>,
+    out: &mut W,
+) -> io::Result<()> {
+    write_to(head, out)?;
+    writeln!(out, "Linked list length {len}", len = length(head))
+}
+
+pub fn print<T: Display>(head: &ListNode<T>) {
+    write_to(head, &mut io::stdout()).expect("failed printing to stdout");
 }
 
 pub fn print_summary<T: Display>(head: &ListNode<T>) {
-    print(&head);
-    println!("Linked list length {len}", len = length(&head));
+    write_summary_to(head, &mut io::stdout()).expect("failed printing to stdout");
 }
 
-pub fn length<T: Display>(head: &ListNode<T>) -> i32 {
+pub fn length<T>(head: &ListNode<T>) -> usize {
     let mut cur = Some(head);
     let mut count = 0;
     while let Some(node) = cur {
@@ -48,22 +193,19 @@ pub fn length<T: Display>(head: &ListNode<T>) -> i32 {
 }
 
 // Receives an option because the List could be empty
-pub fn insert_at_beginning<T: Display>(
-    head: Option<Box<ListNode<T>>>,
-    data: T,
-) -> Box<ListNode<T>> {
+pub fn insert_at_beginning<T>(head: Option<Box<ListNode<T>>>, data: T) -> Box<ListNode<T>> {
     let mut new_node = Box::new(ListNode::new(data));
 
     match head {
         Some(node) => {
             new_node.next = Some(node);
-            return new_node;
+            new_node
         }
         None => new_node,
     }
 }
 
-pub fn insert_at_end<T: Display>(mut head: Option<Box<ListNode<T>>>, data: T) -> Box<ListNode<T>> {
+pub fn insert_at_end<T>(mut head: Option<Box<ListNode<T>>>, data: T) -> Box<ListNode<T>> {
     let new_node = Box::new(ListNode::new(data));
 
     match head {
@@ -73,13 +215,13 @@ pub fn insert_at_end<T: Display>(mut head: Option<Box<ListNode<T>>>, data: T) ->
                 cur = temp;
             }
             cur.next = Some(new_node);
-            return head.unwrap();
+            head.unwrap()
         }
         None => new_node,
     }
 }
 
-pub fn insert_at_position<T: Display>(
+pub fn insert_at_position<T>(
     mut head: Option<Box<ListNode<T>>>,
     data: T,
     pos: usize,
@@ -101,27 +243,27 @@ pub fn insert_at_position<T: Display>(
         }
 
         counter += 1;
-        cur = &mut (*node).next;
+        cur = &mut node.next;
     }
 
-    return head.expect("head node must exist to insert at position");
+    head.expect("head node must exist to insert at position")
 }
 
 // Returns the new head
-pub fn delete_first<T: Display>(head: Option<Box<ListNode<T>>>) -> Option<Box<ListNode<T>>> {
+pub fn delete_first<T>(head: Option<Box<ListNode<T>>>) -> Option<Box<ListNode<T>>> {
     match head {
-        Some(first_node) => return first_node.next,
+        Some(first_node) => first_node.next,
         None => None,
     }
 }
 
-pub fn delete_last<T: Display>(mut head: Option<Box<ListNode<T>>>) -> Option<Box<ListNode<T>>> {
+pub fn delete_last<T>(mut head: Option<Box<ListNode<T>>>) -> Option<Box<ListNode<T>>> {
     match head {
         Some(ref mut node) => {
             let mut cur = node;
 
             // Iterate until we find the second to last
-            while let Some(ref mut current) = (*cur).next {
+            while let Some(ref mut current) = cur.next {
                 let next = &current.next;
                 // 1 -> 2 -> 3 -> None
                 //      ^    ^
@@ -129,20 +271,20 @@ pub fn delete_last<T: Display>(mut head: Option<Box<ListNode<T>>>) -> Option<Box
                 //          next
                 if let Some(next) = next {
                     if next.next.is_none() {
-                        (*current).next = None;
+                        current.next = None;
                         return head;
                     }
                 }
                 cur = current;
             }
             // next node does not exist, so we delete the head.
-            return None;
+            None
         }
         None => None,
     }
 }
 
-pub fn delete_at_position<T: Display>(
+pub fn delete_at_position<T>(
     mut head: Option<Box<ListNode<T>>>,
     position: usize,
 ) -> Option<Box<ListNode<T>>> {
@@ -156,7 +298,7 @@ pub fn delete_at_position<T: Display>(
             let mut counter = 1;
 
             // Iterate until we find the position
-            while let Some(ref mut current) = (*cur).next {
+            while let Some(ref mut current) = cur.next {
                 // i = 2
                 // 1 -> 2 -> 3 -> 4 -> None
                 //      ^    ^  ^
@@ -165,7 +307,7 @@ pub fn delete_at_position<T: Display>(
                 //             next
                 if counter == position - 1 {
                     if let Some(ref mut next) = current.next {
-                        (*current).next = (*next).next.take();
+                        current.next = next.next.take();
                     }
                     return head;
                 }
@@ -176,7 +318,7 @@ pub fn delete_at_position<T: Display>(
                 counter += 1;
             }
 
-            return head;
+            head
         }
         None => None,
     }
@@ -202,6 +344,54 @@ mod tests {
         print_summary(&head);
     }
 
+    #[test]
+    fn test_write_to() {
+        let mut head = insert_at_beginning::<i32>(None, 3);
+        head = insert_at_beginning(Some(head), 2);
+        head = insert_at_beginning(Some(head), 1);
+
+        let mut out = Vec::new();
+        write_to(&head, &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "[1] -> [2] -> [3] -> None\n"
+        );
+
+        let mut out = Vec::new();
+        write_summary_to(&head, &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "[1] -> [2] -> [3] -> None\nLinked list length 3\n"
+        );
+    }
+
+    #[test]
+    fn test_write_with_format() {
+        let mut head = insert_at_beginning::<i32>(None, 2);
+        head = insert_at_beginning(Some(head), 1);
+
+        let format = ListFormat {
+            separator: " | ",
+            end: "END",
+        };
+        let mut out = Vec::new();
+        write_with_format(&head, &mut out, format).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "[1] | [2] | END\n");
+    }
+
+    #[test]
+    fn test_chain_display_and_debug() {
+        let single = insert_at_beginning::<i32>(None, 1);
+        assert_eq!(Chain::new(&single).to_string(), "[1] -> None");
+        assert_eq!(format!("{:?}", single), "[1]");
+
+        let mut head = insert_at_beginning(None, String::from("b"));
+        head = insert_at_beginning(Some(head), String::from("a"));
+        assert_eq!(Chain::new(&head).to_string(), "[a] -> [b] -> None");
+        assert_eq!(format!("{:?}", Chain::new(&head)), r#"["a", "b"]"#);
+        assert_eq!(format!("{:?}", head), r#"["a", "b"]"#);
+    }
+
     #[test]
     fn test_length() {
         let mut head = insert_at_beginning::<i32>(None, 1);
@@ -211,6 +401,16 @@ mod tests {
         assert_eq!(length(&head), 3);
     }
 
+    #[test]
+    fn test_drop_long_list() {
+        let mut head = insert_at_beginning::<usize>(None, 0);
+        for i in 1..3_000_000 {
+            head = insert_at_beginning(Some(head), i);
+        }
+        assert_eq!(length(&head), 3_000_000);
+        drop(List::from(head));
+    }
+
     #[test]
     fn test_linked_list_to_string_debug() {
         let head = insert_at_beginning::<i32>(None, 1);
@@ -222,15 +422,15 @@ mod tests {
     #[test]
     fn test_insert_at_beggining() {
         let head = insert_at_beginning::<i32>(None, 1);
-        assert_eq!((*head).data, 1);
-        assert!((*head).next.is_none());
+        assert_eq!(head.data, 1);
+        assert!(head.next.is_none());
     }
 
     #[test]
     fn test_insert_at_incorrect() {
         let head = insert_at_beginning::<i32>(None, 1);
-        assert_ne!((*head).data, 10);
-        assert!(!(*head).next.is_some());
+        assert_ne!(head.data, 10);
+        assert!(head.next.is_none());
     }
 
     #[test]
@@ -238,9 +438,9 @@ mod tests {
         let mut head = insert_at_beginning::<i32>(None, 2);
         head = insert_at_beginning(Some(head), 1);
         // Data is correct
-        assert_eq!((*head).data, 1);
+        assert_eq!(head.data, 1);
         // Next node exists
-        assert!((*head).next.is_some());
+        assert!(head.next.is_some());
         // Next node's data is correct
         let next_node = head.next.unwrap();
         assert_eq!(next_node.data, 2);
@@ -251,19 +451,19 @@ mod tests {
     #[test]
     fn test_insert_at_end() {
         let mut head = insert_at_end::<i32>(None, 1);
-        assert_eq!((*head).data, 1);
-        assert!((*head).next.is_none());
+        assert_eq!(head.data, 1);
+        assert!(head.next.is_none());
         head = insert_at_end(Some(head), 3);
         head = insert_at_end(Some(head), 2);
-        assert_eq!((*head).data, 1);
-        assert!((*head).next.is_some());
+        assert_eq!(head.data, 1);
+        assert!(head.next.is_some());
     }
 
     #[test]
     fn test_insert_at_position() {
         let mut head = insert_at_position::<i32>(None, 1, 0);
-        assert_eq!((*head).data, 1);
-        assert!((*head).next.is_none());
+        assert_eq!(head.data, 1);
+        assert!(head.next.is_none());
 
         let list_length = 29;
 
@@ -280,13 +480,13 @@ mod tests {
 
         while counter < position {
             if let Some(ref node) = cur.next {
-                cur = &node;
+                cur = node;
                 counter += 1;
             }
         }
         print_summary(&head);
-        assert_eq!(length(&head), list_length);
-        assert_eq!((*cur).data, value);
+        assert_eq!(length(&head), list_length as usize);
+        assert_eq!(cur.data, value);
     }
 
     #[test]
@@ -339,12 +539,12 @@ mod tests {
 
         let mut cur = &head;
 
-        while let Some(ref node) = (*cur).next {
+        while let Some(ref node) = cur.next {
             cur = node;
         }
 
-        assert_eq!(list_length, length(&head));
-        assert_eq!(1, (*cur).data);
+        assert_eq!(list_length as usize, length(&head));
+        assert_eq!(1, cur.data);
     }
 
     #[test]
@@ -374,11 +574,11 @@ mod tests {
             let mut original_cur = &head;
             while counter < delete_position {
                 if let Some(ref node) = original_cur.next {
-                    original_cur = &node;
+                    original_cur = node;
                     counter += 1;
                 }
             }
-            original_data = (*original_cur).data;
+            original_data = original_cur.data;
         }
 
         head = delete_at_position(Some(head), delete_position).unwrap();
@@ -388,11 +588,11 @@ mod tests {
 
         while counter < delete_position {
             if let Some(ref node) = cur.next {
-                cur = &node;
+                cur = node;
                 counter += 1;
             }
         }
-        assert_ne!(original_data, (*cur).data);
+        assert_ne!(original_data, cur.data);
     }
 
     #[test]
@@ -417,7 +617,7 @@ mod tests {
         head = delete_at_position(Some(head), (list_length + 1000) as usize).unwrap();
 
         // Should have not deleted something
-        assert_eq!(length(&head), list_length);
+        assert_eq!(length(&head), list_length as usize);
     }
 
     #[test]
@@ -0,0 +1,345 @@
+use std::fmt;
+use std::rc::Rc;
+use std::sync::Arc;
+
+// Both lists only differ in the reference-counted pointer holding the nodes,
+// so they are generated from the same definition.
+macro_rules! persistent_list {
+    ($(#[$attr:meta])* $list:ident, $node:ident, $iter:ident, $ptr:ident) => {
+        $(#[$attr])*
+        pub struct $list<T> {
+            head: Option<$ptr<$node<T>>>,
+            length: usize,
+        }
+
+        struct $node<T> {
+            data: T,
+            next: Option<$ptr<$node<T>>>,
+        }
+
+        /// Borrowing iterator over the elements of the list, from head to end.
+        pub struct $iter<'a, T> {
+            next: Option<&'a $node<T>>,
+            remaining: usize,
+        }
+
+        impl<T> $list<T> {
+            /// Creates a new empty list.
+            pub fn new() -> $list<T> {
+                $list {
+                    head: None,
+                    length: 0,
+                }
+            }
+
+            /// Returns a new list with `data` in front of `self`.
+            ///
+            /// `self` is left untouched and its nodes are shared with the new list.
+            ///
+            /// # Complexity
+            /// - `O(1)`
+            pub fn cons(&self, data: T) -> $list<T> {
+                $list {
+                    head: Some($ptr::new($node {
+                        data,
+                        next: self.head.clone(),
+                    })),
+                    length: self.length + 1,
+                }
+            }
+
+            /// Retrieves a reference to the first element, if it exists.
+            pub fn head(&self) -> Option<&T> {
+                self.head.as_ref().map(|node| &node.data)
+            }
+
+            /// Returns the list without its first element, sharing its nodes with `self`.
+            ///
+            /// The tail of an empty list is the empty list.
+            ///
+            /// # Complexity
+            /// - `O(1)`
+            pub fn tail(&self) -> $list<T> {
+                match &self.head {
+                    Some(node) => $list {
+                        head: node.next.clone(),
+                        length: self.length - 1,
+                    },
+                    None => $list::new(),
+                }
+            }
+
+            /// Gets the amount of items in the list.
+            pub fn len(&self) -> usize {
+                self.length
+            }
+
+            /// Checks if the list is empty.
+            pub fn is_empty(&self) -> bool {
+                self.head.is_none()
+            }
+
+            /// Returns an iterator over the elements, from head to end.
+            pub fn iter(&self) -> $iter<'_, T> {
+                $iter {
+                    next: self.head.as_deref(),
+                    remaining: self.length,
+                }
+            }
+        }
+
+        impl<T: Clone> $list<T> {
+            /// Returns a new list with the elements of `self` in reverse order.
+            ///
+            /// # Complexity
+            /// - `O(n)`, every node is copied
+            pub fn reverse(&self) -> $list<T> {
+                self.iter()
+                    .fold($list::new(), |list, data| list.cons(data.clone()))
+            }
+        }
+
+        impl<T> Clone for $list<T> {
+            /// Cheap copy: only the head pointer is cloned, all nodes are shared.
+            fn clone(&self) -> Self {
+                $list {
+                    head: self.head.clone(),
+                    length: self.length,
+                }
+            }
+        }
+
+        impl<T> Default for $list<T> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<T> Drop for $list<T> {
+            fn drop(&mut self) {
+                // Unlink the nodes one by one so the drop does not recurse on
+                // long lists, stopping at the first node shared with another list.
+                // `into_inner` hands the node to exactly one of several lists
+                // dropped concurrently, so none of them falls back to recursion.
+                let mut next = self.head.take();
+                while let Some(mut node) = next.and_then($ptr::into_inner) {
+                    next = node.next.take();
+                }
+            }
+        }
+
+        impl<T> FromIterator<T> for $list<T> {
+            /// Builds a list holding the items in iteration order.
+            fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+                let items: Vec<T> = iter.into_iter().collect();
+                items
+                    .into_iter()
+                    .rev()
+                    .fold($list::new(), |list, data| list.cons(data))
+            }
+        }
+
+        impl<'a, T> IntoIterator for &'a $list<T> {
+            type Item = &'a T;
+            type IntoIter = $iter<'a, T>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        impl<'a, T> Iterator for $iter<'a, T> {
+            type Item = &'a T;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.next.map(|node| {
+                    self.next = node.next.as_deref();
+                    self.remaining -= 1;
+                    &node.data
+                })
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.remaining, Some(self.remaining))
+            }
+        }
+
+        impl<T> ExactSizeIterator for $iter<'_, T> {}
+
+        impl<T: PartialEq> PartialEq for $list<T> {
+            fn eq(&self, other: &Self) -> bool {
+                self.length == other.length && self.iter().eq(other.iter())
+            }
+        }
+
+        impl<T: Eq> Eq for $list<T> {}
+
+        impl<T: fmt::Debug> fmt::Debug for $list<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_list().entries(self.iter()).finish()
+            }
+        }
+    };
+}
+
+persistent_list!(
+    /// An immutable singly linked list whose versions share structure.
+    ///
+    /// Prepending with [`cons`](PersistentList::cons) returns a new list that
+    /// reuses every node of the old one, so older versions stay valid and
+    /// cloning is `O(1)`. Nodes are reference counted with `Rc`; use
+    /// [`SyncPersistentList`] to share lists between threads.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::persistent_list::PersistentList;
+    ///
+    /// let empty = PersistentList::new();
+    /// let one = empty.cons(1);
+    /// let two = one.cons(2);
+    ///
+    /// assert_eq!(two.head(), Some(&2));
+    /// assert_eq!(two.tail(), one);
+    /// assert_eq!(two.iter().collect::<Vec<_>>(), vec![&2, &1]);
+    /// assert_eq!(one.len(), 1);
+    /// ```
+    PersistentList,
+    Node,
+    Iter,
+    Rc
+);
+
+persistent_list!(
+    /// The thread-safe counterpart of [`PersistentList`], backed by `Arc`.
+    ///
+    /// It is `Send + Sync` whenever `T` is, so versions can be handed to other
+    /// threads while still sharing their nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::persistent_list::SyncPersistentList;
+    /// use std::thread;
+    ///
+    /// let list: SyncPersistentList<i32> = (1..=3).collect();
+    /// let shared = list.clone();
+    /// let sum = thread::spawn(move || shared.iter().sum::<i32>()).join().unwrap();
+    ///
+    /// assert_eq!(sum, 6);
+    /// assert_eq!(list.head(), Some(&1));
+    /// ```
+    SyncPersistentList,
+    SyncNode,
+    SyncIter,
+    Arc
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn test_empty() {
+        let list: PersistentList<i32> = PersistentList::new();
+        assert!(list.is_empty());
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.head(), None);
+        assert!(list.tail().is_empty());
+        assert_eq!(list.iter().next(), None);
+    }
+
+    #[test]
+    fn test_cons_keeps_old_versions() {
+        let a = PersistentList::new().cons(1);
+        let b = a.cons(2);
+        let c = a.cons(3);
+
+        assert_eq!(a.iter().copied().collect::<Vec<_>>(), vec![1]);
+        assert_eq!(b.iter().copied().collect::<Vec<_>>(), vec![2, 1]);
+        assert_eq!(c.iter().copied().collect::<Vec<_>>(), vec![3, 1]);
+        assert_eq!(b.tail(), c.tail());
+        assert_eq!(b.len(), 2);
+        assert_eq!(b.tail().len(), 1);
+    }
+
+    #[test]
+    fn test_structural_sharing() {
+        let base: PersistentList<i32> = (1..=3).collect();
+        let extended = base.cons(0);
+
+        let base_head = base.head.as_ref().unwrap();
+        let shared = extended.head.as_ref().unwrap().next.as_ref().unwrap();
+        assert!(Rc::ptr_eq(base_head, shared));
+        // base, extended and the clone all point to the same node
+        let copy = base.clone();
+        assert_eq!(Rc::strong_count(base_head), 3);
+        assert_eq!(copy, base);
+    }
+
+    #[test]
+    fn test_reverse() {
+        let list: PersistentList<i32> = (1..=4).collect();
+        let reversed = list.reverse();
+
+        assert_eq!(reversed.iter().copied().collect::<Vec<_>>(), vec![4, 3, 2, 1]);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        assert_eq!(reversed.reverse(), list);
+        assert!(PersistentList::<i32>::new().reverse().is_empty());
+    }
+
+    #[test]
+    fn test_iter_len_and_debug() {
+        let list: PersistentList<&str> = vec!["a", "b"].into_iter().collect();
+        let iter = list.iter();
+        assert_eq!(iter.len(), 2);
+
+        let mut count = 0;
+        for _ in &list {
+            count += 1;
+        }
+        assert_eq!(count, 2);
+        assert_eq!(format!("{:?}", list), r#"["a", "b"]"#);
+    }
+
+    #[test]
+    fn test_drop_long_list() {
+        let list: PersistentList<usize> = (0..2_000_000).collect();
+        let tail = list.tail().tail();
+        drop(list);
+        assert_eq!(tail.len(), 1_999_998);
+        assert_eq!(tail.head(), Some(&2));
+        drop(tail);
+
+        let list: SyncPersistentList<usize> = (0..2_000_000).collect();
+        drop(list);
+    }
+
+    #[test]
+    fn test_sync_list_across_threads() {
+        let base: SyncPersistentList<usize> = (0..100).collect();
+
+        let handles: Vec<_> = (0..8)
+            .map(|i| {
+                let base = base.clone();
+                thread::spawn(move || {
+                    let own = base.cons(i);
+                    assert_eq!(own.tail(), base);
+                    own.iter().sum::<usize>()
+                })
+            })
+            .collect();
+
+        for (i, handle) in handles.into_iter().enumerate() {
+            assert_eq!(handle.join().unwrap(), 4950 + i);
+        }
+        assert_eq!(base.len(), 100);
+    }
+
+    #[test]
+    fn test_sync_list_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<SyncPersistentList<i32>>();
+    }
+}
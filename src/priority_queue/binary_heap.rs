@@ -0,0 +1,542 @@
+use super::{Compare, MaxOrder, MinOrder};
+use std::cmp::Ordering;
+
+/// A priority queue stored as a binary heap in an array.
+///
+/// The item with the highest priority according to the comparator `C` is
+/// always at the top: [`MaxOrder`] (the default) makes it a max-heap,
+/// [`MinOrder`] a min-heap, and any [`Compare`] implementation, closures
+/// included, can be used for custom orders.
+///
+/// Every [`push`](BinaryHeap::push) returns a [`Handle`] to the item, which
+/// stays valid until the item leaves the heap and allows raising its priority
+/// with [`decrease_key`](BinaryHeap::decrease_key) or removing it with
+/// [`remove`](BinaryHeap::remove), as needed by algorithms like Dijkstra's.
+///
+/// # Type Parameters
+/// - `T`: The type of elements stored in the heap.
+/// - `C`: The comparator deciding the priority of the elements.
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::priority_queue::BinaryHeap;
+///
+/// let mut heap = BinaryHeap::new_min();
+/// let far = heap.push((10, 'a'));
+/// heap.push((4, 'b'));
+///
+/// // A shorter path to 'a' was found
+/// heap.decrease_key(far, (1, 'a')).unwrap();
+///
+/// assert_eq!(heap.pop(), Some((1, 'a')));
+/// assert_eq!(heap.pop(), Some((4, 'b')));
+/// assert_eq!(heap.pop(), None);
+/// ```
+#[derive(Clone)]
+pub struct BinaryHeap<T, C = MaxOrder> {
+    entries: Vec<Entry<T>>,
+    slots: Vec<Slot>,
+    free_slots: Vec<usize>,
+    comparator: C,
+}
+
+/// Identifies an item of a [`BinaryHeap`] while it is in the heap.
+///
+/// Once the item is popped or removed the handle is stale, and every method
+/// taking it treats it as invalid, even if the slot was reused.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Handle {
+    slot: usize,
+    generation: usize,
+}
+
+/// An item of the heap, with the slot tracking its position.
+#[derive(Clone)]
+struct Entry<T> {
+    data: T,
+    slot: usize,
+}
+
+/// Where the item of a handle currently is in the heap array.
+#[derive(Clone)]
+struct Slot {
+    generation: usize,
+    position: Option<usize>,
+}
+
+impl<T: Ord> BinaryHeap<T> {
+    /// Creates a new empty max-heap.
+    pub fn new() -> BinaryHeap<T> {
+        BinaryHeap::with_comparator(MaxOrder)
+    }
+}
+
+impl<T: Ord> BinaryHeap<T, MinOrder> {
+    /// Creates a new empty min-heap.
+    pub fn new_min() -> BinaryHeap<T, MinOrder> {
+        BinaryHeap::with_comparator(MinOrder)
+    }
+}
+
+impl<T, C: Compare<T>> BinaryHeap<T, C> {
+    /// Creates a new empty heap ordered by `comparator`.
+    pub fn with_comparator(comparator: C) -> BinaryHeap<T, C> {
+        BinaryHeap {
+            entries: Vec::new(),
+            slots: Vec::new(),
+            free_slots: Vec::new(),
+            comparator,
+        }
+    }
+
+    /// Builds a heap from the items of `items` in place.
+    ///
+    /// Items are sifted down from the last parent to the root, which is
+    /// cheaper than pushing them one by one.
+    ///
+    /// # Complexity
+    /// - `O(n)`
+    pub fn from_vec(items: Vec<T>, comparator: C) -> BinaryHeap<T, C> {
+        let mut heap = BinaryHeap::with_comparator(comparator);
+        heap.entries.reserve(items.len());
+        for data in items {
+            let handle = heap.allocate_slot(heap.entries.len());
+            heap.entries.push(Entry {
+                data,
+                slot: handle.slot,
+            });
+        }
+        heap.heapify();
+        heap
+    }
+
+    /// Gets the amount of items in the heap.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Checks if the heap is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Adds an item to the heap and returns a handle to it.
+    ///
+    /// # Complexity
+    /// - `O(log n)`
+    pub fn push(&mut self, data: T) -> Handle {
+        let position = self.entries.len();
+        let handle = self.allocate_slot(position);
+        self.entries.push(Entry {
+            data,
+            slot: handle.slot,
+        });
+        self.sift_up(position);
+        handle
+    }
+
+    /// Retrieves a reference to the item with the highest priority, if it exists.
+    ///
+    /// # Complexity
+    /// - `O(1)`
+    pub fn peek(&self) -> Option<&T> {
+        self.entries.first().map(|entry| &entry.data)
+    }
+
+    /// Removes the item with the highest priority and returns it, or `None`
+    /// if the heap is empty.
+    ///
+    /// # Complexity
+    /// - `O(log n)`
+    pub fn pop(&mut self) -> Option<T> {
+        if self.entries.is_empty() {
+            None
+        } else {
+            Some(self.remove_at(0))
+        }
+    }
+
+    /// Checks if `handle` still refers to an item of the heap.
+    pub fn contains(&self, handle: Handle) -> bool {
+        self.position(handle).is_some()
+    }
+
+    /// Retrieves a reference to the item of `handle`, if it is still in the heap.
+    pub fn get(&self, handle: Handle) -> Option<&T> {
+        self.position(handle)
+            .map(|position| &self.entries[position].data)
+    }
+
+    /// Replaces the item of `handle` with `data`, which must have the same
+    /// or a higher priority, and moves it up accordingly.
+    ///
+    /// In a min-heap this is the classic decrease-key; in a max-heap it
+    /// increases the key.
+    ///
+    /// # Errors
+    ///
+    /// - `"Invalid handle"`: The item is no longer in the heap.
+    /// - `"Priority would decrease"`: `data` has a lower priority than the
+    ///   current item, which is left untouched.
+    ///
+    /// # Complexity
+    /// - `O(log n)`
+    pub fn decrease_key(&mut self, handle: Handle, data: T) -> Result<(), &str> {
+        let position = self.position(handle).ok_or("Invalid handle")?;
+        let current = &self.entries[position].data;
+        if self.comparator.compare(&data, current) == Ordering::Less {
+            return Err("Priority would decrease");
+        }
+        self.entries[position].data = data;
+        self.sift_up(position);
+        Ok(())
+    }
+
+    /// Removes the item of `handle` and returns it, or `None` if it is no
+    /// longer in the heap.
+    ///
+    /// # Complexity
+    /// - `O(log n)`
+    pub fn remove(&mut self, handle: Handle) -> Option<T> {
+        self.position(handle)
+            .map(|position| self.remove_at(position))
+    }
+
+    /// Moves every item of `other` into the heap.
+    ///
+    /// Handles to the items of `self` stay valid, those of `other` do not.
+    ///
+    /// # Complexity
+    /// - `O(n + m)`, the whole array is heapified again
+    pub fn merge(&mut self, other: BinaryHeap<T, C>) {
+        self.entries.reserve(other.entries.len());
+        for entry in other.entries {
+            let handle = self.allocate_slot(self.entries.len());
+            self.entries.push(Entry {
+                data: entry.data,
+                slot: handle.slot,
+            });
+        }
+        self.heapify();
+    }
+
+    /// Returns the items sorted by priority, the highest one last.
+    ///
+    /// A max-heap gives them in ascending order and a min-heap in
+    /// descending order.
+    ///
+    /// # Complexity
+    /// - `O(n log n)`, heapsort in place
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        for end in (1..self.entries.len()).rev() {
+            self.swap(0, end);
+            self.sift_down(0, end);
+        }
+        self.into_vec()
+    }
+
+    /// Returns the items in heap order, which is not sorted.
+    pub fn into_vec(self) -> Vec<T> {
+        self.entries.into_iter().map(|entry| entry.data).collect()
+    }
+
+    /// Returns an iterator over the items in heap order, which is not sorted.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.entries.iter().map(|entry| &entry.data)
+    }
+
+    /// Removes every item. All handles become stale.
+    pub fn clear(&mut self) {
+        while let Some(entry) = self.entries.pop() {
+            self.free_slot(entry.slot);
+        }
+    }
+
+    /// Gets the current position of the item of `handle`.
+    fn position(&self, handle: Handle) -> Option<usize> {
+        self.slots
+            .get(handle.slot)
+            .filter(|slot| slot.generation == handle.generation)
+            .and_then(|slot| slot.position)
+    }
+
+    fn allocate_slot(&mut self, position: usize) -> Handle {
+        let slot = match self.free_slots.pop() {
+            Some(slot) => slot,
+            None => {
+                self.slots.push(Slot {
+                    generation: 0,
+                    position: None,
+                });
+                self.slots.len() - 1
+            }
+        };
+        self.slots[slot].position = Some(position);
+        Handle {
+            slot,
+            generation: self.slots[slot].generation,
+        }
+    }
+
+    fn free_slot(&mut self, slot: usize) {
+        // Bumping the generation makes every handle to the slot stale
+        self.slots[slot].generation += 1;
+        self.slots[slot].position = None;
+        self.free_slots.push(slot);
+    }
+
+    /// Removes the item at `position` by replacing it with the last one.
+    fn remove_at(&mut self, position: usize) -> T {
+        let last = self.entries.len() - 1;
+        self.swap(position, last);
+        let entry = self.entries.pop().expect("the heap is not empty");
+        self.free_slot(entry.slot);
+        if position < self.entries.len() {
+            // The moved item may belong either above or below
+            self.sift_up(position);
+            self.sift_down(position, self.entries.len());
+        }
+        entry.data
+    }
+
+    fn heapify(&mut self) {
+        let len = self.entries.len();
+        for position in (0..len / 2).rev() {
+            self.sift_down(position, len);
+        }
+    }
+
+    /// Checks if the item at `a` has a higher priority than the one at `b`.
+    fn outranks(&self, a: usize, b: usize) -> bool {
+        self.comparator
+            .compare(&self.entries[a].data, &self.entries[b].data)
+            == Ordering::Greater
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.entries.swap(a, b);
+        self.slots[self.entries[a].slot].position = Some(a);
+        self.slots[self.entries[b].slot].position = Some(b);
+    }
+
+    fn sift_up(&mut self, mut position: usize) {
+        while position > 0 {
+            let parent = (position - 1) / 2;
+            if !self.outranks(position, parent) {
+                break;
+            }
+            self.swap(position, parent);
+            position = parent;
+        }
+    }
+
+    /// Sifts the item at `position` down, only looking at the first `end` items.
+    fn sift_down(&mut self, mut position: usize, end: usize) {
+        loop {
+            let left = 2 * position + 1;
+            let right = left + 1;
+            let mut top = position;
+            if left < end && self.outranks(left, top) {
+                top = left;
+            }
+            if right < end && self.outranks(right, top) {
+                top = right;
+            }
+            if top == position {
+                break;
+            }
+            self.swap(position, top);
+            position = top;
+        }
+    }
+}
+
+impl<T: Ord> Default for BinaryHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for BinaryHeap<T> {
+    /// Builds a max-heap in `O(n)`.
+    fn from(items: Vec<T>) -> Self {
+        BinaryHeap::from_vec(items, MaxOrder)
+    }
+}
+
+impl<T: Ord> FromIterator<T> for BinaryHeap<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        BinaryHeap::from(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks the heap property and that every slot points to its entry.
+    fn assert_valid<T, C: Compare<T>>(heap: &BinaryHeap<T, C>) {
+        for position in 1..heap.entries.len() {
+            assert!(!heap.outranks(position, (position - 1) / 2));
+        }
+        for (position, entry) in heap.entries.iter().enumerate() {
+            assert_eq!(heap.slots[entry.slot].position, Some(position));
+        }
+    }
+
+    #[test]
+    fn test_max_heap() {
+        let mut heap = BinaryHeap::new();
+        assert!(heap.is_empty());
+        assert_eq!(heap.peek(), None);
+        assert_eq!(heap.pop(), None);
+
+        for value in [3, 1, 4, 1, 5, 9, 2, 6] {
+            heap.push(value);
+            assert_valid(&heap);
+        }
+        assert_eq!(heap.len(), 8);
+        assert_eq!(heap.peek(), Some(&9));
+
+        let mut popped = Vec::new();
+        while let Some(value) = heap.pop() {
+            assert_valid(&heap);
+            popped.push(value);
+        }
+        assert_eq!(popped, vec![9, 6, 5, 4, 3, 2, 1, 1]);
+    }
+
+    #[test]
+    fn test_min_heap_and_custom_comparator() {
+        let mut heap = BinaryHeap::new_min();
+        for value in [5, 2, 8, 1] {
+            heap.push(value);
+        }
+        assert_eq!(heap.pop(), Some(1));
+        assert_eq!(heap.pop(), Some(2));
+
+        let by_second = |a: &(char, i32), b: &(char, i32)| a.1.cmp(&b.1);
+        let mut heap = BinaryHeap::with_comparator(by_second);
+        heap.push(('a', 2));
+        heap.push(('b', 7));
+        heap.push(('c', -1));
+        assert_eq!(heap.pop(), Some(('b', 7)));
+        assert_eq!(heap.pop(), Some(('a', 2)));
+    }
+
+    #[test]
+    fn test_heapify_and_sorted_vec() {
+        let values: Vec<i32> = (0..300).map(|i| (i * 7919) % 211 - 100).collect();
+        let mut sorted = values.clone();
+        sorted.sort();
+
+        let heap = BinaryHeap::from(values.clone());
+        assert_valid(&heap);
+        assert_eq!(heap.len(), values.len());
+        assert_eq!(heap.peek(), sorted.last());
+        assert_eq!(heap.into_sorted_vec(), sorted);
+
+        let heap = BinaryHeap::from_vec(values, MinOrder);
+        assert_valid(&heap);
+        sorted.reverse();
+        assert_eq!(heap.into_sorted_vec(), sorted);
+
+        let heap: BinaryHeap<i32> = Vec::new().into_iter().collect();
+        assert!(heap.into_sorted_vec().is_empty());
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut a: BinaryHeap<i32> = [1, 5, 3].into_iter().collect();
+        let kept = a.push(4);
+        let mut b = BinaryHeap::new();
+        b.push(10);
+        b.push(2);
+
+        a.merge(b);
+        assert_valid(&a);
+        assert_eq!(a.len(), 6);
+        assert_eq!(a.get(kept), Some(&4));
+        assert_eq!(a.peek(), Some(&10));
+        assert_eq!(a.into_sorted_vec(), vec![1, 2, 3, 4, 5, 10]);
+    }
+
+    #[test]
+    fn test_decrease_key() {
+        let mut heap = BinaryHeap::new_min();
+        let handles: Vec<Handle> = (0..10).map(|i| heap.push(i * 10)).collect();
+
+        assert_eq!(heap.decrease_key(handles[7], 5), Ok(()));
+        assert_valid(&heap);
+        assert_eq!(heap.get(handles[7]), Some(&5));
+        assert_eq!(heap.decrease_key(handles[9], 90), Ok(()));
+        assert_eq!(
+            heap.decrease_key(handles[3], 31),
+            Err("Priority would decrease")
+        );
+        assert_eq!(heap.get(handles[3]), Some(&30));
+
+        assert_eq!(heap.pop(), Some(0));
+        assert_eq!(heap.pop(), Some(5));
+        assert!(!heap.contains(handles[7]));
+        assert_eq!(heap.decrease_key(handles[7], 1), Err("Invalid handle"));
+        assert_eq!(heap.pop(), Some(10));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut heap = BinaryHeap::new();
+        let handles: Vec<Handle> = [8, 3, 9, 1, 7, 4]
+            .into_iter()
+            .map(|v| heap.push(v))
+            .collect();
+
+        assert_eq!(heap.remove(handles[4]), Some(7));
+        assert_valid(&heap);
+        assert_eq!(heap.remove(handles[4]), None);
+        assert_eq!(heap.remove(handles[2]), Some(9));
+        assert_valid(&heap);
+        assert_eq!(heap.len(), 4);
+
+        // The freed slot is reused, but the old handle stays stale
+        let reused = heap.push(100);
+        assert_eq!(reused.slot, handles[2].slot);
+        assert_eq!(heap.get(handles[2]), None);
+        assert_eq!(heap.get(reused), Some(&100));
+
+        heap.clear();
+        assert!(heap.is_empty());
+        assert!(!heap.contains(reused));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // Adjacency list of (neighbour, weight)
+        let graph: Vec<Vec<(usize, u32)>> = vec![
+            vec![(1, 4), (2, 1)],
+            vec![(3, 1)],
+            vec![(1, 2), (3, 5)],
+            vec![],
+        ];
+        let mut distances = vec![u32::MAX; graph.len()];
+        let mut handles: Vec<Option<Handle>> = vec![None; graph.len()];
+        let mut heap = BinaryHeap::new_min();
+
+        distances[0] = 0;
+        handles[0] = Some(heap.push((0, 0)));
+        while let Some((distance, node)) = heap.pop() {
+            for &(next, weight) in &graph[node] {
+                let candidate = distance + weight;
+                if candidate < distances[next] {
+                    distances[next] = candidate;
+                    match handles[next].filter(|&handle| heap.contains(handle)) {
+                        Some(handle) => heap.decrease_key(handle, (candidate, next)).unwrap(),
+                        None => handles[next] = Some(heap.push((candidate, next))),
+                    }
+                }
+            }
+        }
+        assert_eq!(distances, vec![0, 3, 1, 4]);
+    }
+}
@@ -0,0 +1,355 @@
+/// A min-priority queue of integer ids `0..capacity`, each with a priority.
+///
+/// Items are addressed by their id instead of a handle, which suits graph
+/// algorithms like Dijkstra's or Prim's where ids are the vertices. A
+/// position map from ids to heap positions is kept in sync on every move,
+/// so any id can be found, changed or removed in `O(log n)`.
+///
+/// # Type Parameters
+/// - `P`: The type of the priorities, the smallest one is popped first.
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::priority_queue::IndexedPriorityQueue;
+///
+/// let mut queue = IndexedPriorityQueue::new(4);
+/// queue.insert(0, 7).unwrap();
+/// queue.insert(3, 2).unwrap();
+/// queue.insert(1, 5).unwrap();
+///
+/// queue.change_priority(0, 1).unwrap();
+/// assert_eq!(queue.pop_min(), Some((0, 1)));
+/// assert_eq!(queue.remove(3), Some(2));
+/// assert_eq!(queue.pop_min(), Some((1, 5)));
+/// assert!(queue.is_empty());
+/// ```
+#[derive(Clone, Debug)]
+pub struct IndexedPriorityQueue<P> {
+    /// Ids in heap order.
+    heap: Vec<usize>,
+    /// Position in `heap` of every id, `None` if the id is not queued.
+    positions: Vec<Option<usize>>,
+    /// Priority of every id, `None` if the id is not queued.
+    priorities: Vec<Option<P>>,
+}
+
+impl<P: Ord> IndexedPriorityQueue<P> {
+    /// Creates a new empty `IndexedPriorityQueue` for the ids `0..capacity`.
+    pub fn new(capacity: usize) -> IndexedPriorityQueue<P> {
+        IndexedPriorityQueue {
+            heap: Vec::with_capacity(capacity),
+            positions: vec![None; capacity],
+            priorities: (0..capacity).map(|_| None).collect(),
+        }
+    }
+
+    /// Gets the amount of ids the queue can hold, which are `0..capacity`.
+    pub fn capacity(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Gets the amount of ids in the queue.
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Checks if the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Checks if `id` is in the queue. Ids out of bounds are never in it.
+    ///
+    /// # Complexity
+    /// - `O(1)`
+    pub fn contains(&self, id: usize) -> bool {
+        matches!(self.positions.get(id), Some(Some(_)))
+    }
+
+    /// Retrieves a reference to the priority of `id`, if it is in the queue.
+    pub fn priority(&self, id: usize) -> Option<&P> {
+        self.priorities.get(id).and_then(Option::as_ref)
+    }
+
+    /// Adds `id` to the queue with the given priority.
+    ///
+    /// # Errors
+    ///
+    /// - `"Index out of bounds"`: `id` is not below the capacity.
+    /// - `"Id already in the queue"`: use [`change_priority`](Self::change_priority) instead.
+    ///
+    /// # Complexity
+    /// - `O(log n)`
+    pub fn insert(&mut self, id: usize, priority: P) -> Result<(), &str> {
+        if id >= self.capacity() {
+            return Err("Index out of bounds");
+        }
+        if self.contains(id) {
+            return Err("Id already in the queue");
+        }
+        let position = self.heap.len();
+        self.heap.push(id);
+        self.positions[id] = Some(position);
+        self.priorities[id] = Some(priority);
+        self.sift_up(position);
+        Ok(())
+    }
+
+    /// Replaces the priority of `id`, moving it up or down as needed, and
+    /// returns the old priority.
+    ///
+    /// # Errors
+    ///
+    /// - `"Id not in the queue"`: `id` was never inserted, was removed or is
+    ///   out of bounds.
+    ///
+    /// # Complexity
+    /// - `O(log n)`
+    pub fn change_priority(&mut self, id: usize, priority: P) -> Result<P, &str> {
+        let position = self.position(id).ok_or("Id not in the queue")?;
+        let old = self.priorities[id]
+            .replace(priority)
+            .expect("queued ids have a priority");
+        self.sift_up(position);
+        self.sift_down(self.positions[id].expect("the id is still queued"));
+        Ok(old)
+    }
+
+    /// Removes `id` from the queue and returns its priority, or `None` if it
+    /// is not in the queue.
+    ///
+    /// # Complexity
+    /// - `O(log n)`
+    pub fn remove(&mut self, id: usize) -> Option<P> {
+        let position = self.position(id)?;
+        Some(self.remove_at(position).1)
+    }
+
+    /// Retrieves the id with the smallest priority and that priority, if the
+    /// queue is not empty.
+    ///
+    /// # Complexity
+    /// - `O(1)`
+    pub fn peek_min(&self) -> Option<(usize, &P)> {
+        self.heap
+            .first()
+            .map(|&id| (id, self.priority(id).expect("queued ids have a priority")))
+    }
+
+    /// Removes the id with the smallest priority and returns it along with
+    /// its priority, or `None` if the queue is empty.
+    ///
+    /// Ties are broken arbitrarily.
+    ///
+    /// # Complexity
+    /// - `O(log n)`
+    pub fn pop_min(&mut self) -> Option<(usize, P)> {
+        if self.heap.is_empty() {
+            None
+        } else {
+            Some(self.remove_at(0))
+        }
+    }
+
+    fn position(&self, id: usize) -> Option<usize> {
+        self.positions.get(id).copied().flatten()
+    }
+
+    fn remove_at(&mut self, position: usize) -> (usize, P) {
+        let last = self.heap.len() - 1;
+        self.swap(position, last);
+        let id = self.heap.pop().expect("the queue is not empty");
+        self.positions[id] = None;
+        let priority = self.priorities[id]
+            .take()
+            .expect("queued ids have a priority");
+        if position < self.heap.len() {
+            // The moved id may belong either above or below
+            let moved = self.heap[position];
+            self.sift_up(position);
+            self.sift_down(self.positions[moved].expect("the id is still queued"));
+        }
+        (id, priority)
+    }
+
+    /// Checks if the id at position `a` has a smaller priority than the one at `b`.
+    fn less(&self, a: usize, b: usize) -> bool {
+        self.priorities[self.heap[a]] < self.priorities[self.heap[b]]
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        self.positions[self.heap[a]] = Some(a);
+        self.positions[self.heap[b]] = Some(b);
+    }
+
+    fn sift_up(&mut self, mut position: usize) {
+        while position > 0 {
+            let parent = (position - 1) / 2;
+            if !self.less(position, parent) {
+                break;
+            }
+            self.swap(position, parent);
+            position = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut position: usize) {
+        let len = self.heap.len();
+        loop {
+            let left = 2 * position + 1;
+            let right = left + 1;
+            let mut smallest = position;
+            if left < len && self.less(left, smallest) {
+                smallest = left;
+            }
+            if right < len && self.less(right, smallest) {
+                smallest = right;
+            }
+            if smallest == position {
+                break;
+            }
+            self.swap(position, smallest);
+            position = smallest;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks the heap property and that the position map matches the heap.
+    fn assert_valid<P: Ord>(queue: &IndexedPriorityQueue<P>) {
+        for position in 1..queue.heap.len() {
+            assert!(!queue.less(position, (position - 1) / 2));
+        }
+        for (position, &id) in queue.heap.iter().enumerate() {
+            assert_eq!(queue.positions[id], Some(position));
+            assert!(queue.priorities[id].is_some());
+        }
+        let queued = queue.positions.iter().filter(|p| p.is_some()).count();
+        assert_eq!(queued, queue.len());
+    }
+
+    #[test]
+    fn test_insert_and_pop_min() {
+        let mut queue = IndexedPriorityQueue::new(10);
+        assert!(queue.is_empty());
+        assert_eq!(queue.pop_min(), None);
+        assert_eq!(queue.peek_min(), None);
+
+        for id in 0..10 {
+            queue.insert(id, (id * 7) % 10).unwrap();
+            assert_valid(&queue);
+        }
+        assert_eq!(queue.len(), 10);
+        assert_eq!(queue.peek_min(), Some((0, &0)));
+
+        let mut popped = Vec::new();
+        while let Some((_, priority)) = queue.pop_min() {
+            assert_valid(&queue);
+            popped.push(priority);
+        }
+        assert_eq!(popped, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_insert_errors() {
+        let mut queue = IndexedPriorityQueue::new(2);
+        assert_eq!(queue.insert(2, 'a'), Err("Index out of bounds"));
+        assert_eq!(queue.insert(1, 'a'), Ok(()));
+        assert_eq!(queue.insert(1, 'b'), Err("Id already in the queue"));
+        assert_eq!(queue.priority(1), Some(&'a'));
+        assert!(!queue.contains(5));
+    }
+
+    #[test]
+    fn test_change_priority() {
+        let mut queue = IndexedPriorityQueue::new(5);
+        for id in 0..5 {
+            queue.insert(id, id as i32 * 10).unwrap();
+        }
+
+        assert_eq!(queue.change_priority(4, -1), Ok(40));
+        assert_valid(&queue);
+        assert_eq!(queue.peek_min(), Some((4, &-1)));
+
+        assert_eq!(queue.change_priority(4, 100), Ok(-1));
+        assert_valid(&queue);
+        assert_eq!(queue.change_priority(0, 25), Ok(0));
+        assert_valid(&queue);
+        assert_eq!(queue.change_priority(7, 1), Err("Id not in the queue"));
+
+        let order: Vec<usize> = std::iter::from_fn(|| queue.pop_min().map(|(id, _)| id)).collect();
+        assert_eq!(order, vec![1, 2, 0, 3, 4]);
+        assert_eq!(queue.change_priority(1, 0), Err("Id not in the queue"));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut queue = IndexedPriorityQueue::new(8);
+        for (id, priority) in [5, 1, 7, 3, 9, 0, 4, 2].into_iter().enumerate() {
+            queue.insert(id, priority).unwrap();
+        }
+
+        assert_eq!(queue.remove(3), Some(3));
+        assert_valid(&queue);
+        assert!(!queue.contains(3));
+        assert_eq!(queue.remove(3), None);
+        assert_eq!(queue.remove(5), Some(0));
+        assert_valid(&queue);
+        assert_eq!(queue.remove(100), None);
+
+        // Removed ids can be inserted again
+        queue.insert(3, 6).unwrap();
+        let order: Vec<(usize, i32)> = std::iter::from_fn(|| queue.pop_min()).collect();
+        assert_eq!(
+            order,
+            vec![(1, 1), (7, 2), (6, 4), (0, 5), (3, 6), (2, 7), (4, 9)]
+        );
+    }
+
+    #[test]
+    fn test_prim() {
+        // Weighted undirected edges (a, b, weight)
+        let edges = [
+            (0, 1, 4),
+            (0, 2, 3),
+            (1, 2, 1),
+            (1, 3, 2),
+            (2, 3, 4),
+            (3, 4, 2),
+        ];
+        let vertices = 5;
+        let mut adjacency = vec![Vec::new(); vertices];
+        for &(a, b, weight) in &edges {
+            adjacency[a].push((b, weight));
+            adjacency[b].push((a, weight));
+        }
+
+        let mut in_tree = vec![false; vertices];
+        let mut queue = IndexedPriorityQueue::new(vertices);
+        queue.insert(0, 0).unwrap();
+        let mut total = 0;
+        while let Some((vertex, weight)) = queue.pop_min() {
+            in_tree[vertex] = true;
+            total += weight;
+            for &(next, edge) in &adjacency[vertex] {
+                if in_tree[next] {
+                    continue;
+                }
+                match queue.priority(next) {
+                    Some(&current) if current <= edge => {}
+                    Some(_) => {
+                        queue.change_priority(next, edge).unwrap();
+                    }
+                    None => queue.insert(next, edge).unwrap(),
+                }
+            }
+        }
+        assert_eq!(total, 8);
+        assert!(in_tree.iter().all(|&v| v));
+    }
+}
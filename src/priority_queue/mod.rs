@@ -0,0 +1,65 @@
+//! Priority queues: collections that always hand out their highest priority
+//! item first.
+//!
+//! - [`BinaryHeap`]: generic items ordered by a [`Compare`] implementation,
+//!   with handles to change or remove any item.
+//! - [`IndexedPriorityQueue`]: priorities of the integer ids `0..n`, changed
+//!   or removed by id.
+
+use std::cmp::Ordering;
+
+pub mod binary_heap;
+pub mod indexed_priority_queue;
+
+pub use binary_heap::{BinaryHeap, Handle};
+pub use indexed_priority_queue::IndexedPriorityQueue;
+
+/// Decides which of two items has the higher priority.
+///
+/// Closures taking two references and returning an [`Ordering`] implement it,
+/// so a heap can be ordered by any key.
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::priority_queue::BinaryHeap;
+///
+/// // Shortest words first
+/// let mut heap = BinaryHeap::with_comparator(|a: &&str, b: &&str| b.len().cmp(&a.len()));
+/// heap.push("three");
+/// heap.push("one");
+/// heap.push("four");
+///
+/// assert_eq!(heap.pop(), Some("one"));
+/// ```
+pub trait Compare<T> {
+    /// Returns `Greater` if `a` has a higher priority than `b`, `Less` if it
+    /// has a lower one and `Equal` otherwise.
+    fn compare(&self, a: &T, b: &T) -> Ordering;
+}
+
+impl<T, F: Fn(&T, &T) -> Ordering> Compare<T> for F {
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        self(a, b)
+    }
+}
+
+/// Gives the highest priority to the greatest item, for max-heaps.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MaxOrder;
+
+/// Gives the highest priority to the smallest item, for min-heaps.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MinOrder;
+
+impl<T: Ord> Compare<T> for MaxOrder {
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        a.cmp(b)
+    }
+}
+
+impl<T: Ord> Compare<T> for MinOrder {
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        b.cmp(a)
+    }
+}
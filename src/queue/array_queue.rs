@@ -0,0 +1,240 @@
+use super::Queue;
+
+/// Capacity of the array allocated by the first enqueue.
+const INITIAL_CAPACITY: usize = 4;
+
+/// A [`Queue`] built on a growable circular array.
+///
+/// The front of the queue moves forward through the array as items are
+/// dequeued and wraps around at its end, so no item is moved by `enqueue`
+/// or `dequeue`. The array doubles its capacity when it is full and halves
+/// it when only a quarter of it is used.
+pub struct ArrayQueue<T> {
+    items: Box<[Option<T>]>,
+    front: usize,
+    length: usize,
+}
+
+/// Borrowing iterator over an [`ArrayQueue`], from front to back.
+pub struct Iter<'a, T> {
+    items: &'a [Option<T>],
+    position: usize,
+    remaining: usize,
+}
+
+impl<T> ArrayQueue<T> {
+    /// Creates a new empty `ArrayQueue`. Nothing is allocated until the first enqueue.
+    pub fn new() -> ArrayQueue<T> {
+        ArrayQueue::with_capacity(0)
+    }
+
+    /// Creates a new empty `ArrayQueue` able to hold `capacity` items without growing.
+    pub fn with_capacity(capacity: usize) -> ArrayQueue<T> {
+        ArrayQueue {
+            items: Self::allocate(capacity),
+            front: 0,
+            length: 0,
+        }
+    }
+
+    /// Gets the amount of items the queue can hold before growing.
+    pub fn capacity(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Retrieves a reference to the item at the back of the queue, if it exists.
+    pub fn back(&self) -> Option<&T> {
+        if self.length == 0 {
+            return None;
+        }
+        self.items[self.index(self.length - 1)].as_ref()
+    }
+
+    /// Position in the array of the item `offset` places behind the front.
+    fn index(&self, offset: usize) -> usize {
+        (self.front + offset) % self.capacity()
+    }
+
+    fn allocate(capacity: usize) -> Box<[Option<T>]> {
+        (0..capacity).map(|_| None).collect()
+    }
+
+    /// Moves the items to an array of `capacity` slots, the front going to
+    /// the first one.
+    fn resize(&mut self, capacity: usize) {
+        let mut items = Self::allocate(capacity);
+        for (offset, slot) in items.iter_mut().take(self.length).enumerate() {
+            let index = self.index(offset);
+            *slot = self.items[index].take();
+        }
+        self.items = items;
+        self.front = 0;
+    }
+}
+
+impl<T> Queue<T> for ArrayQueue<T> {
+    type Iter<'a>
+        = Iter<'a, T>
+    where
+        T: 'a;
+
+    /// # Complexity
+    /// - `O(1)` amortized, `O(n)` when the array grows
+    fn enqueue(&mut self, data: T) {
+        if self.length == self.capacity() {
+            self.resize((self.capacity() * 2).max(INITIAL_CAPACITY));
+        }
+        let back = self.index(self.length);
+        self.items[back] = Some(data);
+        self.length += 1;
+    }
+
+    /// # Complexity
+    /// - `O(1)` amortized, `O(n)` when the array shrinks
+    fn dequeue(&mut self) -> Option<T> {
+        if self.length == 0 {
+            return None;
+        }
+        let data = self.items[self.front].take();
+        self.front = self.index(1);
+        self.length -= 1;
+
+        if self.length > 0 && self.length <= self.capacity() / 4 {
+            self.resize(self.capacity() / 2);
+        }
+        data
+    }
+
+    fn front(&self) -> Option<&T> {
+        if self.length == 0 {
+            return None;
+        }
+        self.items[self.front].as_ref()
+    }
+
+    fn len(&self) -> usize {
+        self.length
+    }
+
+    fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            items: &self.items,
+            position: self.front,
+            remaining: self.length,
+        }
+    }
+}
+
+impl<T> Default for ArrayQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let data = self.items[self.position].as_ref();
+        self.position = (self.position + 1) % self.items.len();
+        self.remaining -= 1;
+        data
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enqueue_dequeue() {
+        let mut queue = ArrayQueue::new();
+        assert!(queue.is_empty());
+        assert_eq!(queue.dequeue(), None);
+        assert_eq!(queue.front(), None);
+        assert_eq!(queue.back(), None);
+
+        queue.enqueue(1);
+        queue.enqueue(2);
+        queue.enqueue(3);
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.front(), Some(&1));
+        assert_eq!(queue.back(), Some(&3));
+
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.dequeue(), Some(3));
+        assert_eq!(queue.dequeue(), None);
+    }
+
+    #[test]
+    fn test_wraps_around() {
+        let mut queue = ArrayQueue::with_capacity(4);
+        for i in 0..4 {
+            queue.enqueue(i);
+        }
+        queue.dequeue();
+        queue.dequeue();
+        queue.enqueue(4);
+        queue.enqueue(5);
+
+        // The back is now stored before the front in the array
+        assert_eq!(queue.capacity(), 4);
+        assert_eq!(queue.iter().copied().collect::<Vec<_>>(), vec![2, 3, 4, 5]);
+        assert_eq!(queue.back(), Some(&5));
+
+        // Growing while wrapped keeps the order
+        queue.enqueue(6);
+        assert_eq!(queue.capacity(), 8);
+        assert_eq!(
+            queue.iter().copied().collect::<Vec<_>>(),
+            vec![2, 3, 4, 5, 6]
+        );
+    }
+
+    #[test]
+    fn test_grows_and_shrinks() {
+        let mut queue = ArrayQueue::new();
+        for i in 0..100 {
+            queue.enqueue(i);
+        }
+        assert_eq!(queue.capacity(), 128);
+
+        for i in 0..90 {
+            assert_eq!(queue.dequeue(), Some(i));
+        }
+        assert!(queue.capacity() < 128);
+        assert_eq!(
+            queue.iter().copied().collect::<Vec<_>>(),
+            (90..100).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_matches_linked_queue() {
+        use crate::queue::LinkedQueue;
+
+        let mut array = ArrayQueue::new();
+        let mut linked = LinkedQueue::new();
+        for i in 0..1000 {
+            if i % 3 == 2 {
+                assert_eq!(array.dequeue(), linked.dequeue());
+            } else {
+                array.enqueue(i);
+                linked.enqueue(i);
+            }
+            assert_eq!(array.len(), linked.len());
+            assert_eq!(array.front(), linked.front());
+        }
+        assert!(array.iter().eq(linked.iter()));
+    }
+}
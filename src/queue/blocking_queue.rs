@@ -0,0 +1,476 @@
+use crate::doubly_linked_list::DoublyLinkedList;
+use std::error::Error;
+use std::fmt;
+use std::sync::{Condvar, Mutex, MutexGuard};
+use std::time::Duration;
+
+/// A FIFO queue shared between producer and consumer threads.
+///
+/// Items are kept in a [`DoublyLinkedList`] behind a `Mutex`. When the queue
+/// is full, [`push`](BlockingQueue::push) waits for a consumer to make room;
+/// when it is empty, [`pop`](BlockingQueue::pop) waits for a producer. Any
+/// amount of threads can push and pop concurrently.
+///
+/// [`close`](BlockingQueue::close) stops the queue from accepting items and
+/// wakes every waiting thread. Consumers still receive the items already in
+/// the queue, then `pop` returns `None`.
+///
+/// # Type Parameters
+/// - `T`: The type of elements stored in the queue.
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::queue::blocking_queue::BlockingQueue;
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// let queue = Arc::new(BlockingQueue::new(2));
+/// let producer = {
+///     let queue = Arc::clone(&queue);
+///     thread::spawn(move || {
+///         for i in 0..5 {
+///             queue.push(i).unwrap();
+///         }
+///         queue.close();
+///     })
+/// };
+///
+/// let received: Vec<_> = std::iter::from_fn(|| queue.pop()).collect();
+/// producer.join().unwrap();
+/// assert_eq!(received, vec![0, 1, 2, 3, 4]);
+/// ```
+pub struct BlockingQueue<T> {
+    state: Mutex<State<T>>,
+    capacity: Option<usize>,
+    /// Signaled when an item is pushed or the queue is closed.
+    not_empty: Condvar,
+    /// Signaled when an item is popped or the queue is closed.
+    not_full: Condvar,
+}
+
+struct State<T> {
+    items: DoublyLinkedList<T>,
+    closed: bool,
+}
+
+/// Why an item could not be pushed. The item is handed back.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PushError<T> {
+    /// The queue stayed full: right away for `try_push`, or until the timeout.
+    Full(T),
+    /// The queue is closed.
+    Closed(T),
+}
+
+/// Why no item could be popped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PopError {
+    /// The queue stayed empty: right away for `try_pop`, or until the timeout.
+    Empty,
+    /// The queue is closed and has no items left.
+    Closed,
+}
+
+impl<T> BlockingQueue<T> {
+    /// Creates a new empty `BlockingQueue` holding at most `capacity` items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize) -> BlockingQueue<T> {
+        assert!(capacity > 0, "capacity must be greater than zero");
+        BlockingQueue::with_capacity(Some(capacity))
+    }
+
+    /// Creates a new empty `BlockingQueue` without capacity limit, so pushing
+    /// never blocks.
+    pub fn unbounded() -> BlockingQueue<T> {
+        BlockingQueue::with_capacity(None)
+    }
+
+    fn with_capacity(capacity: Option<usize>) -> BlockingQueue<T> {
+        BlockingQueue {
+            state: Mutex::new(State {
+                items: DoublyLinkedList::new(),
+                closed: false,
+            }),
+            capacity,
+            not_empty: Condvar::new(),
+            not_full: Condvar::new(),
+        }
+    }
+
+    /// Gets the maximum amount of items, or `None` if the queue is unbounded.
+    pub fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+
+    /// Gets the amount of items in the queue.
+    ///
+    /// Other threads may change it right after it is read.
+    pub fn len(&self) -> usize {
+        self.lock().items.len()
+    }
+
+    /// Checks if the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.lock().items.is_empty()
+    }
+
+    /// Checks if [`close`](BlockingQueue::close) was called.
+    pub fn is_closed(&self) -> bool {
+        self.lock().closed
+    }
+
+    /// Closes the queue and wakes every waiting thread.
+    ///
+    /// Pushes fail from now on, while pops keep returning the remaining
+    /// items. Closing twice has no effect.
+    pub fn close(&self) {
+        self.lock().closed = true;
+        self.not_empty.notify_all();
+        self.not_full.notify_all();
+    }
+
+    /// Adds an item at the back, waiting while the queue is full.
+    ///
+    /// # Errors
+    ///
+    /// - [`PushError::Closed`]: The queue is closed, or was closed while waiting.
+    pub fn push(&self, data: T) -> Result<(), PushError<T>> {
+        let state = self.lock();
+        let state = self
+            .not_full
+            .wait_while(state, |state| self.is_blocked_push(state))
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        self.push_ready(state, data)
+    }
+
+    /// Adds an item at the back if there is room, without waiting.
+    ///
+    /// # Errors
+    ///
+    /// - [`PushError::Full`]: The queue is full.
+    /// - [`PushError::Closed`]: The queue is closed.
+    pub fn try_push(&self, data: T) -> Result<(), PushError<T>> {
+        self.push_ready(self.lock(), data)
+    }
+
+    /// Adds an item at the back, waiting at most `timeout` while the queue is full.
+    ///
+    /// # Errors
+    ///
+    /// - [`PushError::Full`]: The queue was still full after `timeout`.
+    /// - [`PushError::Closed`]: The queue is closed, or was closed while waiting.
+    pub fn push_timeout(&self, data: T, timeout: Duration) -> Result<(), PushError<T>> {
+        let state = self.lock();
+        let (state, _) = self
+            .not_full
+            .wait_timeout_while(state, timeout, |state| self.is_blocked_push(state))
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        self.push_ready(state, data)
+    }
+
+    /// Removes the item at the front, waiting while the queue is empty.
+    ///
+    /// Returns `None` once the queue is closed and has no items left.
+    pub fn pop(&self) -> Option<T> {
+        let state = self.lock();
+        let state = self
+            .not_empty
+            .wait_while(state, |state| Self::is_blocked_pop(state))
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        self.pop_ready(state).ok()
+    }
+
+    /// Removes the item at the front if there is one, without waiting.
+    ///
+    /// # Errors
+    ///
+    /// - [`PopError::Empty`]: The queue is empty.
+    /// - [`PopError::Closed`]: The queue is closed and empty.
+    pub fn try_pop(&self) -> Result<T, PopError> {
+        self.pop_ready(self.lock())
+    }
+
+    /// Removes the item at the front, waiting at most `timeout` while the
+    /// queue is empty.
+    ///
+    /// # Errors
+    ///
+    /// - [`PopError::Empty`]: The queue was still empty after `timeout`.
+    /// - [`PopError::Closed`]: The queue is closed and empty.
+    pub fn pop_timeout(&self, timeout: Duration) -> Result<T, PopError> {
+        let state = self.lock();
+        let (state, _) = self
+            .not_empty
+            .wait_timeout_while(state, timeout, |state| Self::is_blocked_pop(state))
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        self.pop_ready(state)
+    }
+
+    /// Locks the state. A thread panicking while holding the lock cannot
+    /// leave the list half-modified, so poisoning is ignored.
+    fn lock(&self) -> MutexGuard<'_, State<T>> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn is_full(&self, state: &State<T>) -> bool {
+        self.capacity
+            .is_some_and(|capacity| state.items.len() >= capacity)
+    }
+
+    fn is_blocked_push(&self, state: &mut State<T>) -> bool {
+        !state.closed && self.is_full(state)
+    }
+
+    fn is_blocked_pop(state: &mut State<T>) -> bool {
+        !state.closed && state.items.is_empty()
+    }
+
+    /// Pushes without waiting, once the lock is held.
+    fn push_ready(&self, mut state: MutexGuard<'_, State<T>>, data: T) -> Result<(), PushError<T>> {
+        if state.closed {
+            return Err(PushError::Closed(data));
+        }
+        if self.is_full(&state) {
+            return Err(PushError::Full(data));
+        }
+        state.items.insert_at_end(data);
+        drop(state);
+        self.not_empty.notify_one();
+        Ok(())
+    }
+
+    /// Pops without waiting, once the lock is held.
+    fn pop_ready(&self, mut state: MutexGuard<'_, State<T>>) -> Result<T, PopError> {
+        match state.items.remove_first() {
+            Some(data) => {
+                drop(state);
+                self.not_full.notify_one();
+                Ok(data)
+            }
+            None if state.closed => Err(PopError::Closed),
+            None => Err(PopError::Empty),
+        }
+    }
+}
+
+impl<T> PushError<T> {
+    /// Returns the item that could not be pushed.
+    pub fn into_inner(self) -> T {
+        match self {
+            PushError::Full(data) | PushError::Closed(data) => data,
+        }
+    }
+}
+
+impl<T> fmt::Display for PushError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PushError::Full(_) => write!(f, "the queue is full"),
+            PushError::Closed(_) => write!(f, "the queue is closed"),
+        }
+    }
+}
+
+impl<T: fmt::Debug> Error for PushError<T> {}
+
+impl fmt::Display for PopError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PopError::Empty => write!(f, "the queue is empty"),
+            PopError::Closed => write!(f, "the queue is closed"),
+        }
+    }
+}
+
+impl Error for PopError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Instant;
+
+    #[test]
+    fn test_try_operations() {
+        let queue = BlockingQueue::new(2);
+        assert_eq!(queue.capacity(), Some(2));
+        assert_eq!(queue.try_pop(), Err(PopError::Empty));
+
+        assert_eq!(queue.try_push(1), Ok(()));
+        assert_eq!(queue.try_push(2), Ok(()));
+        assert_eq!(queue.try_push(3), Err(PushError::Full(3)));
+        assert_eq!(queue.len(), 2);
+
+        assert_eq!(queue.try_pop(), Ok(1));
+        assert_eq!(queue.try_push(3), Ok(()));
+        assert_eq!(queue.try_pop(), Ok(2));
+        assert_eq!(queue.try_pop(), Ok(3));
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_unbounded() {
+        let queue = BlockingQueue::unbounded();
+        assert_eq!(queue.capacity(), None);
+        for i in 0..1000 {
+            queue.try_push(i).unwrap();
+        }
+        assert_eq!(queue.len(), 1000);
+        assert_eq!(queue.pop(), Some(0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_capacity() {
+        BlockingQueue::<i32>::new(0);
+    }
+
+    #[test]
+    fn test_close() {
+        let queue = BlockingQueue::new(3);
+        queue.push('a').unwrap();
+        queue.push('b').unwrap();
+        queue.close();
+        queue.close();
+        assert!(queue.is_closed());
+
+        assert_eq!(queue.push('c'), Err(PushError::Closed('c')));
+        assert_eq!(queue.try_push('c').unwrap_err().into_inner(), 'c');
+        // Remaining items are still handed out
+        assert_eq!(queue.pop(), Some('a'));
+        assert_eq!(queue.try_pop(), Ok('b'));
+        assert_eq!(queue.pop(), None);
+        assert_eq!(queue.try_pop(), Err(PopError::Closed));
+        assert_eq!(
+            queue.pop_timeout(Duration::from_secs(10)),
+            Err(PopError::Closed)
+        );
+    }
+
+    #[test]
+    fn test_timeouts() {
+        let queue = BlockingQueue::new(1);
+        let start = Instant::now();
+        assert_eq!(
+            queue.pop_timeout(Duration::from_millis(20)),
+            Err(PopError::Empty)
+        );
+        assert!(start.elapsed() >= Duration::from_millis(20));
+
+        queue.push(1).unwrap();
+        let start = Instant::now();
+        assert_eq!(
+            queue.push_timeout(2, Duration::from_millis(20)),
+            Err(PushError::Full(2))
+        );
+        assert!(start.elapsed() >= Duration::from_millis(20));
+
+        assert_eq!(queue.pop_timeout(Duration::from_millis(20)), Ok(1));
+        assert_eq!(queue.push_timeout(2, Duration::from_millis(20)), Ok(()));
+    }
+
+    #[test]
+    fn test_timeout_wakes_on_item() {
+        let queue = Arc::new(BlockingQueue::new(1));
+        let consumer = {
+            let queue = Arc::clone(&queue);
+            thread::spawn(move || queue.pop_timeout(Duration::from_secs(10)))
+        };
+        thread::sleep(Duration::from_millis(20));
+        queue.push(42).unwrap();
+        assert_eq!(consumer.join().unwrap(), Ok(42));
+    }
+
+    #[test]
+    fn test_close_wakes_waiters() {
+        let queue = Arc::new(BlockingQueue::new(1));
+        queue.push(0).unwrap();
+
+        let producers: Vec<_> = (1..=3)
+            .map(|i| {
+                let queue = Arc::clone(&queue);
+                thread::spawn(move || queue.push(i))
+            })
+            .collect();
+        thread::sleep(Duration::from_millis(20));
+        queue.close();
+        for producer in producers {
+            assert!(matches!(
+                producer.join().unwrap(),
+                Err(PushError::Closed(_))
+            ));
+        }
+
+        let empty = Arc::new(BlockingQueue::<i32>::new(1));
+        let consumers: Vec<_> = (0..3)
+            .map(|_| {
+                let empty = Arc::clone(&empty);
+                thread::spawn(move || empty.pop())
+            })
+            .collect();
+        thread::sleep(Duration::from_millis(20));
+        empty.close();
+        for consumer in consumers {
+            assert_eq!(consumer.join().unwrap(), None);
+        }
+    }
+
+    #[test]
+    fn test_multiple_producers_and_consumers() {
+        let producers = 4;
+        let consumers = 4;
+        let per_producer = 5_000;
+        let queue = Arc::new(BlockingQueue::new(16));
+
+        let producer_handles: Vec<_> = (0..producers)
+            .map(|p| {
+                let queue = Arc::clone(&queue);
+                thread::spawn(move || {
+                    for i in 0..per_producer {
+                        queue.push(p * per_producer + i).unwrap();
+                    }
+                })
+            })
+            .collect();
+        let consumer_handles: Vec<_> = (0..consumers)
+            .map(|_| {
+                let queue = Arc::clone(&queue);
+                thread::spawn(move || {
+                    let mut received = Vec::new();
+                    while let Some(value) = queue.pop() {
+                        assert!(queue.len() <= 16);
+                        received.push(value);
+                    }
+                    received
+                })
+            })
+            .collect();
+
+        for handle in producer_handles {
+            handle.join().unwrap();
+        }
+        queue.close();
+
+        let mut seen = HashSet::new();
+        for handle in consumer_handles {
+            let received = handle.join().unwrap();
+            // Items of one producer arrive in order at each consumer
+            let mut last = vec![None; producers];
+            for value in received {
+                let producer = value / per_producer;
+                assert!(last[producer] < Some(value));
+                last[producer] = Some(value);
+                assert!(seen.insert(value), "value {} received twice", value);
+            }
+        }
+        assert_eq!(seen.len(), producers * per_producer);
+    }
+}
@@ -0,0 +1,134 @@
+use super::RingBuffer;
+use crate::doubly_linked_list::DoublyLinkedList;
+
+/// A double-ended queue: items can be added and removed at both ends.
+///
+/// It is the storage abstraction behind [`super::monotonic::MonotonicDeque`],
+/// implemented by [`DoublyLinkedList`] and [`RingBuffer`].
+///
+/// # Type Parameters
+/// - `T`: The type of elements stored in the deque.
+pub trait Deque<T> {
+    /// Adds an item at the back of the deque.
+    fn push_back(&mut self, data: T);
+
+    /// Removes the item at the front of the deque and returns it, or `None`
+    /// if the deque is empty.
+    fn pop_front(&mut self) -> Option<T>;
+
+    /// Removes the item at the back of the deque and returns it, or `None`
+    /// if the deque is empty.
+    fn pop_back(&mut self) -> Option<T>;
+
+    /// Retrieves a reference to the item at the front of the deque, if it exists.
+    fn front(&self) -> Option<&T>;
+
+    /// Retrieves a reference to the item at the back of the deque, if it exists.
+    fn back(&self) -> Option<&T>;
+
+    /// Gets the amount of items in the deque.
+    fn len(&self) -> usize;
+
+    /// Checks if the deque is empty.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Deque<T> for DoublyLinkedList<T> {
+    fn push_back(&mut self, data: T) {
+        self.insert_at_end(data);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        self.remove_first()
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        self.remove_last()
+    }
+
+    fn front(&self) -> Option<&T> {
+        self.get_first()
+    }
+
+    fn back(&self) -> Option<&T> {
+        self.get_last()
+    }
+
+    fn len(&self) -> usize {
+        DoublyLinkedList::len(self)
+    }
+}
+
+impl<T> Deque<T> for RingBuffer<T> {
+    /// Adds an item at the back of the buffer, following its overflow policy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is full and rejects new items.
+    fn push_back(&mut self, data: T) {
+        if RingBuffer::push_back(self, data).is_err() {
+            panic!("ring buffer is full");
+        }
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        RingBuffer::pop_front(self)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        RingBuffer::pop_back(self)
+    }
+
+    fn front(&self) -> Option<&T> {
+        RingBuffer::front(self)
+    }
+
+    fn back(&self) -> Option<&T> {
+        RingBuffer::back(self)
+    }
+
+    fn len(&self) -> usize {
+        RingBuffer::len(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::queue::OverflowPolicy;
+
+    fn exercise<D: Deque<i32>>(deque: &mut D) {
+        assert!(deque.is_empty());
+        deque.push_back(1);
+        deque.push_back(2);
+        deque.push_back(3);
+        assert_eq!(deque.len(), 3);
+        assert_eq!(deque.front(), Some(&1));
+        assert_eq!(deque.back(), Some(&3));
+        assert_eq!(deque.pop_back(), Some(3));
+        assert_eq!(deque.pop_front(), Some(1));
+        assert_eq!(deque.pop_front(), Some(2));
+        assert_eq!(deque.pop_back(), None);
+        assert!(deque.is_empty());
+    }
+
+    #[test]
+    fn test_doubly_linked_list_deque() {
+        exercise(&mut DoublyLinkedList::new());
+    }
+
+    #[test]
+    fn test_ring_buffer_deque() {
+        exercise(&mut RingBuffer::new(3, OverflowPolicy::Reject));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_full_rejecting_ring_buffer() {
+        let mut buffer = RingBuffer::new(1, OverflowPolicy::Reject);
+        Deque::push_back(&mut buffer, 1);
+        Deque::push_back(&mut buffer, 2);
+    }
+}
@@ -0,0 +1,99 @@
+use super::Queue;
+use crate::doubly_linked_list::{iter::Iter, DoublyLinkedList};
+
+/// A [`Queue`] built on a [`DoublyLinkedList`].
+///
+/// Items are inserted at the end of the list and removed from its start, so
+/// both operations are `O(1)`.
+pub struct LinkedQueue<T> {
+    list: DoublyLinkedList<T>,
+}
+
+impl<T> LinkedQueue<T> {
+    /// Creates a new empty `LinkedQueue`.
+    pub fn new() -> LinkedQueue<T> {
+        LinkedQueue {
+            list: DoublyLinkedList::new(),
+        }
+    }
+
+    /// Retrieves a reference to the item at the back of the queue, if it exists.
+    pub fn back(&self) -> Option<&T> {
+        self.list.get_last()
+    }
+}
+
+impl<T> Queue<T> for LinkedQueue<T> {
+    type Iter<'a>
+        = Iter<'a, T>
+    where
+        T: 'a;
+
+    /// # Complexity
+    /// - `O(1)`
+    fn enqueue(&mut self, data: T) {
+        self.list.insert_at_end(data);
+    }
+
+    /// # Complexity
+    /// - `O(1)`
+    fn dequeue(&mut self) -> Option<T> {
+        self.list.remove_first()
+    }
+
+    fn front(&self) -> Option<&T> {
+        self.list.get_first()
+    }
+
+    fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    fn iter(&self) -> Iter<'_, T> {
+        self.list.iter()
+    }
+}
+
+impl<T> Default for LinkedQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enqueue_dequeue() {
+        let mut queue = LinkedQueue::new();
+        assert!(queue.is_empty());
+        assert_eq!(queue.dequeue(), None);
+        assert_eq!(queue.front(), None);
+
+        queue.enqueue(1);
+        queue.enqueue(2);
+        queue.enqueue(3);
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.front(), Some(&1));
+        assert_eq!(queue.back(), Some(&3));
+
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.dequeue(), Some(2));
+        queue.enqueue(4);
+        assert_eq!(queue.dequeue(), Some(3));
+        assert_eq!(queue.dequeue(), Some(4));
+        assert_eq!(queue.dequeue(), None);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_iter_front_to_back() {
+        let mut queue = LinkedQueue::new();
+        for i in 0..5 {
+            queue.enqueue(i);
+        }
+        queue.dequeue();
+        assert_eq!(queue.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    }
+}
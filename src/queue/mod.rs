@@ -0,0 +1,75 @@
+/// A first-in, first-out collection.
+///
+/// Items are enqueued at the back and dequeued from the front. Iteration goes
+/// from the front to the back, the same order in which `dequeue` would return
+/// the items.
+///
+/// The crate provides two implementations:
+/// - [`LinkedQueue`]: built on [`crate::doubly_linked_list::DoublyLinkedList`].
+/// - [`ArrayQueue`]: built on a circular array that doubles its capacity when full.
+///
+/// # Type Parameters
+/// - `T`: The type of elements stored in the queue.
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::queue::{ArrayQueue, LinkedQueue, Queue};
+///
+/// fn fill<Q: Queue<i32>>(queue: &mut Q) {
+///     for i in 1..=3 {
+///         queue.enqueue(i);
+///     }
+/// }
+///
+/// let mut linked = LinkedQueue::new();
+/// let mut array = ArrayQueue::new();
+/// fill(&mut linked);
+/// fill(&mut array);
+///
+/// assert_eq!(linked.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+/// assert_eq!(array.dequeue(), Some(1));
+/// assert_eq!(array.front(), Some(&2));
+/// ```
+pub trait Queue<T> {
+    /// Iterator over the items of the queue, from front to back.
+    type Iter<'a>: Iterator<Item = &'a T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    /// Adds an item at the back of the queue.
+    fn enqueue(&mut self, data: T);
+
+    /// Removes the item at the front of the queue and returns it, or `None`
+    /// if the queue is empty.
+    fn dequeue(&mut self) -> Option<T>;
+
+    /// Retrieves a reference to the item at the front of the queue, if it exists.
+    fn front(&self) -> Option<&T>;
+
+    /// Gets the amount of items in the queue.
+    fn len(&self) -> usize;
+
+    /// Checks if the queue is empty.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over the items, from front to back.
+    fn iter(&self) -> Self::Iter<'_>;
+}
+
+pub mod array_queue;
+pub mod blocking_queue;
+pub mod deque;
+pub mod linked_queue;
+pub mod monotonic;
+pub mod ring_buffer;
+
+pub use array_queue::ArrayQueue;
+pub use blocking_queue::BlockingQueue;
+pub use deque::Deque;
+pub use linked_queue::LinkedQueue;
+pub use monotonic::{Extremum, MonotonicDeque};
+pub use ring_buffer::{OverflowPolicy, RingBuffer};
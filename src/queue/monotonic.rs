@@ -0,0 +1,449 @@
+//! Sliding-window algorithms built on a monotonic deque.
+//!
+//! A monotonic deque keeps its items sorted from front to back: before
+//! pushing an item at the back, every item that would break the order is
+//! popped, so the front always holds the extremum of the current window.
+//! Each item is pushed and popped at most once, so every function here runs
+//! in `O(n)` time and `O(k)` extra space for a window of `k` items.
+//!
+//! The window functions take any `IntoIterator`. Slices can be passed as is
+//! to the maximum and minimum, which then return references; the sum and
+//! first negative work on values, e.g. `values.iter().copied()`.
+
+use super::{Deque, OverflowPolicy, RingBuffer};
+use crate::doubly_linked_list::DoublyLinkedList;
+use std::marker::PhantomData;
+use std::ops::{Add, Sub};
+
+/// Which end of the ordering a [`MonotonicDeque`] keeps at its front.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Extremum {
+    /// Items are non-increasing from front to back, the front is the maximum.
+    Maximum,
+    /// Items are non-decreasing from front to back, the front is the minimum.
+    Minimum,
+}
+
+/// A deque whose items stay sorted, tracking the maximum or minimum of a
+/// sliding window in amortized `O(1)` per item.
+///
+/// Items enter the window with [`push`](MonotonicDeque::push) and leave it
+/// with [`evict`](MonotonicDeque::evict), in the same order. Items that can
+/// never become the extremum are dropped on the way in, so the deque usually
+/// holds fewer items than the window.
+///
+/// Any [`Deque`] can back it: a [`DoublyLinkedList`] by default, or a
+/// [`RingBuffer`] with at least the window size as capacity to avoid
+/// allocating while sliding.
+///
+/// # Type Parameters
+/// - `T`: The type of elements stored in the deque.
+/// - `D`: The deque holding the items.
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::queue::monotonic::{Extremum, MonotonicDeque};
+///
+/// let mut window = MonotonicDeque::new(Extremum::Maximum);
+/// window.push(3);
+/// window.push(1);
+/// window.push(2);
+/// assert_eq!(window.front(), Some(&3));
+///
+/// // 3 leaves the window
+/// window.evict(&3);
+/// assert_eq!(window.front(), Some(&2));
+/// ```
+pub struct MonotonicDeque<T, D = DoublyLinkedList<T>> {
+    items: D,
+    extremum: Extremum,
+    data: PhantomData<T>,
+}
+
+impl<T: Ord> MonotonicDeque<T> {
+    /// Creates a new empty `MonotonicDeque` backed by a [`DoublyLinkedList`].
+    pub fn new(extremum: Extremum) -> MonotonicDeque<T> {
+        MonotonicDeque::with_storage(DoublyLinkedList::new(), extremum)
+    }
+}
+
+impl<T: Ord, D: Deque<T>> MonotonicDeque<T, D> {
+    /// Creates a new `MonotonicDeque` on top of `items`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `items` is not empty.
+    pub fn with_storage(items: D, extremum: Extremum) -> MonotonicDeque<T, D> {
+        assert!(items.is_empty(), "the storage must be empty");
+        MonotonicDeque {
+            items,
+            extremum,
+            data: PhantomData,
+        }
+    }
+
+    /// Gets which extremum is kept at the front.
+    pub fn extremum(&self) -> Extremum {
+        self.extremum
+    }
+
+    /// Adds an item entering the window, dropping every item at the back
+    /// that it outranks.
+    ///
+    /// Equal items are kept, so each of them can later be evicted on its own.
+    ///
+    /// # Complexity
+    /// - `O(1)` amortized
+    pub fn push(&mut self, data: T) {
+        while let Some(back) = self.items.back() {
+            let outranked = match self.extremum {
+                Extremum::Maximum => *back < data,
+                Extremum::Minimum => *back > data,
+            };
+            if !outranked {
+                break;
+            }
+            self.items.pop_back();
+        }
+        self.items.push_back(data);
+    }
+
+    /// Signals that `data`, the oldest item of the window, has left it.
+    ///
+    /// The front is removed only if it is that item; otherwise it was
+    /// already dropped by a later push.
+    ///
+    /// # Returns
+    ///
+    /// - `true`: The front was removed.
+    /// - `false`: The deque was left untouched.
+    ///
+    /// # Complexity
+    /// - `O(1)`
+    pub fn evict(&mut self, data: &T) -> bool {
+        if self.items.front() == Some(data) {
+            self.items.pop_front();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Retrieves a reference to the extremum of the window, if it exists.
+    pub fn front(&self) -> Option<&T> {
+        self.items.front()
+    }
+
+    /// Gets the amount of items kept, which is at most the window size.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Checks if the deque is empty.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the storage, with the items kept from front to back.
+    pub fn into_storage(self) -> D {
+        self.items
+    }
+}
+
+/// Finds the maximum of every window of `window` consecutive items.
+///
+/// Returns one value per window, so `n - window + 1` values, or none if
+/// there are fewer items than `window`.
+///
+/// # Panics
+///
+/// Panics if `window` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::queue::monotonic::sliding_window_max;
+///
+/// assert_eq!(
+///     sliding_window_max([1, 3, -1, -3, 5, 3, 6, 7], 3),
+///     vec![3, 3, 5, 5, 6, 7]
+/// );
+/// // Slices give references back
+/// assert_eq!(sliding_window_max(&["b", "a", "c"], 2), vec![&"b", &"c"]);
+/// ```
+pub fn sliding_window_max<T, I>(items: I, window: usize) -> Vec<T>
+where
+    T: Ord + Clone,
+    I: IntoIterator<Item = T>,
+{
+    sliding_window_extremum(items, window, Extremum::Maximum)
+}
+
+/// Finds the minimum of every window of `window` consecutive items.
+///
+/// Returns one value per window, so `n - window + 1` values, or none if
+/// there are fewer items than `window`.
+///
+/// # Panics
+///
+/// Panics if `window` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::queue::monotonic::sliding_window_min;
+///
+/// assert_eq!(
+///     sliding_window_min([1, 3, -1, -3, 5, 3, 6, 7], 3),
+///     vec![-1, -3, -3, -3, 3, 3]
+/// );
+/// ```
+pub fn sliding_window_min<T, I>(items: I, window: usize) -> Vec<T>
+where
+    T: Ord + Clone,
+    I: IntoIterator<Item = T>,
+{
+    sliding_window_extremum(items, window, Extremum::Minimum)
+}
+
+/// Computes the sum of every window of `window` consecutive items.
+///
+/// The sum is updated by adding the entering item and subtracting the
+/// leaving one, so each window costs `O(1)`.
+///
+/// # Panics
+///
+/// Panics if `window` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::queue::monotonic::sliding_window_sum;
+///
+/// assert_eq!(sliding_window_sum([1, 2, 3, 4, 5], 2), vec![3, 5, 7, 9]);
+/// assert!(sliding_window_sum([1.5, 2.0], 3).is_empty());
+/// ```
+pub fn sliding_window_sum<T, I>(items: I, window: usize) -> Vec<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T>,
+    I: IntoIterator<Item = T>,
+{
+    let mut current = RingBuffer::new(window, OverflowPolicy::Overwrite);
+    let mut sum: Option<T> = None;
+    let mut sums = Vec::new();
+
+    for data in items {
+        // The evicted item is subtracted before the new one is added, so the
+        // running sum never goes past the sum of a window
+        if let Ok(Some(evicted)) = current.push_back(data) {
+            sum = sum.map(|sum| sum - evicted);
+        }
+        sum = Some(match sum {
+            Some(sum) => sum + data,
+            None => data,
+        });
+        if current.is_full() {
+            sums.extend(sum);
+        }
+    }
+    sums
+}
+
+/// Finds the first negative item of every window of `window` consecutive
+/// items, or `None` for windows without one.
+///
+/// An item is negative when it is smaller than `T::default()`.
+///
+/// # Panics
+///
+/// Panics if `window` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::queue::monotonic::first_negative;
+///
+/// assert_eq!(
+///     first_negative([12, -1, -7, 8, 15, 30, 16, 28], 3),
+///     vec![Some(-1), Some(-1), Some(-7), None, None, None]
+/// );
+/// ```
+pub fn first_negative<T, I>(items: I, window: usize) -> Vec<Option<T>>
+where
+    T: PartialOrd + Default + Clone,
+    I: IntoIterator<Item = T>,
+{
+    let zero = T::default();
+    let mut current = RingBuffer::new(window, OverflowPolicy::Overwrite);
+    // Negative items of the window, oldest first
+    let mut negatives = DoublyLinkedList::new();
+    let mut firsts = Vec::new();
+
+    for data in items {
+        if data < zero {
+            negatives.insert_at_end(data.clone());
+        }
+        if let Ok(Some(evicted)) = current.push_back(data) {
+            // The oldest negative is the front of `negatives`
+            if evicted < zero {
+                negatives.remove_first();
+            }
+        }
+        if current.is_full() {
+            firsts.push(negatives.get_first().cloned());
+        }
+    }
+    firsts
+}
+
+fn sliding_window_extremum<T, I>(items: I, window: usize, extremum: Extremum) -> Vec<T>
+where
+    T: Ord + Clone,
+    I: IntoIterator<Item = T>,
+{
+    let mut current = RingBuffer::new(window, OverflowPolicy::Overwrite);
+    let mut candidates =
+        MonotonicDeque::with_storage(RingBuffer::new(window, OverflowPolicy::Reject), extremum);
+    let mut extremes = Vec::new();
+
+    for data in items {
+        // Evicting first keeps at most `window - 1` candidates before the push
+        if let Ok(Some(evicted)) = current.push_back(data.clone()) {
+            candidates.evict(&evicted);
+        }
+        candidates.push(data);
+        if current.is_full() {
+            extremes.extend(candidates.front().cloned());
+        }
+    }
+    extremes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brute_force<T: Ord + Clone>(values: &[T], window: usize, extremum: Extremum) -> Vec<T> {
+        values
+            .windows(window)
+            .map(|w| match extremum {
+                Extremum::Maximum => w.iter().max().unwrap().clone(),
+                Extremum::Minimum => w.iter().min().unwrap().clone(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_monotonic_deque_keeps_order() {
+        let mut deque = MonotonicDeque::new(Extremum::Minimum);
+        assert!(deque.is_empty());
+        assert_eq!(deque.front(), None);
+
+        for value in [5, 3, 4, 3, 6] {
+            deque.push(value);
+        }
+        // 5 and 4 can never be the minimum anymore
+        assert_eq!(deque.len(), 3);
+        assert_eq!(deque.front(), Some(&3));
+
+        assert!(!deque.evict(&5));
+        assert!(deque.evict(&3));
+        assert_eq!(deque.front(), Some(&3));
+        assert!(deque.evict(&3));
+        assert_eq!(deque.front(), Some(&6));
+
+        let storage = deque.into_storage();
+        assert_eq!(storage.iter().collect::<Vec<_>>(), vec![&6]);
+    }
+
+    #[test]
+    fn test_monotonic_deque_on_ring_buffer() {
+        let storage = RingBuffer::new(3, OverflowPolicy::Reject);
+        let mut deque = MonotonicDeque::with_storage(storage, Extremum::Maximum);
+        assert_eq!(deque.extremum(), Extremum::Maximum);
+        deque.push(1);
+        deque.push(2);
+        deque.push(2);
+        deque.push(0);
+        assert_eq!(deque.front(), Some(&2));
+        assert_eq!(deque.len(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_monotonic_deque_non_empty_storage() {
+        let mut storage = DoublyLinkedList::new();
+        storage.insert_at_end(1);
+        MonotonicDeque::with_storage(storage, Extremum::Maximum);
+    }
+
+    #[test]
+    fn test_sliding_window_extremes_match_brute_force() {
+        let values: Vec<i64> = (0..500).map(|i| (i * 7919 % 101) - 50).collect();
+        for window in [1, 2, 3, 10, 64, 500] {
+            assert_eq!(
+                sliding_window_max(values.iter().copied(), window),
+                brute_force(&values, window, Extremum::Maximum)
+            );
+            assert_eq!(
+                sliding_window_min(&values, window),
+                brute_force(&values, window, Extremum::Minimum)
+                    .iter()
+                    .map(|min| values.iter().find(|v| *v == min).unwrap())
+                    .collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn test_sliding_window_with_duplicates() {
+        assert_eq!(sliding_window_max([2, 2, 2, 1, 1], 2), vec![2, 2, 2, 1]);
+        assert_eq!(sliding_window_min([1, 1, 3, 3, 1], 2), vec![1, 1, 3, 1]);
+    }
+
+    #[test]
+    fn test_window_larger_than_input() {
+        assert!(sliding_window_max([1, 2], 3).is_empty());
+        assert!(sliding_window_sum([1, 2], 3).is_empty());
+        assert!(first_negative([-1, 2], 3).is_empty());
+        assert!(sliding_window_min(Vec::<i32>::new(), 1).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_window() {
+        sliding_window_max([1, 2], 0);
+    }
+
+    #[test]
+    fn test_sliding_window_sum() {
+        assert_eq!(sliding_window_sum([1, 2, 3], 1), vec![1, 2, 3]);
+        assert_eq!(sliding_window_sum([1, 2, 3], 3), vec![6]);
+        assert_eq!(sliding_window_sum([4, -2, 7, 1, -5], 2), vec![2, 5, 8, -4]);
+
+        let values: Vec<i32> = (0..200).map(|i| i * 31 % 17 - 8).collect();
+        let expected: Vec<i32> = values.windows(9).map(|w| w.iter().sum()).collect();
+        assert_eq!(sliding_window_sum(values, 9), expected);
+    }
+
+    #[test]
+    fn test_sliding_window_sum_without_overflow() {
+        // Every window fits in an i8, although 100 + 27 + 27 does not
+        assert_eq!(sliding_window_sum([100i8, 27, 27], 2), vec![127, 54]);
+        assert_eq!(sliding_window_sum([u8::MAX, 0, u8::MAX], 2), vec![255, 255]);
+    }
+
+    #[test]
+    fn test_first_negative() {
+        assert_eq!(
+            first_negative([-8, 2, 3, -6, 10], 2),
+            vec![Some(-8), None, Some(-6), Some(-6)]
+        );
+        assert_eq!(first_negative([1, 2, 3], 1), vec![None, None, None]);
+        assert_eq!(
+            first_negative([-1.5, -2.5, 0.0].iter().copied(), 2),
+            vec![Some(-1.5), Some(-2.5)]
+        );
+    }
+}
@@ -0,0 +1,421 @@
+use std::iter::Chain;
+use std::mem::MaybeUninit;
+use std::ptr;
+use std::slice;
+
+/// What a [`RingBuffer`] does with a push when it is full.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// The new item is handed back and the buffer is left untouched.
+    Reject,
+    /// The item at the opposite end is evicted to make room for the new one.
+    Overwrite,
+}
+
+/// A double-ended FIFO with a fixed capacity.
+///
+/// Unlike [`crate::doubly_linked_list::DoublyLinkedList`], the buffer never
+/// allocates after it is created: items live in one array whose ends wrap
+/// around, and a push on a full buffer either fails or evicts the oldest item
+/// according to its [`OverflowPolicy`].
+///
+/// # Type Parameters
+/// - `T`: The type of elements stored in the buffer.
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::queue::ring_buffer::{OverflowPolicy, RingBuffer};
+///
+/// let mut samples = RingBuffer::new(3, OverflowPolicy::Overwrite);
+/// for value in 1..=4 {
+///     samples.push_back(value).unwrap();
+/// }
+/// // 1 was evicted to make room for 4
+/// assert_eq!(samples.iter().collect::<Vec<_>>(), vec![&2, &3, &4]);
+///
+/// let mut bounded = RingBuffer::new(1, OverflowPolicy::Reject);
+/// assert_eq!(bounded.push_back('a'), Ok(None));
+/// assert_eq!(bounded.push_back('b'), Err('b'));
+/// ```
+pub struct RingBuffer<T> {
+    items: Box<[MaybeUninit<T>]>,
+    front: usize,
+    length: usize,
+    policy: OverflowPolicy,
+}
+
+/// Borrowing iterator over a [`RingBuffer`], from front to back.
+pub struct Iter<'a, T> {
+    inner: Chain<slice::Iter<'a, T>, slice::Iter<'a, T>>,
+}
+
+impl<T> RingBuffer<T> {
+    /// Creates a new empty `RingBuffer` able to hold `capacity` items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize, policy: OverflowPolicy) -> RingBuffer<T> {
+        assert!(capacity > 0, "capacity must be greater than zero");
+        RingBuffer {
+            items: (0..capacity).map(|_| MaybeUninit::uninit()).collect(),
+            front: 0,
+            length: 0,
+            policy,
+        }
+    }
+
+    /// Gets the amount of items the buffer can hold.
+    pub fn capacity(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Gets the amount of items in the buffer.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Checks if the buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Checks if the next push will be rejected or overwrite an item.
+    pub fn is_full(&self) -> bool {
+        self.length == self.capacity()
+    }
+
+    /// Gets the policy applied when pushing on a full buffer.
+    pub fn policy(&self) -> OverflowPolicy {
+        self.policy
+    }
+
+    /// Inserts an item at the back of the buffer.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(None)`: The item was stored and nothing was evicted.
+    /// - `Ok(Some(T))`: The buffer was full and overwrites, the front item was evicted.
+    /// - `Err(T)`: The buffer was full and rejects, the item is handed back.
+    ///
+    /// # Complexity
+    /// - `O(1)`
+    pub fn push_back(&mut self, data: T) -> Result<Option<T>, T> {
+        let evicted = match (self.is_full(), self.policy) {
+            (false, _) => None,
+            (true, OverflowPolicy::Reject) => return Err(data),
+            (true, OverflowPolicy::Overwrite) => self.pop_front(),
+        };
+        let back = self.index(self.length);
+        self.items[back].write(data);
+        self.length += 1;
+        Ok(evicted)
+    }
+
+    /// Inserts an item at the front of the buffer.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(None)`: The item was stored and nothing was evicted.
+    /// - `Ok(Some(T))`: The buffer was full and overwrites, the back item was evicted.
+    /// - `Err(T)`: The buffer was full and rejects, the item is handed back.
+    ///
+    /// # Complexity
+    /// - `O(1)`
+    pub fn push_front(&mut self, data: T) -> Result<Option<T>, T> {
+        let evicted = match (self.is_full(), self.policy) {
+            (false, _) => None,
+            (true, OverflowPolicy::Reject) => return Err(data),
+            (true, OverflowPolicy::Overwrite) => self.pop_back(),
+        };
+        self.front = self.index(self.capacity() - 1);
+        self.items[self.front].write(data);
+        self.length += 1;
+        Ok(evicted)
+    }
+
+    /// Removes the item at the front of the buffer and returns it, or `None`
+    /// if the buffer is empty.
+    ///
+    /// # Complexity
+    /// - `O(1)`
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.length == 0 {
+            return None;
+        }
+        // SAFETY: the first `length` slots from `front` are initialized, and
+        // the slot is considered uninitialized again right after the read.
+        let data = unsafe { self.items[self.front].assume_init_read() };
+        self.front = self.index(1);
+        self.length -= 1;
+        Some(data)
+    }
+
+    /// Removes the item at the back of the buffer and returns it, or `None`
+    /// if the buffer is empty.
+    ///
+    /// # Complexity
+    /// - `O(1)`
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.length == 0 {
+            return None;
+        }
+        let back = self.index(self.length - 1);
+        self.length -= 1;
+        // SAFETY: `back` was the last initialized slot and is no longer counted.
+        Some(unsafe { self.items[back].assume_init_read() })
+    }
+
+    /// Retrieves a reference to the item at the front of the buffer, if it exists.
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Retrieves a reference to the item at the back of the buffer, if it exists.
+    pub fn back(&self) -> Option<&T> {
+        self.length.checked_sub(1).and_then(|last| self.get(last))
+    }
+
+    /// Retrieves a reference to the item `pos` places behind the front, if it exists.
+    pub fn get(&self, pos: usize) -> Option<&T> {
+        if pos >= self.length {
+            return None;
+        }
+        // SAFETY: `pos < length`, so the slot is initialized.
+        Some(unsafe { self.items[self.index(pos)].assume_init_ref() })
+    }
+
+    /// Returns the items as two slices which, one after the other, hold the
+    /// buffer from front to back. The second slice is empty unless the items
+    /// wrap around the end of the array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::queue::ring_buffer::{OverflowPolicy, RingBuffer};
+    ///
+    /// let mut buffer = RingBuffer::new(4, OverflowPolicy::Reject);
+    /// buffer.push_back(2).unwrap();
+    /// buffer.push_back(3).unwrap();
+    /// buffer.push_front(1).unwrap();
+    ///
+    /// assert_eq!(buffer.as_slices(), (&[1][..], &[2, 3][..]));
+    /// ```
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (first, second) = self.ranges();
+        // SAFETY: both ranges only cover initialized slots, and `MaybeUninit<T>`
+        // has the same layout as `T`.
+        unsafe {
+            (
+                &*(&self.items[first.0..first.1] as *const [MaybeUninit<T>] as *const [T]),
+                &*(&self.items[second.0..second.1] as *const [MaybeUninit<T>] as *const [T]),
+            )
+        }
+    }
+
+    /// Mutable version of [`as_slices`](RingBuffer::as_slices).
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (first, second) = self.ranges();
+        let (head, tail) = self.items.split_at_mut(first.0);
+        // SAFETY: the second range always ends before the first one starts,
+        // and both only cover initialized slots.
+        unsafe {
+            (
+                &mut *(&mut tail[..first.1 - first.0] as *mut [MaybeUninit<T>] as *mut [T]),
+                &mut *(&mut head[second.0..second.1] as *mut [MaybeUninit<T>] as *mut [T]),
+            )
+        }
+    }
+
+    /// Returns an iterator over the items, from front to back.
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (first, second) = self.as_slices();
+        Iter {
+            inner: first.iter().chain(second.iter()),
+        }
+    }
+
+    /// Removes every item, keeping the capacity.
+    pub fn clear(&mut self) {
+        let (first, second) = self.as_mut_slices();
+        let first: *mut [T] = first;
+        let second: *mut [T] = second;
+        // Forget the items first, so a panicking destructor cannot cause a double drop
+        self.front = 0;
+        self.length = 0;
+        // SAFETY: the slices held the initialized items, which are no longer counted.
+        unsafe {
+            ptr::drop_in_place(first);
+            ptr::drop_in_place(second);
+        }
+    }
+
+    /// Position in the array of the item `offset` places behind the front.
+    fn index(&self, offset: usize) -> usize {
+        (self.front + offset) % self.capacity()
+    }
+
+    /// Bounds of the two runs of initialized slots, in front to back order.
+    fn ranges(&self) -> ((usize, usize), (usize, usize)) {
+        let end = self.front + self.length;
+        if end <= self.capacity() {
+            ((self.front, end), (0, 0))
+        } else {
+            ((self.front, self.capacity()), (0, end - self.capacity()))
+        }
+    }
+}
+
+impl<T> Drop for RingBuffer<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<'a, T> IntoIterator for &'a RingBuffer<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+
+    #[test]
+    #[should_panic]
+    fn test_zero_capacity() {
+        let _: RingBuffer<i32> = RingBuffer::new(0, OverflowPolicy::Reject);
+    }
+
+    #[test]
+    fn test_reject_policy() {
+        let mut buffer = RingBuffer::new(2, OverflowPolicy::Reject);
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.push_back(1), Ok(None));
+        assert_eq!(buffer.push_back(2), Ok(None));
+        assert!(buffer.is_full());
+        assert_eq!(buffer.push_back(3), Err(3));
+        assert_eq!(buffer.push_front(0), Err(0));
+        assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), vec![1, 2]);
+
+        assert_eq!(buffer.pop_front(), Some(1));
+        assert_eq!(buffer.push_back(3), Ok(None));
+        assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), vec![2, 3]);
+    }
+
+    #[test]
+    fn test_overwrite_policy() {
+        let mut buffer = RingBuffer::new(3, OverflowPolicy::Overwrite);
+        for i in 0..3 {
+            assert_eq!(buffer.push_back(i), Ok(None));
+        }
+        assert_eq!(buffer.push_back(3), Ok(Some(0)));
+        assert_eq!(buffer.push_back(4), Ok(Some(1)));
+        assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), vec![2, 3, 4]);
+
+        // Pushing at the front evicts the back
+        assert_eq!(buffer.push_front(1), Ok(Some(4)));
+        assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(buffer.len(), 3);
+    }
+
+    #[test]
+    fn test_both_ends() {
+        let mut buffer = RingBuffer::new(4, OverflowPolicy::Reject);
+        buffer.push_back(2).unwrap();
+        buffer.push_front(1).unwrap();
+        buffer.push_back(3).unwrap();
+        buffer.push_front(0).unwrap();
+
+        assert_eq!(buffer.front(), Some(&0));
+        assert_eq!(buffer.back(), Some(&3));
+        assert_eq!(buffer.get(2), Some(&2));
+        assert_eq!(buffer.get(4), None);
+
+        assert_eq!(buffer.pop_back(), Some(3));
+        assert_eq!(buffer.pop_front(), Some(0));
+        assert_eq!(buffer.pop_back(), Some(2));
+        assert_eq!(buffer.pop_back(), Some(1));
+        assert_eq!(buffer.pop_back(), None);
+        assert_eq!(buffer.pop_front(), None);
+        assert_eq!(buffer.front(), None);
+        assert_eq!(buffer.back(), None);
+    }
+
+    #[test]
+    fn test_slices() {
+        let mut buffer = RingBuffer::new(4, OverflowPolicy::Overwrite);
+        assert_eq!(buffer.as_slices(), (&[][..], &[][..]));
+
+        for i in 0..3 {
+            buffer.push_back(i).unwrap();
+        }
+        assert_eq!(buffer.as_slices(), (&[0, 1, 2][..], &[][..]));
+
+        buffer.push_back(3).unwrap();
+        buffer.push_back(4).unwrap();
+        buffer.push_back(5).unwrap();
+        assert_eq!(buffer.as_slices(), (&[2, 3][..], &[4, 5][..]));
+
+        let (first, second) = buffer.as_mut_slices();
+        first[0] = 20;
+        second[1] = 50;
+        assert_eq!(
+            buffer.iter().copied().collect::<Vec<_>>(),
+            vec![20, 3, 4, 50]
+        );
+        assert_eq!(
+            buffer.iter().rev().copied().collect::<Vec<_>>(),
+            vec![50, 4, 3, 20]
+        );
+        assert_eq!(buffer.iter().len(), 4);
+    }
+
+    #[test]
+    fn test_items_are_dropped_once() {
+        let item = Rc::new(());
+        let mut buffer = RingBuffer::new(3, OverflowPolicy::Overwrite);
+        for _ in 0..5 {
+            buffer.push_back(Rc::clone(&item)).unwrap();
+        }
+        assert_eq!(Rc::strong_count(&item), 4);
+
+        buffer.pop_front();
+        assert_eq!(Rc::strong_count(&item), 3);
+
+        buffer.clear();
+        assert!(buffer.is_empty());
+        assert_eq!(Rc::strong_count(&item), 1);
+
+        buffer.push_front(Rc::clone(&item)).unwrap();
+        buffer.push_back(Rc::clone(&item)).unwrap();
+        drop(buffer);
+        assert_eq!(Rc::strong_count(&item), 1);
+    }
+}
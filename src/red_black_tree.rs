@@ -0,0 +1,646 @@
+use crate::search_tree::{self, Entry, SearchTree};
+use std::cmp::Ordering;
+use std::ops::RangeBounds;
+
+/// An ordered map kept balanced as a red-black tree.
+///
+/// Every node is either red or black, the root is black, a red node never
+/// has a red child and every path from a node down to an empty subtree goes
+/// through the same number of black nodes. Thus the longest path is at most
+/// twice the shortest one and the height stays below `2 log2(n + 1)`.
+///
+/// The balance is looser than the one of an [`AvlTree`](crate::avl_tree::AvlTree),
+/// so lookups may walk a little deeper, but most fix-ups after a write only
+/// recolor nodes: an insertion needs at most two rotations and a removal at
+/// most three.
+///
+/// # Type Parameters
+/// - `K`: The type of the keys, which order the entries.
+/// - `V`: The type of the values.
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::red_black_tree::RedBlackTree;
+///
+/// let mut tree = RedBlackTree::new();
+/// for i in 0..1000 {
+///     tree.insert(i, i * i);
+/// }
+/// assert_eq!(tree.get(&12), Some(&144));
+/// assert_eq!(tree.remove(&12), Some(144));
+///
+/// let keys: Vec<_> = tree.range(10..15).map(|(key, _)| *key).collect();
+/// assert_eq!(keys, vec![10, 11, 13, 14]);
+/// assert!(tree.check_invariants().is_ok());
+/// ```
+pub struct RedBlackTree<K, V> {
+    nodes: SearchTree<K, V, Color>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Color {
+    Red,
+    Black,
+}
+
+/// A node of the [`RedBlackTree`], whose `meta` is its color.
+type Node<K, V> = search_tree::Node<K, V, Color>;
+
+/// Borrowing in-order iterator over the entries of a [`RedBlackTree`] within
+/// a range, from the smallest key to the greatest one.
+pub struct Range<'a, K, V>(search_tree::Range<'a, K, V, Color>);
+
+impl<K, V> Node<K, V> {
+    /// Checks if `node` is red, an empty subtree counting as black.
+    unsafe fn is_red(node: Option<*mut Node<K, V>>) -> bool {
+        node.is_some_and(|node| (*node).meta == Color::Red)
+    }
+}
+
+impl<K: Ord, V> RedBlackTree<K, V> {
+    /// Creates a new empty `RedBlackTree`.
+    pub fn new() -> RedBlackTree<K, V> {
+        RedBlackTree {
+            nodes: SearchTree::new(),
+        }
+    }
+
+    /// Gets the amount of entries in the tree.
+    pub fn len(&self) -> usize {
+        self.nodes.length
+    }
+
+    /// Checks if the tree is empty.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.length == 0
+    }
+
+    /// Retrieves a reference to the value of `key`, if it is in the tree.
+    ///
+    /// # Complexity
+    /// - `O(log n)`
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.nodes
+            .find(key)
+            .map(|node| unsafe { &(*node).data.value })
+    }
+
+    /// Retrieves a mutable reference to the value of `key`, if it is in the tree.
+    ///
+    /// # Complexity
+    /// - `O(log n)`
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.nodes
+            .find(key)
+            .map(|node| unsafe { &mut (*node).data.value })
+    }
+
+    /// Checks if `key` is in the tree.
+    ///
+    /// # Complexity
+    /// - `O(log n)`
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.nodes.find(key).is_some()
+    }
+
+    /// Retrieves the entry with the smallest key, if the tree is not empty.
+    ///
+    /// # Complexity
+    /// - `O(log n)`
+    pub fn first_key_value(&self) -> Option<(&K, &V)> {
+        self.nodes
+            .first()
+            .map(|node| unsafe { (*node).data.pair() })
+    }
+
+    /// Retrieves the entry with the greatest key, if the tree is not empty.
+    ///
+    /// # Complexity
+    /// - `O(log n)`
+    pub fn last_key_value(&self) -> Option<(&K, &V)> {
+        self.nodes.last().map(|node| unsafe { (*node).data.pair() })
+    }
+
+    /// Inserts `value` under `key`, as a red node, then recolors and rotates
+    /// the nodes above it until no red node has a red parent.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(V)`: The value replaced, if `key` was already in the tree.
+    ///   The key itself is not replaced.
+    /// - `None`: If `key` was inserted.
+    ///
+    /// # Complexity
+    /// - `O(log n)`, with at most two rotations
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let place = self.nodes.search(&key);
+        unsafe {
+            if let Some((node, Ordering::Equal)) = place {
+                return Some(std::mem::replace(&mut (*node).data.value, value));
+            }
+            let node = self.nodes.link_leaf(place, key, value, Color::Red);
+            self.fix_insert(node);
+        }
+        None
+    }
+
+    /// Removes `key` from the tree, then recolors and rotates the nodes
+    /// above it if a black node was taken out of its paths.
+    ///
+    /// The color that matters is the one of the node taken out, which is the
+    /// node of the successor of `key` when `key` has two children.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(V)`: The value of the removed entry.
+    /// - `None`: If `key` was not in the tree.
+    ///
+    /// # Complexity
+    /// - `O(log n)`, with at most three rotations
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let node = self.nodes.find(key)?;
+        unsafe {
+            let (node, child, parent) = self.nodes.unlink(node);
+            if node.meta == Color::Black {
+                match child {
+                    // The only child of a black node is red, and taking its
+                    // place as a black node restores the paths
+                    Some(child) if (*child).meta == Color::Red => (*child).meta = Color::Black,
+                    _ => self.fix_remove(child, parent),
+                }
+            }
+            Some(node.data.value)
+        }
+    }
+
+    /// Returns an in-order iterator over the entries with a key within
+    /// `range`, from the smallest key to the greatest one.
+    ///
+    /// A range whose start is past its end is empty.
+    ///
+    /// # Complexity
+    /// - `O(log n)` to find the range, then `O(m)` for the whole traversal,
+    ///   where `m` is the number of entries in the range
+    pub fn range<R: RangeBounds<K>>(&self, range: R) -> Range<'_, K, V> {
+        Range(self.nodes.range(range))
+    }
+
+    /// Returns an in-order iterator over every entry of the tree.
+    pub fn iter(&self) -> Range<'_, K, V> {
+        self.range(..)
+    }
+
+    /// Checks the rules of the red-black tree: the keys are in order, every
+    /// child links back to its parent, the root is black, no red node has a
+    /// red child and every path down to an empty subtree has the same number
+    /// of black nodes.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(usize)`: The black height, the number of black nodes on every
+    ///   path from the root down to an empty subtree.
+    ///
+    /// # Errors
+    ///
+    /// - `"Keys are not in order"`
+    /// - `"Broken parent link"`
+    /// - `"Root is red"`
+    /// - `"Red node with a red child"`
+    /// - `"Paths with different black heights"`
+    /// - `"Length does not match the nodes"`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::red_black_tree::RedBlackTree;
+    ///
+    /// let tree: RedBlackTree<i32, ()> = (0..7).map(|key| (key, ())).collect();
+    /// assert_eq!(tree.check_invariants(), Ok(2));
+    /// ```
+    ///
+    /// # Complexity
+    /// - `O(n)`
+    pub fn check_invariants(&self) -> Result<usize, &'static str> {
+        let Some(root) = self.nodes.root else {
+            return if self.nodes.length == 0 {
+                Ok(0)
+            } else {
+                Err("Length does not match the nodes")
+            };
+        };
+        unsafe {
+            if (*root).parent.is_some() {
+                return Err("Broken parent link");
+            }
+            if (*root).meta == Color::Red {
+                return Err("Root is red");
+            }
+            let (black_height, count) = Self::check_subtree(root, None, None)?;
+            if count != self.nodes.length {
+                return Err("Length does not match the nodes");
+            }
+            Ok(black_height)
+        }
+    }
+
+    /// Checks the subtree of `node`, whose keys must be strictly between
+    /// `lower` and `upper`, returning its black height and its size.
+    unsafe fn check_subtree(
+        node: *mut Node<K, V>,
+        lower: Option<&K>,
+        upper: Option<&K>,
+    ) -> Result<(usize, usize), &'static str> {
+        let key = &(*node).data.key;
+        if lower.is_some_and(|lower| key <= lower) || upper.is_some_and(|upper| key >= upper) {
+            return Err("Keys are not in order");
+        }
+        let mut heights = [0, 0];
+        let mut count = 1;
+        for (side, child, lower, upper) in [
+            (0, (*node).left, lower, Some(key)),
+            (1, (*node).right, Some(key), upper),
+        ] {
+            let Some(child) = child else {
+                continue;
+            };
+            if (*child).parent != Some(node) {
+                return Err("Broken parent link");
+            }
+            if (*node).meta == Color::Red && (*child).meta == Color::Red {
+                return Err("Red node with a red child");
+            }
+            let (height, size) = Self::check_subtree(child, lower, upper)?;
+            heights[side] = height;
+            count += size;
+        }
+        if heights[0] != heights[1] {
+            return Err("Paths with different black heights");
+        }
+        let own = usize::from((*node).meta == Color::Black);
+        Ok((heights[0] + own, count))
+    }
+
+    /// Restores the rules after the red `node` was inserted, while it may
+    /// have a red parent.
+    unsafe fn fix_insert(&mut self, mut node: *mut Node<K, V>) {
+        while let Some(mut parent) = (*node).parent.filter(|&parent| Node::is_red(Some(parent))) {
+            let grandparent = (*parent).parent.expect("a red node is not the root");
+            if (*grandparent).left == Some(parent) {
+                let uncle = (*grandparent).right;
+                if let Some(uncle) = uncle.filter(|&uncle| Node::is_red(Some(uncle))) {
+                    // Red uncle: push the blackness of the grandparent down
+                    // and go on from the grandparent
+                    (*parent).meta = Color::Black;
+                    (*uncle).meta = Color::Black;
+                    (*grandparent).meta = Color::Red;
+                    node = grandparent;
+                    continue;
+                }
+                if (*parent).right == Some(node) {
+                    // Inner child: turn it into an outer one
+                    self.nodes.rotate_left(parent);
+                    node = parent;
+                    parent = (*node).parent.expect("the rotation gave it a parent");
+                }
+                // Outer child: lift the parent above the grandparent
+                (*parent).meta = Color::Black;
+                (*grandparent).meta = Color::Red;
+                self.nodes.rotate_right(grandparent);
+            } else {
+                let uncle = (*grandparent).left;
+                if let Some(uncle) = uncle.filter(|&uncle| Node::is_red(Some(uncle))) {
+                    (*parent).meta = Color::Black;
+                    (*uncle).meta = Color::Black;
+                    (*grandparent).meta = Color::Red;
+                    node = grandparent;
+                    continue;
+                }
+                if (*parent).left == Some(node) {
+                    self.nodes.rotate_right(parent);
+                    node = parent;
+                    parent = (*node).parent.expect("the rotation gave it a parent");
+                }
+                (*parent).meta = Color::Black;
+                (*grandparent).meta = Color::Red;
+                self.nodes.rotate_left(grandparent);
+            }
+        }
+        if let Some(root) = self.nodes.root {
+            (*root).meta = Color::Black;
+        }
+    }
+
+    /// Restores the rules after a black node was removed from the paths
+    /// through `node`, a child of `parent` that may be empty. Until it is
+    /// fixed, `node` counts as "doubly black", one black short of its sibling.
+    unsafe fn fix_remove(
+        &mut self,
+        mut node: Option<*mut Node<K, V>>,
+        mut parent: Option<*mut Node<K, V>>,
+    ) {
+        while node != self.nodes.root && !Node::is_red(node) {
+            let current = parent.expect("a node other than the root has a parent");
+            // The sibling is not empty, as its paths have one more black node
+            if (*current).left == node {
+                let mut sibling = (*current).right.expect("a doubly black node has a sibling");
+                if (*sibling).meta == Color::Red {
+                    // Case 1, red sibling: rotate it up to get a black sibling
+                    (*sibling).meta = Color::Black;
+                    (*current).meta = Color::Red;
+                    self.nodes.rotate_left(current);
+                    sibling = (*current).right.expect("a doubly black node has a sibling");
+                }
+                if !Node::is_red((*sibling).left) && !Node::is_red((*sibling).right) {
+                    // Case 2, black nephews: make the sibling red and move
+                    // the missing black up to the parent
+                    (*sibling).meta = Color::Red;
+                    node = Some(current);
+                    parent = (*current).parent;
+                    continue;
+                }
+                if !Node::is_red((*sibling).right) {
+                    // Case 3, only the inner nephew is red: rotate it up to
+                    // make the outer nephew red
+                    let nephew = (*sibling).left.expect("the inner nephew is red");
+                    (*nephew).meta = Color::Black;
+                    (*sibling).meta = Color::Red;
+                    self.nodes.rotate_right(sibling);
+                    sibling = nephew;
+                }
+                // Case 4, red outer nephew: rotate the sibling up and paint
+                // the nephew black, adding the missing black to `node`
+                (*sibling).meta = (*current).meta;
+                (*current).meta = Color::Black;
+                if let Some(nephew) = (*sibling).right {
+                    (*nephew).meta = Color::Black;
+                }
+                self.nodes.rotate_left(current);
+            } else {
+                let mut sibling = (*current).left.expect("a doubly black node has a sibling");
+                if (*sibling).meta == Color::Red {
+                    (*sibling).meta = Color::Black;
+                    (*current).meta = Color::Red;
+                    self.nodes.rotate_right(current);
+                    sibling = (*current).left.expect("a doubly black node has a sibling");
+                }
+                if !Node::is_red((*sibling).left) && !Node::is_red((*sibling).right) {
+                    (*sibling).meta = Color::Red;
+                    node = Some(current);
+                    parent = (*current).parent;
+                    continue;
+                }
+                if !Node::is_red((*sibling).left) {
+                    let nephew = (*sibling).right.expect("the inner nephew is red");
+                    (*nephew).meta = Color::Black;
+                    (*sibling).meta = Color::Red;
+                    self.nodes.rotate_left(sibling);
+                    sibling = nephew;
+                }
+                (*sibling).meta = (*current).meta;
+                (*current).meta = Color::Black;
+                if let Some(nephew) = (*sibling).left {
+                    (*nephew).meta = Color::Black;
+                }
+                self.nodes.rotate_right(current);
+            }
+            node = self.nodes.root;
+            break;
+        }
+        if let Some(node) = node {
+            (*node).meta = Color::Black;
+        }
+    }
+}
+
+impl<K: Ord, V> Default for RedBlackTree<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for RedBlackTree<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut tree = RedBlackTree::new();
+        for (key, value) in iter {
+            tree.insert(key, value);
+        }
+        tree
+    }
+}
+
+impl<'a, K: Ord, V> IntoIterator for &'a RedBlackTree<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Range<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V> Iterator for Range<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(Entry::pair)
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Range<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(Entry::pair)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search_tree::tests::{self as shared, TestMap};
+
+    impl TestMap for RedBlackTree<i32, i32> {
+        fn insert(&mut self, key: i32, value: i32) -> Option<i32> {
+            RedBlackTree::insert(self, key, value)
+        }
+
+        fn remove(&mut self, key: i32) -> Option<i32> {
+            RedBlackTree::remove(self, &key)
+        }
+
+        fn get(&mut self, key: i32) -> Option<i32> {
+            RedBlackTree::get(self, &key).copied()
+        }
+
+        fn first_and_last(&mut self) -> Option<(i32, i32)> {
+            Some((*self.first_key_value()?.0, *self.last_key_value()?.0))
+        }
+
+        fn len(&self) -> usize {
+            RedBlackTree::len(self)
+        }
+
+        fn check(&self) -> Vec<i32> {
+            self.check_invariants().unwrap();
+            keys(self)
+        }
+    }
+
+    fn keys<K: Ord + Copy, V>(tree: &RedBlackTree<K, V>) -> Vec<K> {
+        tree.iter().map(|(key, _)| *key).collect()
+    }
+
+    /// The height of the tree, counted in nodes.
+    fn height<K, V>(tree: &RedBlackTree<K, V>) -> usize {
+        let mut deepest = 0;
+        let mut stack: Vec<(*mut Node<K, V>, usize)> =
+            tree.nodes.root.into_iter().map(|root| (root, 1)).collect();
+        while let Some((node, depth)) = stack.pop() {
+            deepest = deepest.max(depth);
+            unsafe {
+                for child in [(*node).left, (*node).right].into_iter().flatten() {
+                    stack.push((child, depth + 1));
+                }
+            }
+        }
+        deepest
+    }
+
+    /// Builds a node by hand, to break the rules on purpose.
+    fn attach(
+        tree: &mut RedBlackTree<i32, ()>,
+        parent: Option<*mut Node<i32, ()>>,
+        key: i32,
+        color: Color,
+    ) -> *mut Node<i32, ()> {
+        let place = parent.map(|parent| (parent, key.cmp(unsafe { &(*parent).data.key })));
+        unsafe { tree.nodes.link_leaf(place, key, (), color) }
+    }
+
+    #[test]
+    fn test_insert_fix_up_cases() {
+        // Both outer and inner children, on both sides, end up with the
+        // middle key as a black root and two red children
+        for order in [[3, 2, 1], [1, 2, 3], [3, 1, 2], [1, 3, 2]] {
+            let tree: RedBlackTree<i32, ()> = order.into_iter().map(|key| (key, ())).collect();
+            assert_eq!(tree.check_invariants(), Ok(1));
+            unsafe {
+                let root = tree.nodes.root.unwrap();
+                assert_eq!((*root).data.key, 2);
+                assert!(Node::is_red((*root).left) && Node::is_red((*root).right));
+            }
+        }
+
+        // A red uncle only recolors
+        let tree: RedBlackTree<i32, ()> = [2, 1, 3, 4].into_iter().map(|key| (key, ())).collect();
+        assert_eq!(tree.check_invariants(), Ok(2));
+        unsafe {
+            assert_eq!((*tree.nodes.root.unwrap()).data.key, 2);
+        }
+    }
+
+    #[test]
+    fn test_remove_fix_up_cases() {
+        // Red sibling (case 1) then black nephews (case 2)
+        let mut tree = RedBlackTree::new();
+        let root = attach(&mut tree, None, 2, Color::Black);
+        attach(&mut tree, Some(root), 1, Color::Black);
+        let sibling = attach(&mut tree, Some(root), 4, Color::Red);
+        attach(&mut tree, Some(sibling), 3, Color::Black);
+        attach(&mut tree, Some(sibling), 5, Color::Black);
+        assert_eq!(tree.check_invariants(), Ok(2));
+        tree.remove(&1);
+        assert_eq!(tree.check_invariants(), Ok(2));
+        assert_eq!(keys(&tree), vec![2, 3, 4, 5]);
+
+        // Black sibling with black nephews pushes the black up (case 2)
+        let mut tree: RedBlackTree<i32, ()> = (1..=3).map(|key| (key, ())).collect();
+        unsafe {
+            (*(*tree.nodes.root.unwrap()).right.unwrap()).meta = Color::Black;
+            (*(*tree.nodes.root.unwrap()).left.unwrap()).meta = Color::Black;
+        }
+        tree.remove(&1);
+        assert_eq!(tree.check_invariants(), Ok(1));
+        assert_eq!(keys(&tree), vec![2, 3]);
+
+        // Red inner nephew (case 3) then red outer nephew (case 4), on both
+        // sides
+        for removed in [1, 5] {
+            let mut tree = RedBlackTree::new();
+            let (root_key, sibling_key) = if removed == 1 { (2, 4) } else { (4, 2) };
+            let root = attach(&mut tree, None, root_key, Color::Black);
+            attach(&mut tree, Some(root), removed, Color::Black);
+            let sibling = attach(&mut tree, Some(root), sibling_key, Color::Black);
+            attach(&mut tree, Some(sibling), 3, Color::Red);
+            assert_eq!(tree.check_invariants(), Ok(2));
+            tree.remove(&removed);
+            assert_eq!(tree.check_invariants(), Ok(2));
+            unsafe {
+                assert_eq!((*tree.nodes.root.unwrap()).data.key, 3);
+            }
+        }
+
+        // Removing a black node with a red child only recolors the child
+        let mut tree: RedBlackTree<i32, ()> =
+            [2, 1, 3, 4].into_iter().map(|key| (key, ())).collect();
+        tree.remove(&3);
+        assert_eq!(tree.check_invariants(), Ok(2));
+        assert_eq!(keys(&tree), vec![1, 2, 4]);
+    }
+
+    #[test]
+    fn test_check_invariants_errors() {
+        let mut tree = RedBlackTree::new();
+        let root = attach(&mut tree, None, 2, Color::Red);
+        assert_eq!(tree.check_invariants(), Err("Root is red"));
+
+        unsafe { (*root).meta = Color::Black };
+        attach(&mut tree, Some(root), 1, Color::Black);
+        assert_eq!(
+            tree.check_invariants(),
+            Err("Paths with different black heights")
+        );
+
+        let right = attach(&mut tree, Some(root), 3, Color::Red);
+        let grandchild = attach(&mut tree, Some(right), 4, Color::Red);
+        assert_eq!(tree.check_invariants(), Err("Red node with a red child"));
+
+        unsafe {
+            (*right).meta = Color::Black;
+            (*grandchild).data.key = 0;
+        }
+        assert_eq!(tree.check_invariants(), Err("Keys are not in order"));
+
+        unsafe {
+            (*grandchild).data.key = 4;
+            (*grandchild).parent = Some(root);
+        }
+        assert_eq!(tree.check_invariants(), Err("Broken parent link"));
+
+        unsafe { (*grandchild).parent = Some(right) };
+        assert_eq!(tree.check_invariants(), Ok(2));
+
+        tree.nodes.length += 1;
+        assert_eq!(
+            tree.check_invariants(),
+            Err("Length does not match the nodes")
+        );
+        tree.nodes.length -= 1;
+    }
+
+    #[test]
+    fn test_map_operations() {
+        shared::check_replaced_values::<RedBlackTree<i32, i32>>();
+        shared::check_mixed_writes::<RedBlackTree<i32, i32>>();
+    }
+
+    #[test]
+    fn test_sorted_writes_stay_balanced() {
+        // At most 2 log2(n + 1)
+        let tree: RedBlackTree<i32, i32> = shared::write_in_order(0..10_000, []);
+        assert!(height(&tree) <= 26);
+
+        let removed = (0..2048).rev().filter(|key| key % 3 != 0);
+        let tree: RedBlackTree<i32, i32> = shared::write_in_order(0..2048, removed);
+        assert_eq!(tree.len(), 683);
+        assert!(height(&tree) <= 18);
+    }
+}
@@ -0,0 +1,436 @@
+//! The binary search tree under [`AvlTree`](crate::avl_tree::AvlTree),
+//! [`RedBlackTree`](crate::red_black_tree::RedBlackTree) and
+//! [`SplayTree`](crate::splay_tree::SplayTree).
+//!
+//! The trees are built on the parent-linked [`binary_tree::Node`], holding
+//! an [`Entry`] as data, so they share its navigation, bounds and range
+//! iterator with [`BinaryTree`](crate::binary_tree::BinaryTree). Each tree
+//! keeps its own balance information in the `meta` field of the nodes, the
+//! height for the AVL tree, the color for the red-black tree and the size
+//! of the subtree for the splay tree, and adds its own rebalancing on top of
+//! the searches and rotations here.
+
+use crate::binary_tree::{self, range};
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+use std::ops::RangeBounds;
+
+/// An entry of a map, ordered by its key alone.
+pub(crate) struct Entry<K, V> {
+    pub(crate) key: K,
+    pub(crate) value: V,
+}
+
+/// A node of a map, with the balance information `meta` of its tree.
+pub(crate) type Node<K, V, M> = binary_tree::Node<Entry<K, V>, M>;
+
+/// Borrowing in-order iterator over the entries of a map between two nodes.
+pub(crate) type Range<'a, K, V, M> = range::Range<'a, Entry<K, V>, M>;
+
+/// The nodes of a tree, owned through its root.
+pub(crate) struct SearchTree<K, V, M> {
+    pub(crate) root: Option<*mut Node<K, V, M>>,
+    pub(crate) length: usize,
+    marker: PhantomData<Box<Node<K, V, M>>>,
+}
+
+/// A node taken out by [`SearchTree::unlink`], with the child that took its
+/// place and their parent.
+type Unlinked<K, V, M> = (
+    Box<Node<K, V, M>>,
+    Option<*mut Node<K, V, M>>,
+    Option<*mut Node<K, V, M>>,
+);
+
+impl<K, V> Entry<K, V> {
+    pub(crate) fn pair(&self) -> (&K, &V) {
+        (&self.key, &self.value)
+    }
+}
+
+// Lets the shared bounds and range iterator compare entries by key
+impl<K, V> Borrow<K> for Entry<K, V> {
+    fn borrow(&self) -> &K {
+        &self.key
+    }
+}
+
+impl<K, V, M> SearchTree<K, V, M> {
+    /// Creates a new empty `SearchTree`.
+    pub(crate) fn new() -> SearchTree<K, V, M> {
+        SearchTree {
+            root: None,
+            length: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// Finds the node with the smallest key, if the tree is not empty.
+    pub(crate) fn first(&self) -> Option<*mut Node<K, V, M>> {
+        self.root.map(|root| unsafe { Node::leftmost(root) })
+    }
+
+    /// Finds the node with the greatest key, if the tree is not empty.
+    pub(crate) fn last(&self) -> Option<*mut Node<K, V, M>> {
+        self.root.map(|root| unsafe { Node::rightmost(root) })
+    }
+
+    /// Points the link from `parent` to `old`, or the root if there is no
+    /// parent, at `new`. The parent link of `new` is left to the caller.
+    pub(crate) unsafe fn replace_child(
+        &mut self,
+        parent: Option<*mut Node<K, V, M>>,
+        old: *mut Node<K, V, M>,
+        new: Option<*mut Node<K, V, M>>,
+    ) {
+        match parent {
+            None => self.root = new,
+            Some(parent) if (*parent).left == Some(old) => (*parent).left = new,
+            Some(parent) => (*parent).right = new,
+        }
+    }
+
+    /// Rotates `node` down to the left, lifting its right child into its
+    /// place, and returns that child. The `meta` of both is left to the
+    /// caller.
+    ///
+    /// ```text
+    ///   node             right
+    ///   /  \             /   \
+    ///  a   right  =>   node   c
+    ///      /   \       /  \
+    ///     b     c     a    b
+    /// ```
+    pub(crate) unsafe fn rotate_left(&mut self, node: *mut Node<K, V, M>) -> *mut Node<K, V, M> {
+        let right = (*node).right.expect("rotating left needs a right child");
+        (*node).right = (*right).left;
+        if let Some(inner) = (*right).left {
+            (*inner).parent = Some(node);
+        }
+        (*right).parent = (*node).parent;
+        self.replace_child((*node).parent, node, Some(right));
+        (*right).left = Some(node);
+        (*node).parent = Some(right);
+        right
+    }
+
+    /// Rotates `node` down to the right, lifting its left child into its
+    /// place, and returns that child. The mirror of [`rotate_left`](Self::rotate_left).
+    pub(crate) unsafe fn rotate_right(&mut self, node: *mut Node<K, V, M>) -> *mut Node<K, V, M> {
+        let left = (*node).left.expect("rotating right needs a left child");
+        (*node).left = (*left).right;
+        if let Some(inner) = (*left).right {
+            (*inner).parent = Some(node);
+        }
+        (*left).parent = (*node).parent;
+        self.replace_child((*node).parent, node, Some(left));
+        (*left).right = Some(node);
+        (*node).parent = Some(left);
+        left
+    }
+
+    /// Takes `node` out of the tree and returns it, with the child that took
+    /// its place and their parent.
+    ///
+    /// A node with two children first swaps its entry with its successor,
+    /// whose node is taken out instead, so the returned node always holds
+    /// the entry of `node` and had at most one child.
+    pub(crate) unsafe fn unlink(&mut self, mut node: *mut Node<K, V, M>) -> Unlinked<K, V, M> {
+        if let (Some(_), Some(right)) = ((*node).left, (*node).right) {
+            let successor = Node::leftmost(right);
+            std::mem::swap(&mut (*node).data, &mut (*successor).data);
+            node = successor;
+        }
+
+        let child = (*node).left.or((*node).right);
+        let parent = (*node).parent;
+        if let Some(child) = child {
+            (*child).parent = parent;
+        }
+        self.replace_child(parent, node, child);
+        self.length -= 1;
+        (Box::from_raw(node), child, parent)
+    }
+}
+
+impl<K: Ord, V, M> SearchTree<K, V, M> {
+    /// Walks down from the root towards `key` and returns the last node
+    /// reached, with how `key` compares to its key: `Equal` if the node
+    /// holds `key`, otherwise the side where `key` would hang below it.
+    pub(crate) fn search(&self, key: &K) -> Option<(*mut Node<K, V, M>, Ordering)> {
+        let mut node = self.root?;
+        loop {
+            let ordering = key.cmp(unsafe { &(*node).data.key });
+            let next = match ordering {
+                Ordering::Less => unsafe { (*node).left },
+                Ordering::Greater => unsafe { (*node).right },
+                Ordering::Equal => None,
+            };
+            let Some(next) = next else {
+                return Some((node, ordering));
+            };
+            node = next;
+        }
+    }
+
+    pub(crate) fn find(&self, key: &K) -> Option<*mut Node<K, V, M>> {
+        match self.search(key) {
+            Some((node, Ordering::Equal)) => Some(node),
+            _ => None,
+        }
+    }
+
+    /// Links a new leaf at the place returned by [`search`](Self::search)
+    /// for its key, which must not be `Equal`, and returns it.
+    pub(crate) unsafe fn link_leaf(
+        &mut self,
+        place: Option<(*mut Node<K, V, M>, Ordering)>,
+        key: K,
+        value: V,
+        meta: M,
+    ) -> *mut Node<K, V, M> {
+        let node = Box::into_raw(Box::new(Node {
+            data: Entry { key, value },
+            meta,
+            parent: place.map(|(parent, _)| parent),
+            left: None,
+            right: None,
+        }));
+        match place {
+            None => self.root = Some(node),
+            Some((parent, Ordering::Less)) => (*parent).left = Some(node),
+            Some((parent, _)) => (*parent).right = Some(node),
+        }
+        self.length += 1;
+        node
+    }
+
+    /// Returns an in-order iterator over the entries with a key within
+    /// `range`, empty if its start is past its end.
+    pub(crate) fn range<R: RangeBounds<K>>(&self, range: R) -> Range<'_, K, V, M> {
+        unsafe { Range::new(self.root, range) }
+    }
+}
+
+impl<K, V, M> Drop for SearchTree<K, V, M> {
+    fn drop(&mut self) {
+        unsafe { Node::free_subtree(self.root.take()) };
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::rc::Rc;
+
+    /// The map operations of a tree, to run the same scenarios on each one.
+    pub(crate) trait TestMap: Default {
+        fn insert(&mut self, key: i32, value: i32) -> Option<i32>;
+        fn remove(&mut self, key: i32) -> Option<i32>;
+        fn get(&mut self, key: i32) -> Option<i32>;
+        fn first_and_last(&mut self) -> Option<(i32, i32)>;
+        fn len(&self) -> usize;
+        /// Checks the links and the own rules of the tree, panicking if one
+        /// is broken, and returns the keys in order.
+        fn check(&self) -> Vec<i32>;
+    }
+
+    /// Checks that every child links back to its parent, that the keys are
+    /// in order and that the length counts every node, calling `check_node`
+    /// on each node. Returns the keys in order.
+    pub(crate) fn assert_links<K: Ord + Copy, V, M>(
+        tree: &SearchTree<K, V, M>,
+        mut check_node: impl FnMut(*mut Node<K, V, M>),
+    ) -> Vec<K> {
+        let mut count = 0;
+        let mut stack: Vec<*mut Node<K, V, M>> = tree.root.into_iter().collect();
+        unsafe {
+            if let Some(root) = tree.root {
+                assert_eq!((*root).parent, None);
+            }
+            while let Some(node) = stack.pop() {
+                count += 1;
+                for child in [(*node).left, (*node).right].into_iter().flatten() {
+                    assert_eq!((*child).parent, Some(node));
+                    stack.push(child);
+                }
+                check_node(node);
+            }
+        }
+        assert_eq!(count, tree.length);
+
+        let keys: Vec<K> = tree.range(..).map(|entry| entry.key).collect();
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(keys.len(), tree.length);
+        keys
+    }
+
+    /// Inserts a permutation of `0..1000`, then removes every other key of
+    /// it and the rest in order, checking the tree along the way.
+    pub(crate) fn check_mixed_writes<T: TestMap>() {
+        let mut tree = T::default();
+        assert_eq!(tree.remove(1), None);
+        assert_eq!(tree.first_and_last(), None);
+
+        let keys: Vec<i32> = (0..1000).map(|i| (i * 389) % 1000).collect();
+        for &key in &keys {
+            assert_eq!(tree.insert(key, key * 2), None);
+        }
+        assert_eq!(tree.check(), (0..1000).collect::<Vec<_>>());
+        assert_eq!(tree.first_and_last(), Some((0, 999)));
+
+        for (position, &key) in keys.iter().enumerate().step_by(2) {
+            assert_eq!(tree.remove(key), Some(key * 2));
+            assert_eq!(tree.remove(key), None);
+            if position % 100 == 0 {
+                tree.check();
+            }
+        }
+        let mut rest: Vec<i32> = keys.iter().skip(1).step_by(2).copied().collect();
+        rest.sort();
+        assert_eq!(tree.check(), rest);
+
+        for &key in &rest {
+            assert_eq!(tree.get(key), Some(key * 2));
+            assert_eq!(tree.remove(key), Some(key * 2));
+        }
+        assert_eq!(tree.len(), 0);
+        assert_eq!(tree.check(), Vec::<i32>::new());
+    }
+
+    /// Inserting a key again replaces its value only.
+    pub(crate) fn check_replaced_values<T: TestMap>() {
+        let mut tree = T::default();
+        assert_eq!(tree.insert(7, 1), None);
+        assert_eq!(tree.insert(7, 2), Some(1));
+        assert_eq!(tree.len(), 1);
+        assert_eq!(tree.get(7), Some(2));
+        assert_eq!(tree.get(8), None);
+        assert_eq!(tree.check(), vec![7]);
+    }
+
+    /// Inserts `inserted` and then removes `removed`, in the given orders,
+    /// checking the tree every 1000 writes, and returns it for the caller
+    /// to look at its shape.
+    pub(crate) fn write_in_order<T: TestMap>(
+        inserted: impl IntoIterator<Item = i32>,
+        removed: impl IntoIterator<Item = i32>,
+    ) -> T {
+        let mut tree = T::default();
+        let mut expected = Vec::new();
+        for key in inserted {
+            assert_eq!(tree.insert(key, -key), None);
+            expected.push(key);
+            if expected.len() % 1000 == 0 {
+                tree.check();
+            }
+        }
+        for (count, key) in removed.into_iter().enumerate() {
+            assert_eq!(tree.remove(key), Some(-key));
+            if count % 1000 == 0 {
+                tree.check();
+            }
+            expected.retain(|&other| other != key);
+        }
+        expected.sort();
+        assert_eq!(tree.check(), expected);
+        tree
+    }
+
+    /// Builds a tree without any balancing, in the shape given by the order
+    /// of `keys`.
+    fn tree_of(keys: impl IntoIterator<Item = i32>) -> SearchTree<i32, i32, ()> {
+        let mut tree = SearchTree::new();
+        for key in keys {
+            let place = tree.search(&key);
+            assert!(place.is_none_or(|(_, ordering)| ordering != Ordering::Equal));
+            unsafe { tree.link_leaf(place, key, key / 2, ()) };
+        }
+        tree
+    }
+
+    #[test]
+    fn test_search_and_find() {
+        let tree = tree_of([4, 2, 6, 1, 3]);
+        unsafe {
+            let (node, ordering) = tree.search(&5).unwrap();
+            assert_eq!(((*node).data.key, ordering), (6, Ordering::Less));
+            let (node, ordering) = tree.search(&7).unwrap();
+            assert_eq!(((*node).data.key, ordering), (6, Ordering::Greater));
+            assert_eq!((*tree.find(&3).unwrap()).data.key, 3);
+        }
+        assert!(tree.find(&5).is_none());
+        assert!(SearchTree::<i32, i32, ()>::new().search(&1).is_none());
+    }
+
+    #[test]
+    fn test_range() {
+        // The shared range iterator, searched by key
+        let tree = tree_of((0..100).map(|i| (i * 37) % 100 * 2));
+        let keys =
+            |range: Range<'_, i32, i32, ()>| range.map(|entry| entry.key).collect::<Vec<_>>();
+
+        assert_eq!(keys(tree.range(11..=20)), vec![12, 14, 16, 18, 20]);
+        assert_eq!(keys(tree.range(13..14)), Vec::<i32>::new());
+        assert_eq!(tree.range(..).count(), 100);
+
+        let mut range = tree.range(10..=16);
+        assert_eq!(range.next_back().map(Entry::pair), Some((&16, &8)));
+        assert_eq!(range.next().map(Entry::pair), Some((&10, &5)));
+        assert_eq!(keys(range), vec![12, 14]);
+    }
+
+    #[test]
+    fn test_rotations() {
+        let mut tree = tree_of([2, 1, 4, 3, 5]);
+        unsafe {
+            let root = tree.root.unwrap();
+            let lifted = tree.rotate_left(root);
+            assert_eq!((*lifted).data.key, 4);
+            assert_eq!(tree.root, Some(lifted));
+            assert_eq!((*(*root).right.unwrap()).data.key, 3);
+            assert_links(&tree, |_| {});
+
+            assert_eq!(tree.rotate_right(lifted), root);
+            assert_eq!(tree.root, Some(root));
+            assert_eq!(assert_links(&tree, |_| {}), vec![1, 2, 3, 4, 5]);
+        }
+    }
+
+    #[test]
+    fn test_unlink() {
+        let mut tree = tree_of([50, 20, 80, 10, 30, 25]);
+        unsafe {
+            // A node with two children gives its entry to its successor's node
+            let node = tree.find(&20).unwrap();
+            let (removed, child, parent) = tree.unlink(node);
+            assert_eq!((removed.data.key, removed.data.value), (20, 10));
+            assert_eq!((*node).data.key, 25);
+            assert_eq!(child, None);
+            assert_eq!(parent, tree.find(&30));
+
+            let (removed, child, parent) = tree.unlink(tree.find(&80).unwrap());
+            assert_eq!((removed.data.key, child, parent), (80, None, tree.root));
+
+            let (removed, child, parent) = tree.unlink(tree.root.unwrap());
+            assert_eq!((removed.data.key, child, parent), (50, tree.root, None));
+        }
+        assert_eq!(assert_links(&tree, |_| {}), vec![10, 25, 30]);
+    }
+
+    #[test]
+    fn test_drop_frees_every_value() {
+        let counter = Rc::new(());
+        let mut tree = SearchTree::new();
+        for key in (0..100).map(|i| (i * 37) % 100) {
+            let place = tree.search(&key);
+            unsafe { tree.link_leaf(place, key, Rc::clone(&counter), ()) };
+        }
+        let node = tree.find(&50).unwrap();
+        drop(unsafe { tree.unlink(node) });
+        assert_eq!(Rc::strong_count(&counter), 100);
+        drop(tree);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+}
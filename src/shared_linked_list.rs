@@ -0,0 +1,501 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::{Rc, Weak};
+
+/// A node of a singly linked list whose tail can be shared between lists.
+///
+/// Unlike [`crate::linked_list::ListNode`], the next node is held by an `Rc`,
+/// so several heads can point into the same chain. Nodes are immutable once
+/// built, which keeps the shared chains acyclic.
+pub struct ListNode<T> {
+    pub data: T,
+    pub next: Link<T>,
+}
+
+/// An optional shared pointer to a [`ListNode`]. `None` marks the end of the list.
+pub type Link<T> = Option<Rc<ListNode<T>>>;
+
+impl<T> ListNode<T> {
+    pub fn new(value: T) -> ListNode<T> {
+        ListNode {
+            data: value,
+            next: None,
+        }
+    }
+}
+
+// Dropping the default way recurses once per node. Nodes are unlinked one by
+// one instead, stopping at the first node still referenced by another list.
+impl<T> Drop for ListNode<T> {
+    fn drop(&mut self) {
+        let mut next = self.next.take();
+        while let Some(node) = next {
+            match Rc::try_unwrap(node) {
+                Ok(mut node) => next = node.next.take(),
+                Err(_) => break,
+            }
+        }
+    }
+}
+
+pub fn length<T>(head: &Link<T>) -> usize {
+    let mut cur = head.as_ref();
+    let mut count = 0;
+    while let Some(node) = cur {
+        count += 1;
+        cur = node.next.as_ref();
+    }
+    count
+}
+
+// Receives an option because the List could be empty
+pub fn insert_at_beginning<T>(head: Link<T>, data: T) -> Rc<ListNode<T>> {
+    Rc::new(ListNode { data, next: head })
+}
+
+/// Builds a list holding `values` in order, followed by the (possibly shared) `tail`.
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::shared_linked_list::{from_values_with_tail, length};
+///
+/// let common = from_values_with_tail(vec![7, 8], None);
+/// let a = from_values_with_tail(vec![1, 2, 3], common.clone());
+/// let b = from_values_with_tail(vec![4], common);
+///
+/// assert_eq!(length(&a), 5);
+/// assert_eq!(length(&b), 3);
+/// ```
+pub fn from_values_with_tail<T, I>(values: I, tail: Link<T>) -> Link<T>
+where
+    I: IntoIterator<Item = T>,
+    I::IntoIter: DoubleEndedIterator,
+{
+    values
+        .into_iter()
+        .rev()
+        .fold(tail, |head, data| Some(insert_at_beginning(head, data)))
+}
+
+/// Finds the first node shared by both lists, if any.
+///
+/// Nodes are compared by identity, not by value. The longer list is advanced
+/// by the length difference so that both cursors then reach the intersection
+/// at the same step.
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::shared_linked_list::{from_values_with_tail, intersection_point};
+///
+/// let common = from_values_with_tail(vec![7, 8], None);
+/// let a = from_values_with_tail(vec![1, 2, 3], common.clone());
+/// let b = from_values_with_tail(vec![4], common);
+///
+/// assert_eq!(intersection_point(&a, &b).map(|node| node.data), Some(7));
+/// ```
+///
+/// # Complexity
+///
+/// - **Time Complexity:** `O(m + n)`
+/// - **Space Complexity:** `O(1)`
+pub fn intersection_point<T>(a: &Link<T>, b: &Link<T>) -> Link<T> {
+    let len_a = length(a);
+    let len_b = length(b);
+
+    let mut cur_a = a.as_ref();
+    let mut cur_b = b.as_ref();
+
+    // Skip the extra nodes of the longer list
+    for _ in len_b..len_a {
+        cur_a = cur_a.and_then(|node| node.next.as_ref());
+    }
+    for _ in len_a..len_b {
+        cur_b = cur_b.and_then(|node| node.next.as_ref());
+    }
+
+    while let (Some(node_a), Some(node_b)) = (cur_a, cur_b) {
+        if Rc::ptr_eq(node_a, node_b) {
+            return Some(Rc::clone(node_a));
+        }
+        cur_a = node_a.next.as_ref();
+        cur_b = node_b.next.as_ref();
+    }
+    None
+}
+
+/// Counts the nodes shared by both lists, from their intersection point to the end.
+///
+/// Returns `0` when the lists do not intersect.
+pub fn shared_suffix_len<T>(a: &Link<T>, b: &Link<T>) -> usize {
+    length(&intersection_point(a, b))
+}
+
+type RandomLink<T> = Rc<RefCell<RandomListNode<T>>>;
+
+/// A node of a [`RandomPointerList`].
+///
+/// Besides the owning `next` pointer, every node may point to an arbitrary
+/// node of the same list through `random`. The random pointer is weak so that
+/// pointing backwards does not create reference cycles.
+struct RandomListNode<T> {
+    data: T,
+    next: Option<RandomLink<T>>,
+    random: Option<Weak<RefCell<RandomListNode<T>>>>,
+}
+
+/// A singly linked list where every node carries an extra pointer to any node
+/// of the list, as in the book's "clone a list with random pointers" puzzle.
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::shared_linked_list::RandomPointerList;
+///
+/// let mut list = RandomPointerList::new();
+/// list.insert_at_beginning(3);
+/// list.insert_at_beginning(2);
+/// list.insert_at_beginning(1);
+/// list.set_random(0, Some(2)).unwrap();
+///
+/// let copy = list.deep_clone();
+/// assert_eq!(copy.random_index(0), Some(2));
+/// assert_eq!(copy.values(), vec![1, 2, 3]);
+/// ```
+pub struct RandomPointerList<T> {
+    head: Option<RandomLink<T>>,
+    length: usize,
+}
+
+impl<T> RandomPointerList<T> {
+    /// Creates a new empty `RandomPointerList`.
+    pub fn new() -> RandomPointerList<T> {
+        RandomPointerList {
+            head: None,
+            length: 0,
+        }
+    }
+
+    /// Gets the amount of items in the `RandomPointerList`.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Checks if the `RandomPointerList` is empty.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Inserts an item at the start of the list. The new node has no random pointer.
+    ///
+    /// # Complexity
+    /// - `O(1)`
+    pub fn insert_at_beginning(&mut self, data: T) {
+        let node = Rc::new(RefCell::new(RandomListNode {
+            data,
+            next: self.head.take(),
+            random: None,
+        }));
+        self.head = Some(node);
+        self.length += 1;
+    }
+
+    /// Points the random pointer of the node at `from` to the node at `to`,
+    /// or clears it when `to` is `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error string `"Index out of bounds"` if either position is
+    /// not smaller than `self.len()`.
+    pub fn set_random(&mut self, from: usize, to: Option<usize>) -> Result<(), &str> {
+        let source = self.node_at(from).ok_or("Index out of bounds")?;
+        let target = match to {
+            Some(pos) => Some(self.node_at(pos).ok_or("Index out of bounds")?),
+            None => None,
+        };
+
+        source.borrow_mut().random = target.as_ref().map(Rc::downgrade);
+        Ok(())
+    }
+
+    /// Returns the position of the node the random pointer at `pos` points to.
+    ///
+    /// # Complexity
+    /// - `O(n)`
+    pub fn random_index(&self, pos: usize) -> Option<usize> {
+        let target = self.node_at(pos)?.borrow().random.as_ref()?.upgrade()?;
+
+        let mut cursor = self.head.clone();
+        let mut counter = 0;
+        while let Some(node) = cursor {
+            if Rc::ptr_eq(&node, &target) {
+                return Some(counter);
+            }
+            cursor = node.borrow().next.clone();
+            counter += 1;
+        }
+        None
+    }
+
+    fn node_at(&self, pos: usize) -> Option<RandomLink<T>> {
+        let mut cursor = self.head.clone();
+        for _ in 0..pos {
+            cursor = cursor?.borrow().next.clone();
+        }
+        cursor
+    }
+}
+
+impl<T: Clone> RandomPointerList<T> {
+    /// Retrieves a copy of the element at the specified position, if it exists.
+    pub fn get(&self, pos: usize) -> Option<T> {
+        self.node_at(pos).map(|node| node.borrow().data.clone())
+    }
+
+    /// Returns a copy of the values of the list, in order.
+    pub fn values(&self) -> Vec<T> {
+        let mut values = Vec::with_capacity(self.length);
+        let mut cursor = self.head.clone();
+        while let Some(node) = cursor {
+            values.push(node.borrow().data.clone());
+            cursor = node.borrow().next.clone();
+        }
+        values
+    }
+
+    /// Creates an independent copy of the list, including its random pointers.
+    ///
+    /// Random pointers of the copy point into the copy, never into `self`.
+    /// The first pass duplicates every node and remembers which copy belongs
+    /// to which original; the second pass wires `next` and `random`.
+    ///
+    /// # Complexity
+    ///
+    /// - **Time Complexity:** `O(n)`
+    /// - **Space Complexity:** `O(n)`
+    pub fn deep_clone(&self) -> RandomPointerList<T> {
+        let mut originals = Vec::with_capacity(self.length);
+        let mut copies: HashMap<*const RefCell<RandomListNode<T>>, RandomLink<T>> =
+            HashMap::with_capacity(self.length);
+
+        let mut cursor = self.head.clone();
+        while let Some(node) = cursor {
+            let copy = Rc::new(RefCell::new(RandomListNode {
+                data: node.borrow().data.clone(),
+                next: None,
+                random: None,
+            }));
+            copies.insert(Rc::as_ptr(&node), copy);
+            cursor = node.borrow().next.clone();
+            originals.push(node);
+        }
+
+        for node in &originals {
+            let original = node.borrow();
+            let mut copy = copies[&Rc::as_ptr(node)].borrow_mut();
+            copy.next = original
+                .next
+                .as_ref()
+                .map(|next| Rc::clone(&copies[&Rc::as_ptr(next)]));
+            copy.random = original
+                .random
+                .as_ref()
+                .and_then(Weak::upgrade)
+                .map(|random| Rc::downgrade(&copies[&Rc::as_ptr(&random)]));
+        }
+
+        RandomPointerList {
+            head: self
+                .head
+                .as_ref()
+                .map(|head| Rc::clone(&copies[&Rc::as_ptr(head)])),
+            length: self.length,
+        }
+    }
+}
+
+impl<T> Drop for RandomPointerList<T> {
+    fn drop(&mut self) {
+        // Unlink the nodes one by one so the drop does not recurse on long lists
+        let mut cursor = self.head.take();
+        while let Some(node) = cursor {
+            cursor = node.borrow_mut().next.take();
+        }
+    }
+}
+
+impl<T> Default for RandomPointerList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_length() {
+        let mut head = insert_at_beginning(None, 1);
+        head = insert_at_beginning(Some(head), 1);
+        head = insert_at_beginning(Some(head), 1);
+
+        assert_eq!(length(&Some(head)), 3);
+        assert_eq!(length::<i32>(&None), 0);
+    }
+
+    #[test]
+    fn test_from_values_with_tail() {
+        let tail = from_values_with_tail(vec![3, 4], None);
+        let head = from_values_with_tail(vec![1, 2], tail.clone());
+
+        let mut cur = head.as_ref();
+        let mut values = vec![];
+        while let Some(node) = cur {
+            values.push(node.data);
+            cur = node.next.as_ref();
+        }
+        assert_eq!(values, vec![1, 2, 3, 4]);
+        // The tail is shared, not copied
+        assert_eq!(Rc::strong_count(tail.as_ref().unwrap()), 2);
+    }
+
+    #[test]
+    fn test_intersection_point() {
+        let common = from_values_with_tail(vec![10, 11, 12], None);
+        let a = from_values_with_tail(vec![1, 2, 3, 4, 5], common.clone());
+        let b = from_values_with_tail(vec![6], common.clone());
+
+        let point = intersection_point(&a, &b).unwrap();
+        assert!(Rc::ptr_eq(&point, common.as_ref().unwrap()));
+        assert!(Rc::ptr_eq(
+            &intersection_point(&b, &a).unwrap(),
+            common.as_ref().unwrap()
+        ));
+        assert_eq!(shared_suffix_len(&a, &b), 3);
+    }
+
+    #[test]
+    fn test_intersection_compares_identity() {
+        // Same values, different nodes
+        let a = from_values_with_tail(vec![1, 2, 3], None);
+        let b = from_values_with_tail(vec![1, 2, 3], None);
+
+        assert!(intersection_point(&a, &b).is_none());
+        assert_eq!(shared_suffix_len(&a, &b), 0);
+    }
+
+    #[test]
+    fn test_intersection_at_head() {
+        let a = from_values_with_tail(vec![1, 2], None);
+        let b = a.clone();
+
+        assert_eq!(intersection_point(&a, &b).unwrap().data, 1);
+        assert_eq!(shared_suffix_len(&a, &b), 2);
+        assert!(intersection_point(&a, &None).is_none());
+    }
+
+    #[test]
+    fn test_drop_long_list() {
+        let head = from_values_with_tail(0..2_000_000, None);
+        assert_eq!(length(&head), 2_000_000);
+        drop(head);
+    }
+
+    #[test]
+    fn test_drop_keeps_shared_tail() {
+        let common = from_values_with_tail(0..1_000_000, None);
+        let a = from_values_with_tail(vec![1, 2], common.clone());
+        let b = from_values_with_tail(vec![3], common.clone());
+        drop(common);
+
+        drop(a);
+        assert_eq!(length(&b), 1_000_001);
+        assert_eq!(b.as_ref().unwrap().next.as_ref().unwrap().data, 0);
+    }
+
+    #[test]
+    fn test_drop_long_random_pointer_list() {
+        let mut list = RandomPointerList::new();
+        for i in 0..1_000_000 {
+            list.insert_at_beginning(i);
+        }
+        list.set_random(0, Some(999_999)).unwrap();
+        list.set_random(999_999, Some(0)).unwrap();
+        assert_eq!(list.len(), 1_000_000);
+        drop(list);
+    }
+
+    fn random_list(values: &[i32], randoms: &[Option<usize>]) -> RandomPointerList<i32> {
+        let mut list = RandomPointerList::new();
+        for value in values.iter().rev() {
+            list.insert_at_beginning(*value);
+        }
+        for (from, to) in randoms.iter().enumerate() {
+            list.set_random(from, *to).unwrap();
+        }
+        list
+    }
+
+    #[test]
+    fn test_set_random() {
+        let mut list = random_list(&[1, 2, 3], &[Some(2), None, Some(0)]);
+        assert_eq!(list.random_index(0), Some(2));
+        assert_eq!(list.random_index(1), None);
+        assert_eq!(list.random_index(2), Some(0));
+        assert_eq!(list.get(1), Some(2));
+
+        assert!(list.set_random(3, None).is_err());
+        assert!(list.set_random(0, Some(3)).is_err());
+        assert_eq!(list.random_index(0), Some(2));
+    }
+
+    #[test]
+    fn test_deep_clone() {
+        let randoms = [Some(3), Some(0), Some(2), None];
+        let list = random_list(&[1, 2, 3, 4], &randoms);
+        let copy = list.deep_clone();
+
+        assert_eq!(copy.len(), 4);
+        assert_eq!(copy.values(), vec![1, 2, 3, 4]);
+        for (pos, random) in randoms.iter().enumerate() {
+            assert_eq!(copy.random_index(pos), *random);
+        }
+
+        // No node of the copy belongs to the original
+        for (pos, expected) in randoms.iter().enumerate() {
+            let original = list.node_at(pos).unwrap();
+            let copied = copy.node_at(pos).unwrap();
+            assert!(!Rc::ptr_eq(&original, &copied));
+            let random = copied.borrow().random.as_ref().and_then(Weak::upgrade);
+            if let Some(random) = random {
+                assert!(copy.random_index(pos).is_some());
+                assert!(!Rc::ptr_eq(
+                    &random,
+                    &list.node_at(expected.unwrap()).unwrap()
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn test_deep_clone_is_independent() {
+        let mut list = random_list(&[1, 2], &[Some(1), Some(1)]);
+        let copy = list.deep_clone();
+        list.set_random(0, None).unwrap();
+        list.insert_at_beginning(0);
+
+        assert_eq!(copy.len(), 2);
+        assert_eq!(copy.random_index(0), Some(1));
+        assert_eq!(list.random_index(1), None);
+    }
+
+    #[test]
+    fn test_deep_clone_empty() {
+        let list: RandomPointerList<i32> = RandomPointerList::new();
+        let copy = list.deep_clone();
+        assert!(copy.is_empty());
+        assert!(copy.values().is_empty());
+    }
+}
@@ -0,0 +1,653 @@
+use crate::search_tree::{self, SearchTree};
+use std::cmp::Ordering;
+
+/// An ordered map that moves every accessed entry up to the root.
+///
+/// After each lookup, insertion or removal, the node reached is "splayed":
+/// rotated up through its ancestors, two levels at a time, until it becomes
+/// the root. The parent links of the nodes let the splaying walk bottom-up
+/// from the node without a stack.
+///
+/// The tree keeps no balance information, so a single operation may take
+/// `O(n)`, but any sequence of `m` operations takes `O(m log n)`. Recently
+/// accessed keys stay near the root, so skewed workloads that keep asking
+/// for the same few keys are much cheaper than that.
+///
+/// Each node caches the size of its subtree, which the rotations keep up
+/// to date, so splitting the tree never has to count the entries it moves.
+///
+/// Since lookups reshape the tree, they take `&mut self`.
+///
+/// # Type Parameters
+/// - `K`: The type of the keys, which order the entries.
+/// - `V`: The type of the values.
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::splay_tree::SplayTree;
+///
+/// let mut tree = SplayTree::new();
+/// for i in 0..100 {
+///     tree.insert(i, i * i);
+/// }
+/// assert_eq!(tree.get(&12), Some(&144));
+/// assert_eq!(tree.root_key(), Some(&12));
+///
+/// let mut greater = tree.split(&50);
+/// assert_eq!((tree.len(), greater.len()), (50, 50));
+/// assert_eq!(greater.remove(&50), Some(2500));
+///
+/// tree.join(&mut greater).unwrap();
+/// assert_eq!(tree.len(), 99);
+/// ```
+pub struct SplayTree<K, V> {
+    nodes: SearchTree<K, V, usize>,
+}
+
+/// A node of the [`SplayTree`], whose `meta` is the number of nodes in its
+/// subtree, `1` for a leaf.
+type Node<K, V> = search_tree::Node<K, V, usize>;
+
+/// Borrowing in-order iterator over the entries of a [`SplayTree`], from the
+/// smallest key to the greatest one.
+///
+/// It does not splay, so it leaves the shape of the tree as it is.
+pub struct Iter<'a, K, V> {
+    range: search_tree::Range<'a, K, V, usize>,
+    remaining: usize,
+}
+
+impl<K: Ord, V> SplayTree<K, V> {
+    /// Creates a new empty `SplayTree`.
+    pub fn new() -> SplayTree<K, V> {
+        SplayTree {
+            nodes: SearchTree::new(),
+        }
+    }
+
+    /// Gets the amount of entries in the tree.
+    pub fn len(&self) -> usize {
+        self.nodes.length
+    }
+
+    /// Checks if the tree is empty.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.length == 0
+    }
+
+    /// Retrieves the key at the root, the one accessed last, without
+    /// splaying.
+    pub fn root_key(&self) -> Option<&K> {
+        self.nodes.root.map(|root| unsafe { &(*root).data.key })
+    }
+
+    /// Retrieves a reference to the value of `key`, if it is in the tree.
+    ///
+    /// The entry is splayed to the root. If `key` is missing, the last entry
+    /// compared on the way down is splayed instead.
+    ///
+    /// # Complexity
+    /// - Amortized `O(log n)`
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        self.access(key).map(|node| unsafe { &(*node).data.value })
+    }
+
+    /// Retrieves a mutable reference to the value of `key`, if it is in the
+    /// tree, splaying like [`get`](Self::get).
+    ///
+    /// # Complexity
+    /// - Amortized `O(log n)`
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.access(key)
+            .map(|node| unsafe { &mut (*node).data.value })
+    }
+
+    /// Checks if `key` is in the tree, splaying like [`get`](Self::get).
+    ///
+    /// # Complexity
+    /// - Amortized `O(log n)`
+    pub fn contains_key(&mut self, key: &K) -> bool {
+        self.access(key).is_some()
+    }
+
+    /// Retrieves the entry with the smallest key, splaying it to the root.
+    ///
+    /// # Complexity
+    /// - Amortized `O(log n)`
+    pub fn first_key_value(&mut self) -> Option<(&K, &V)> {
+        let first = self.nodes.first()?;
+        unsafe {
+            self.splay(first);
+            Some((*first).data.pair())
+        }
+    }
+
+    /// Retrieves the entry with the greatest key, splaying it to the root.
+    ///
+    /// # Complexity
+    /// - Amortized `O(log n)`
+    pub fn last_key_value(&mut self) -> Option<(&K, &V)> {
+        let last = self.nodes.last()?;
+        unsafe {
+            self.splay(last);
+            Some((*last).data.pair())
+        }
+    }
+
+    /// Inserts `value` under `key` and splays its entry to the root.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(V)`: The value replaced, if `key` was already in the tree.
+    ///   The key itself is not replaced.
+    /// - `None`: If `key` was inserted.
+    ///
+    /// # Complexity
+    /// - Amortized `O(log n)`
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let place = self.nodes.search(&key);
+        unsafe {
+            if let Some((node, Ordering::Equal)) = place {
+                self.splay(node);
+                return Some(std::mem::replace(&mut (*node).data.value, value));
+            }
+            // The sizes of the ancestors are fixed by the rotations of the splay
+            let node = self.nodes.link_leaf(place, key, value, 1);
+            self.splay(node);
+        }
+        None
+    }
+
+    /// Removes `key` from the tree.
+    ///
+    /// The entry is splayed to the root and taken out, then the greatest
+    /// entry of its left subtree is splayed up to become the new root, with
+    /// the right subtree hung to its right.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(V)`: The value of the removed entry.
+    /// - `None`: If `key` was not in the tree.
+    ///
+    /// # Complexity
+    /// - Amortized `O(log n)`
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let node = self.access(key)?;
+        unsafe {
+            let left = Self::detach((*node).left.take());
+            let right = Self::detach((*node).right.take());
+            self.nodes.root = left;
+            self.nodes.length -= 1;
+            self.hang_right(right);
+            Some(Box::from_raw(node).data.value)
+        }
+    }
+
+    /// Splits the tree at `key`, moving every entry with a key greater than
+    /// or equal to `key` into a new tree.
+    ///
+    /// # Returns
+    ///
+    /// - `SplayTree<K, V>`: The tree with the moved entries, while the
+    ///   smaller ones stay in `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::splay_tree::SplayTree;
+    ///
+    /// let mut tree: SplayTree<i32, ()> = (0..10).map(|key| (key, ())).collect();
+    /// let greater = tree.split(&7);
+    ///
+    /// assert_eq!(tree.len(), 7);
+    /// let keys: Vec<_> = greater.iter().map(|(key, _)| *key).collect();
+    /// assert_eq!(keys, vec![7, 8, 9]);
+    /// ```
+    ///
+    /// # Complexity
+    /// - Amortized `O(log n)`, the sizes of the two trees are read from
+    ///   their roots
+    pub fn split(&mut self, key: &K) -> SplayTree<K, V> {
+        let mut greater = SplayTree::new();
+        self.access(key);
+        let Some(root) = self.nodes.root else {
+            return greater;
+        };
+        unsafe {
+            // The root is now either `key` or the entry next to where it would be
+            if (*root).data.key >= *key {
+                self.nodes.root = Self::detach((*root).left.take());
+                greater.nodes.root = Some(root);
+            } else {
+                greater.nodes.root = Self::detach((*root).right.take());
+            }
+            Self::update_size(root);
+            greater.nodes.length = Self::size(greater.nodes.root);
+        }
+        self.nodes.length -= greater.nodes.length;
+        greater
+    }
+
+    /// Moves every entry of `other` into the tree, when all of its keys are
+    /// greater than the ones of the tree, leaving `other` empty.
+    ///
+    /// The greatest entry of the tree is splayed to the root, where it has no
+    /// right child, and the root of `other` is hung there.
+    ///
+    /// # Errors
+    ///
+    /// - `"Keys are not greater than the ones of the tree"`: If `other` has
+    ///   a key smaller than or equal to one of the tree. Neither tree loses
+    ///   any entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use villa01_data_structures::splay_tree::SplayTree;
+    ///
+    /// let mut tree: SplayTree<i32, ()> = (0..5).map(|key| (key, ())).collect();
+    /// let mut other: SplayTree<i32, ()> = (5..10).map(|key| (key, ())).collect();
+    ///
+    /// assert!(other.join(&mut tree).is_err());
+    /// assert_eq!(tree.join(&mut other), Ok(()));
+    /// assert_eq!((tree.len(), other.len()), (10, 0));
+    /// ```
+    ///
+    /// # Complexity
+    /// - Amortized `O(log n + log m)`, where `m` is the size of `other`
+    pub fn join(&mut self, other: &mut SplayTree<K, V>) -> Result<(), &'static str> {
+        if let (Some((last, _)), Some((first, _))) =
+            (self.last_key_value(), other.first_key_value())
+        {
+            if last >= first {
+                return Err("Keys are not greater than the ones of the tree");
+            }
+        }
+        self.nodes.length += std::mem::replace(&mut other.nodes.length, 0);
+        let right = other.nodes.root.take();
+        unsafe { self.hang_right(right) };
+        Ok(())
+    }
+
+    /// Returns an in-order iterator over every entry of the tree, which does
+    /// not splay.
+    ///
+    /// # Complexity
+    /// - `O(n)` for the whole traversal
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            range: self.nodes.range(..),
+            remaining: self.nodes.length,
+        }
+    }
+
+    /// Searches for `key` and splays the last node reached, returning it if
+    /// it holds `key`.
+    fn access(&mut self, key: &K) -> Option<*mut Node<K, V>> {
+        let (node, ordering) = self.nodes.search(key)?;
+        unsafe { self.splay(node) };
+        (ordering == Ordering::Equal).then_some(node)
+    }
+
+    /// Gets the number of nodes of a subtree, `0` if it is empty.
+    unsafe fn size(subtree: Option<*mut Node<K, V>>) -> usize {
+        subtree.map_or(0, |node| (*node).meta)
+    }
+
+    /// Recomputes the size of the subtree of `node` from the ones of its
+    /// children.
+    unsafe fn update_size(node: *mut Node<K, V>) {
+        (*node).meta = 1 + Self::size((*node).left) + Self::size((*node).right);
+    }
+
+    /// Cuts the parent link of a subtree taken out of its parent.
+    unsafe fn detach(subtree: Option<*mut Node<K, V>>) -> Option<*mut Node<K, V>> {
+        if let Some(root) = subtree {
+            (*root).parent = None;
+        }
+        subtree
+    }
+
+    /// Joins `right`, whose keys are all greater than the ones of the tree,
+    /// by splaying the greatest entry and hanging `right` as its right child.
+    unsafe fn hang_right(&mut self, right: Option<*mut Node<K, V>>) {
+        let Some(root) = self.nodes.root else {
+            self.nodes.root = right;
+            return;
+        };
+        let last = Node::rightmost(root);
+        self.splay(last);
+        (*last).right = right;
+        if let Some(right) = right {
+            (*right).parent = Some(last);
+        }
+        Self::update_size(last);
+    }
+
+    /// Rotates `node` above its parent, a right rotation of the parent if
+    /// `node` is its left child and a left rotation otherwise, and updates
+    /// the sizes of both.
+    ///
+    /// ```text
+    ///       parent         node
+    ///       /    \         /  \
+    ///     node    c  =>   a  parent
+    ///     /  \                /  \
+    ///    a    b              b    c
+    /// ```
+    unsafe fn rotate_up(&mut self, node: *mut Node<K, V>) {
+        let parent = (*node).parent.expect("rotating up needs a parent");
+        if (*parent).left == Some(node) {
+            self.nodes.rotate_right(parent);
+        } else {
+            self.nodes.rotate_left(parent);
+        }
+        Self::update_size(parent);
+        Self::update_size(node);
+    }
+
+    /// Rotates `node` up to the root, walking the parent links bottom-up.
+    ///
+    /// Each step lifts it two levels: "zig-zig" when the node and its parent
+    /// are children on the same side, which rotates the parent first, and
+    /// "zig-zag" otherwise, which rotates the node twice. A single "zig"
+    /// rotation finishes when the parent is the root.
+    unsafe fn splay(&mut self, node: *mut Node<K, V>) {
+        while let Some(parent) = (*node).parent {
+            let Some(grandparent) = (*parent).parent else {
+                self.rotate_up(node);
+                break;
+            };
+            let node_is_left = (*parent).left == Some(node);
+            let parent_is_left = (*grandparent).left == Some(parent);
+            if node_is_left == parent_is_left {
+                self.rotate_up(parent);
+            } else {
+                self.rotate_up(node);
+            }
+            self.rotate_up(node);
+        }
+    }
+}
+
+impl<K: Ord, V> Default for SplayTree<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for SplayTree<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut tree = SplayTree::new();
+        for (key, value) in iter {
+            tree.insert(key, value);
+        }
+        tree
+    }
+}
+
+impl<'a, K: Ord, V> IntoIterator for &'a SplayTree<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = self.range.next()?;
+        self.remaining -= 1;
+        Some(entry.pair())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search_tree::tests::{self as shared, TestMap};
+
+    impl TestMap for SplayTree<i32, i32> {
+        fn insert(&mut self, key: i32, value: i32) -> Option<i32> {
+            SplayTree::insert(self, key, value)
+        }
+
+        fn remove(&mut self, key: i32) -> Option<i32> {
+            SplayTree::remove(self, &key)
+        }
+
+        fn get(&mut self, key: i32) -> Option<i32> {
+            SplayTree::get(self, &key).copied()
+        }
+
+        fn first_and_last(&mut self) -> Option<(i32, i32)> {
+            let first = *self.first_key_value()?.0;
+            let last = *self.last_key_value()?.0;
+            Some((first, last))
+        }
+
+        fn len(&self) -> usize {
+            SplayTree::len(self)
+        }
+
+        fn check(&self) -> Vec<i32> {
+            assert_valid(self)
+        }
+    }
+
+    /// Checks the links and the cached sizes, and returns the keys in order.
+    fn assert_valid<K: Ord + Copy, V>(tree: &SplayTree<K, V>) -> Vec<K> {
+        shared::assert_links(&tree.nodes, |node| unsafe {
+            let size = 1 + SplayTree::size((*node).left) + SplayTree::size((*node).right);
+            assert_eq!((*node).meta, size);
+        })
+    }
+
+    /// Gets the depth of `key`, the root being at depth 0.
+    fn depth<V>(tree: &SplayTree<i32, V>, key: i32) -> usize {
+        let mut depth = 0;
+        let mut cursor = tree.nodes.root;
+        while let Some(node) = cursor {
+            unsafe {
+                cursor = match key.cmp(&(*node).data.key) {
+                    Ordering::Less => (*node).left,
+                    Ordering::Greater => (*node).right,
+                    Ordering::Equal => return depth,
+                };
+            }
+            depth += 1;
+        }
+        panic!("{key} is not in the tree");
+    }
+
+    #[test]
+    fn test_insert_splays_to_root() {
+        let mut tree = SplayTree::new();
+        assert_eq!(tree.root_key(), None);
+        for key in [5, 2, 8, 1, 9] {
+            assert_eq!(tree.insert(key, key * 10), None);
+            assert_eq!(tree.root_key(), Some(&key));
+        }
+        assert_eq!(tree.insert(2, 0), Some(20));
+        assert_eq!(tree.root_key(), Some(&2));
+        assert_eq!(assert_valid(&tree), vec![1, 2, 5, 8, 9]);
+    }
+
+    #[test]
+    fn test_zig_zig_and_zig_zag() {
+        // Sorted inserts leave a path down the left side
+        let mut tree: SplayTree<i32, ()> = (1..=7).map(|key| (key, ())).collect();
+        assert_eq!(depth(&tree, 1), 6);
+
+        // Zig-zig steps roughly halve the depth of the path
+        tree.get(&1);
+        assert_eq!(tree.root_key(), Some(&1));
+        assert_eq!(depth(&tree, 7), 2);
+        assert_eq!(depth(&tree, 2), 3);
+        assert_eq!(depth(&tree, 3), 4);
+        assert_valid(&tree);
+
+        // Inserting 2 below 1 -> 3 makes it an inner grandchild, which a
+        // zig-zag lifts above both
+        let tree: SplayTree<i32, ()> = [3, 1, 2].into_iter().map(|key| (key, ())).collect();
+        assert_eq!(tree.root_key(), Some(&2));
+        assert_eq!(depth(&tree, 1), 1);
+        assert_eq!(depth(&tree, 3), 1);
+        assert_valid(&tree);
+    }
+
+    #[test]
+    fn test_get_splays() {
+        let mut tree: SplayTree<i32, i32> = (0..100).map(|key| (key, -key)).collect();
+        assert_eq!(tree.get(&42), Some(&-42));
+        assert_eq!(tree.root_key(), Some(&42));
+
+        // A missing key splays the last key compared
+        assert_eq!(tree.get(&1000), None);
+        assert_eq!(tree.root_key(), Some(&99));
+        assert!(!tree.contains_key(&-5));
+        assert_eq!(tree.root_key(), Some(&0));
+
+        *tree.get_mut(&7).unwrap() = 7;
+        assert_eq!(tree.get(&7), Some(&7));
+        assert_eq!(tree.first_key_value(), Some((&0, &0)));
+        assert_eq!(tree.last_key_value(), Some((&99, &-99)));
+        assert_eq!(tree.root_key(), Some(&99));
+        assert_eq!(assert_valid(&tree), (0..100).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_recent_keys_stay_shallow() {
+        let mut tree: SplayTree<i32, ()> = (0..10_000).map(|key| (key, ())).collect();
+        for _ in 0..3 {
+            for key in [17, 5_000, 9_999] {
+                tree.get(&key);
+            }
+        }
+        for key in [17, 5_000, 9_999] {
+            assert!(depth(&tree, key) <= 2);
+        }
+
+        // Sequential access takes linear time overall
+        for key in 0..10_000 {
+            assert!(tree.contains_key(&key));
+        }
+        assert_eq!(assert_valid(&tree).len(), 10_000);
+    }
+
+    #[test]
+    fn test_map_operations() {
+        shared::check_replaced_values::<SplayTree<i32, i32>>();
+        shared::check_mixed_writes::<SplayTree<i32, i32>>();
+        shared::write_in_order::<SplayTree<i32, i32>>(0..10_000, (0..10_000).step_by(3));
+    }
+
+    #[test]
+    fn test_amortized_depth() {
+        // Sorted inserts leave a single path, the worst starting shape
+        let length = 4096;
+        let mut tree: SplayTree<i32, ()> = (0..length).map(|key| (key, ())).collect();
+        assert_eq!(depth(&tree, 0), 4095);
+
+        // Any `m` accesses walk `O(m log n + n log n)` nodes in total, even
+        // though the first one walks the whole path
+        let accesses = 3 * length;
+        let mut walked = 0;
+        for i in 0..accesses {
+            let key = (i * 1237) % length;
+            walked += depth(&tree, key);
+            assert!(tree.contains_key(&key));
+        }
+        let log = length.ilog2() as usize;
+        assert!(walked <= 3 * accesses as usize * log + length as usize * log);
+    }
+
+    #[test]
+    fn test_split_at_boundaries() {
+        // Keys 10, 12, ..., 28
+        let tree_of = || {
+            (5..15)
+                .map(|key| (key * 2, ()))
+                .collect::<SplayTree<i32, ()>>()
+        };
+        for (key, kept) in [
+            (9, 0),
+            (10, 0),
+            (11, 1),
+            (12, 1),
+            (27, 9),
+            (28, 9),
+            (29, 10),
+            (i32::MAX, 10),
+        ] {
+            let mut tree = tree_of();
+            let greater = tree.split(&key);
+            let keys = assert_valid(&tree);
+            let greater_keys = assert_valid(&greater);
+            assert_eq!(keys.len(), kept, "split at {key}");
+            assert_eq!(greater_keys.len(), 10 - kept, "split at {key}");
+            assert!(keys.iter().all(|&other| other < key));
+            assert!(greater_keys.iter().all(|&other| other >= key));
+        }
+
+        let mut empty: SplayTree<i32, ()> = SplayTree::new();
+        assert!(empty.split(&0).is_empty());
+    }
+
+    #[test]
+    fn test_split_reads_sizes() {
+        // Counting the moved entries would make these splits quadratic overall
+        let length = 20_000;
+        let mut tree: SplayTree<i32, ()> = (0..length).map(|key| (key, ())).collect();
+        for key in (0..length).rev().step_by(7).chain([i32::MIN]) {
+            let mut greater = tree.split(&key);
+            let kept = key.max(0) as usize;
+            assert_eq!((tree.len(), greater.len()), (kept, length as usize - kept));
+            assert_eq!(tree.join(&mut greater), Ok(()));
+        }
+        assert_eq!(assert_valid(&tree), (0..length).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_join_at_boundaries() {
+        let mut tree: SplayTree<i32, ()> = (0..50).map(|key| (key, ())).collect();
+
+        // The greatest key of the tree equal to the smallest one of `other`
+        let mut other: SplayTree<i32, ()> = (49..100).map(|key| (key, ())).collect();
+        assert_eq!(
+            tree.join(&mut other),
+            Err("Keys are not greater than the ones of the tree")
+        );
+        assert_eq!(assert_valid(&tree), (0..50).collect::<Vec<_>>());
+        assert_eq!(assert_valid(&other), (49..100).collect::<Vec<_>>());
+
+        // Adjacent keys
+        other.remove(&49);
+        assert_eq!(tree.join(&mut other), Ok(()));
+        assert!(other.is_empty());
+        assert_eq!(assert_valid(&tree), (0..100).collect::<Vec<_>>());
+
+        // Empty trees on either side
+        assert_eq!(tree.join(&mut other), Ok(()));
+        assert_eq!(other.join(&mut tree), Ok(()));
+        assert_eq!((tree.len(), other.len()), (0, 100));
+        assert_eq!(assert_valid(&other), (0..100).collect::<Vec<_>>());
+
+        // Joining back the halves of every split gives the same entries
+        for key in [-1, 0, 1, 50, 99, 100] {
+            let mut greater = other.split(&key);
+            assert_eq!(other.join(&mut greater), Ok(()));
+            assert_eq!(assert_valid(&other), (0..100).collect::<Vec<_>>());
+        }
+    }
+}
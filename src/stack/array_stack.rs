@@ -0,0 +1,170 @@
+use super::Stack;
+use std::iter::{Flatten, Rev};
+use std::slice;
+
+/// Capacity of the array allocated by the first push.
+const INITIAL_CAPACITY: usize = 4;
+
+/// A [`Stack`] built on a growable array.
+///
+/// The array doubles its capacity when it is full and halves it when only a
+/// quarter of it is used, so `push` and `pop` are `O(1)` amortized and the
+/// unused space stays proportional to the amount of items.
+pub struct ArrayStack<T> {
+    items: Box<[Option<T>]>,
+    length: usize,
+}
+
+impl<T> ArrayStack<T> {
+    /// Creates a new empty `ArrayStack`. Nothing is allocated until the first push.
+    pub fn new() -> ArrayStack<T> {
+        ArrayStack::with_capacity(0)
+    }
+
+    /// Creates a new empty `ArrayStack` able to hold `capacity` items without growing.
+    pub fn with_capacity(capacity: usize) -> ArrayStack<T> {
+        ArrayStack {
+            items: Self::allocate(capacity),
+            length: 0,
+        }
+    }
+
+    /// Gets the amount of items the stack can hold before growing.
+    pub fn capacity(&self) -> usize {
+        self.items.len()
+    }
+
+    fn allocate(capacity: usize) -> Box<[Option<T>]> {
+        (0..capacity).map(|_| None).collect()
+    }
+
+    fn resize(&mut self, capacity: usize) {
+        let mut items = Self::allocate(capacity);
+        for (new, old) in items.iter_mut().zip(self.items[..self.length].iter_mut()) {
+            *new = old.take();
+        }
+        self.items = items;
+    }
+}
+
+impl<T> Stack<T> for ArrayStack<T> {
+    type Iter<'a>
+        = Flatten<Rev<slice::Iter<'a, Option<T>>>>
+    where
+        T: 'a;
+
+    /// # Complexity
+    /// - `O(1)` amortized, `O(n)` when the array grows
+    fn push(&mut self, data: T) {
+        if self.length == self.capacity() {
+            self.resize((self.capacity() * 2).max(INITIAL_CAPACITY));
+        }
+        self.items[self.length] = Some(data);
+        self.length += 1;
+    }
+
+    /// # Complexity
+    /// - `O(1)` amortized, `O(n)` when the array shrinks
+    fn pop(&mut self) -> Option<T> {
+        if self.length == 0 {
+            return None;
+        }
+        self.length -= 1;
+        let data = self.items[self.length].take();
+
+        if self.length > 0 && self.length <= self.capacity() / 4 {
+            self.resize(self.capacity() / 2);
+        }
+        data
+    }
+
+    fn peek(&self) -> Option<&T> {
+        self.length
+            .checked_sub(1)
+            .and_then(|top| self.items[top].as_ref())
+    }
+
+    fn len(&self) -> usize {
+        self.length
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.items[..self.length].iter().rev().flatten()
+    }
+}
+
+impl<T> Default for ArrayStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_pop() {
+        let mut stack = ArrayStack::new();
+        assert!(stack.is_empty());
+        assert_eq!(stack.pop(), None);
+        assert_eq!(stack.peek(), None);
+
+        stack.push(1);
+        stack.push(2);
+        stack.push(3);
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.peek(), Some(&3));
+
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.pop(), Some(1));
+        assert_eq!(stack.pop(), None);
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn test_iter_top_to_bottom() {
+        let mut stack = ArrayStack::new();
+        for i in 0..5 {
+            stack.push(i);
+        }
+        assert_eq!(
+            stack.iter().copied().collect::<Vec<_>>(),
+            vec![4, 3, 2, 1, 0]
+        );
+    }
+
+    #[test]
+    fn test_grows_and_shrinks() {
+        let mut stack = ArrayStack::new();
+        assert_eq!(stack.capacity(), 0);
+
+        for i in 0..100 {
+            stack.push(i);
+        }
+        assert_eq!(stack.capacity(), 128);
+        assert_eq!(stack.len(), 100);
+
+        for i in (10..100).rev() {
+            assert_eq!(stack.pop(), Some(i));
+        }
+        assert!(stack.capacity() < 128);
+        assert!(stack.capacity() >= stack.len());
+        assert_eq!(
+            stack.iter().copied().collect::<Vec<_>>(),
+            (0..10).rev().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_with_capacity() {
+        let mut stack = ArrayStack::with_capacity(10);
+        for i in 0..10 {
+            stack.push(i);
+        }
+        assert_eq!(stack.capacity(), 10);
+        stack.push(10);
+        assert_eq!(stack.capacity(), 20);
+    }
+}
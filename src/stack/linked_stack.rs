@@ -0,0 +1,143 @@
+use super::Stack;
+use crate::linked_list::{self, List, ListNode};
+
+/// A [`Stack`] built on the singly linked [`ListNode`] chain.
+///
+/// The top of the stack is the head of the chain, so `push` and `pop` are
+/// `O(1)` and never move existing items.
+pub struct LinkedStack<T> {
+    // Owned through a `List`, so long stacks are dropped iteratively
+    list: List<T>,
+    length: usize,
+}
+
+/// Borrowing iterator over a [`LinkedStack`], from top to bottom.
+pub struct Iter<'a, T> {
+    next: Option<&'a ListNode<T>>,
+}
+
+impl<T> LinkedStack<T> {
+    /// Creates a new empty `LinkedStack`.
+    pub fn new() -> LinkedStack<T> {
+        LinkedStack {
+            list: List::new(),
+            length: 0,
+        }
+    }
+}
+
+impl<T> Stack<T> for LinkedStack<T> {
+    type Iter<'a>
+        = Iter<'a, T>
+    where
+        T: 'a;
+
+    /// # Complexity
+    /// - `O(1)`
+    fn push(&mut self, data: T) {
+        let head = self.list.head.take();
+        self.list.head = Some(linked_list::insert_at_beginning(head, data));
+        self.length += 1;
+    }
+
+    /// # Complexity
+    /// - `O(1)`
+    fn pop(&mut self) -> Option<T> {
+        self.list.head.take().map(|head| {
+            let ListNode { data, next } = *head;
+            self.list.head = next;
+            self.length -= 1;
+            data
+        })
+    }
+
+    fn peek(&self) -> Option<&T> {
+        self.list.head.as_ref().map(|node| &node.data)
+    }
+
+    fn len(&self) -> usize {
+        self.length
+    }
+
+    fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.list.head.as_deref(),
+        }
+    }
+}
+
+impl<T> Default for LinkedStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            &node.data
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_pop() {
+        let mut stack = LinkedStack::new();
+        assert!(stack.is_empty());
+        assert_eq!(stack.pop(), None);
+        assert_eq!(stack.peek(), None);
+
+        stack.push(1);
+        stack.push(2);
+        stack.push(3);
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.peek(), Some(&3));
+
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.len(), 1);
+        assert_eq!(stack.pop(), Some(1));
+        assert_eq!(stack.pop(), None);
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn test_iter_top_to_bottom() {
+        let mut stack = LinkedStack::new();
+        for i in 0..5 {
+            stack.push(i);
+        }
+        assert_eq!(
+            stack.iter().copied().collect::<Vec<_>>(),
+            vec![4, 3, 2, 1, 0]
+        );
+        assert_eq!(stack.len(), 5);
+    }
+
+    #[test]
+    fn test_items_without_display() {
+        struct T {
+            id: usize,
+        }
+        let mut stack = LinkedStack::new();
+        stack.push(T { id: 1 });
+        stack.push(T { id: 2 });
+        assert_eq!(stack.pop().map(|t| t.id), Some(2));
+    }
+
+    #[test]
+    fn test_drop_long_stack() {
+        let mut stack = LinkedStack::new();
+        for i in 0..2_000_000 {
+            stack.push(i);
+        }
+        drop(stack);
+    }
+}
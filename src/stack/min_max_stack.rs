@@ -0,0 +1,187 @@
+use super::Stack;
+use std::iter::Rev;
+use std::slice;
+
+/// A [`Stack`] that also reports its minimum and maximum in `O(1)`.
+///
+/// Every item remembers where the minimum and maximum of the stack were when
+/// it was pushed, so popping an item restores them without searching.
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::stack::{MinMaxStack, Stack};
+///
+/// let mut stack = MinMaxStack::new();
+/// stack.push(3);
+/// stack.push(1);
+/// stack.push(5);
+/// assert_eq!(stack.min(), Some(&1));
+/// assert_eq!(stack.max(), Some(&5));
+///
+/// stack.pop();
+/// stack.pop();
+/// assert_eq!(stack.min(), Some(&3));
+/// assert_eq!(stack.max(), Some(&3));
+/// ```
+pub struct MinMaxStack<T: Ord> {
+    items: Vec<Entry<T>>,
+}
+
+/// An item of the [`MinMaxStack`] with the positions of the minimum and
+/// maximum of the items below it, itself included.
+struct Entry<T> {
+    data: T,
+    min: usize,
+    max: usize,
+}
+
+/// Borrowing iterator over a [`MinMaxStack`], from top to bottom.
+pub struct Iter<'a, T> {
+    entries: Rev<slice::Iter<'a, Entry<T>>>,
+}
+
+impl<T: Ord> MinMaxStack<T> {
+    /// Creates a new empty `MinMaxStack`.
+    pub fn new() -> MinMaxStack<T> {
+        MinMaxStack { items: Vec::new() }
+    }
+
+    /// Retrieves a reference to the smallest item, if the stack is not empty.
+    ///
+    /// When several items are equal, the one pushed first is returned.
+    ///
+    /// # Complexity
+    /// - `O(1)`
+    pub fn min(&self) -> Option<&T> {
+        self.items.last().map(|top| &self.items[top.min].data)
+    }
+
+    /// Retrieves a reference to the largest item, if the stack is not empty.
+    ///
+    /// When several items are equal, the one pushed first is returned.
+    ///
+    /// # Complexity
+    /// - `O(1)`
+    pub fn max(&self) -> Option<&T> {
+        self.items.last().map(|top| &self.items[top.max].data)
+    }
+}
+
+impl<T: Ord> Stack<T> for MinMaxStack<T> {
+    type Iter<'a>
+        = Iter<'a, T>
+    where
+        T: 'a;
+
+    /// # Complexity
+    /// - `O(1)` amortized
+    fn push(&mut self, data: T) {
+        let position = self.items.len();
+        let (min, max) = match self.items.last() {
+            Some(top) => (
+                if data < self.items[top.min].data {
+                    position
+                } else {
+                    top.min
+                },
+                if data > self.items[top.max].data {
+                    position
+                } else {
+                    top.max
+                },
+            ),
+            None => (position, position),
+        };
+        self.items.push(Entry { data, min, max });
+    }
+
+    /// # Complexity
+    /// - `O(1)`
+    fn pop(&mut self) -> Option<T> {
+        self.items.pop().map(|entry| entry.data)
+    }
+
+    fn peek(&self) -> Option<&T> {
+        self.items.last().map(|entry| &entry.data)
+    }
+
+    fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            entries: self.items.iter().rev(),
+        }
+    }
+}
+
+impl<T: Ord> Default for MinMaxStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.entries.next().map(|entry| &entry.data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty() {
+        let mut stack: MinMaxStack<i32> = MinMaxStack::new();
+        assert!(stack.is_empty());
+        assert_eq!(stack.min(), None);
+        assert_eq!(stack.max(), None);
+        assert_eq!(stack.pop(), None);
+    }
+
+    #[test]
+    fn test_min_max_follow_pops() {
+        let mut stack = MinMaxStack::new();
+        let values = [5, 3, 8, 3, 1, 9, 2];
+        for value in values {
+            stack.push(value);
+        }
+
+        // Compare against a brute force scan at every level
+        for level in (1..=values.len()).rev() {
+            assert_eq!(stack.len(), level);
+            assert_eq!(stack.min(), values[..level].iter().min());
+            assert_eq!(stack.max(), values[..level].iter().max());
+            assert_eq!(stack.peek(), Some(&values[level - 1]));
+            stack.pop();
+        }
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn test_duplicates() {
+        let mut stack = MinMaxStack::new();
+        stack.push((1, 'a'));
+        stack.push((1, 'a'));
+        stack.pop();
+        assert_eq!(stack.min(), Some(&(1, 'a')));
+        assert_eq!(stack.max(), Some(&(1, 'a')));
+    }
+
+    #[test]
+    fn test_iter_top_to_bottom() {
+        let mut stack = MinMaxStack::new();
+        for value in ["b", "c", "a"] {
+            stack.push(value);
+        }
+        assert_eq!(
+            stack.iter().copied().collect::<Vec<_>>(),
+            vec!["a", "c", "b"]
+        );
+    }
+}
@@ -0,0 +1,71 @@
+/// A last-in, first-out collection.
+///
+/// Items are pushed on and popped from the top of the stack. Iteration goes
+/// from the top to the bottom, the same order in which `pop` would return
+/// the items.
+///
+/// The crate provides three implementations:
+/// - [`LinkedStack`]: built on [`crate::linked_list::ListNode`], every push allocates a node.
+/// - [`ArrayStack`]: built on an array that doubles its capacity when full.
+/// - [`MinMaxStack`]: also tracks its minimum and maximum in `O(1)`.
+///
+/// # Type Parameters
+/// - `T`: The type of elements stored in the stack.
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::stack::{ArrayStack, LinkedStack, Stack};
+///
+/// fn fill<S: Stack<i32>>(stack: &mut S) {
+///     for i in 1..=3 {
+///         stack.push(i);
+///     }
+/// }
+///
+/// let mut linked = LinkedStack::new();
+/// let mut array = ArrayStack::new();
+/// fill(&mut linked);
+/// fill(&mut array);
+///
+/// assert_eq!(linked.iter().collect::<Vec<_>>(), vec![&3, &2, &1]);
+/// assert_eq!(array.pop(), Some(3));
+/// assert_eq!(array.peek(), Some(&2));
+/// ```
+pub trait Stack<T> {
+    /// Iterator over the items of the stack, from top to bottom.
+    type Iter<'a>: Iterator<Item = &'a T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    /// Pushes an item on top of the stack.
+    fn push(&mut self, data: T);
+
+    /// Removes the item on top of the stack and returns it, or `None` if the
+    /// stack is empty.
+    fn pop(&mut self) -> Option<T>;
+
+    /// Retrieves a reference to the item on top of the stack, if it exists.
+    fn peek(&self) -> Option<&T>;
+
+    /// Gets the amount of items in the stack.
+    fn len(&self) -> usize;
+
+    /// Checks if the stack is empty.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over the items, from top to bottom.
+    fn iter(&self) -> Self::Iter<'_>;
+}
+
+pub mod array_stack;
+pub mod linked_stack;
+pub mod min_max_stack;
+pub mod monotonic;
+
+pub use array_stack::ArrayStack;
+pub use linked_stack::LinkedStack;
+pub use min_max_stack::MinMaxStack;
@@ -0,0 +1,197 @@
+//! Algorithms built on a monotonic stack.
+//!
+//! A monotonic stack keeps its items sorted from bottom to top: before
+//! pushing an item, every item that would break the order is popped. Each
+//! item is pushed and popped at most once, so every function here runs in
+//! `O(n)` time and `O(n)` extra space.
+
+use super::{ArrayStack, Stack};
+
+/// For every element, finds the index of the first element to its right that
+/// is strictly greater, or `None` if there is none.
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::stack::monotonic::next_greater;
+///
+/// assert_eq!(
+///     next_greater(&[4, 5, 2, 25]),
+///     vec![Some(1), Some(3), Some(3), None]
+/// );
+/// ```
+pub fn next_greater<T: Ord>(values: &[T]) -> Vec<Option<usize>> {
+    next_matching(values, |candidate, current| candidate > current)
+}
+
+/// For every element, finds the index of the first element to its right that
+/// is strictly smaller, or `None` if there is none.
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::stack::monotonic::next_smaller;
+///
+/// assert_eq!(
+///     next_smaller(&[4, 8, 5, 2, 25]),
+///     vec![Some(3), Some(2), Some(3), None, None]
+/// );
+/// ```
+pub fn next_smaller<T: Ord>(values: &[T]) -> Vec<Option<usize>> {
+    next_matching(values, |candidate, current| candidate < current)
+}
+
+/// Resolves, for every element, the first later element for which
+/// `found(later, element)` holds.
+///
+/// The stack holds the indices still waiting for an answer. An element
+/// answers every waiting index it matches; those are popped, which keeps the
+/// stack monotonic.
+fn next_matching<T, F>(values: &[T], found: F) -> Vec<Option<usize>>
+where
+    F: Fn(&T, &T) -> bool,
+{
+    let mut result = vec![None; values.len()];
+    let mut waiting: ArrayStack<usize> = ArrayStack::new();
+
+    for (index, value) in values.iter().enumerate() {
+        while let Some(&top) = waiting.peek() {
+            if !found(value, &values[top]) {
+                break;
+            }
+            result[top] = Some(index);
+            waiting.pop();
+        }
+        waiting.push(index);
+    }
+    result
+}
+
+/// Finds the area of the largest rectangle that fits under a histogram whose
+/// bars have width `1` and the given heights.
+///
+/// The stack holds bars of increasing height. When a lower bar arrives, every
+/// taller bar on the stack cannot extend further right, and the bar below it
+/// on the stack is the first lower bar to its left, so its widest rectangle
+/// is known.
+///
+/// An area too large for a `usize` saturates at `usize::MAX` instead of
+/// overflowing.
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::stack::monotonic::largest_rectangle;
+///
+/// assert_eq!(largest_rectangle(&[2, 1, 5, 6, 2, 3]), 10);
+/// assert_eq!(largest_rectangle(&[]), 0);
+/// ```
+pub fn largest_rectangle(heights: &[usize]) -> usize {
+    let mut bars: ArrayStack<usize> = ArrayStack::new();
+    let mut largest = 0;
+
+    // A final bar of height zero flushes the stack
+    for index in 0..=heights.len() {
+        let height = heights.get(index).copied().unwrap_or(0);
+        while let Some(&top) = bars.peek() {
+            if heights[top] <= height {
+                break;
+            }
+            bars.pop();
+            let left = bars.peek().map_or(0, |&below| below + 1);
+            largest = largest.max(heights[top].saturating_mul(index - left));
+        }
+        bars.push(index);
+    }
+    largest
+}
+
+/// Computes the stock span of every day: the amount of consecutive days,
+/// ending on that day, whose price is lower than or equal to its price.
+///
+/// # Examples
+///
+/// ```
+/// use villa01_data_structures::stack::monotonic::stock_span;
+///
+/// assert_eq!(
+///     stock_span(&[100, 80, 60, 70, 60, 75, 85]),
+///     vec![1, 1, 1, 2, 1, 4, 6]
+/// );
+/// ```
+pub fn stock_span<T: Ord>(prices: &[T]) -> Vec<usize> {
+    let mut spans = Vec::with_capacity(prices.len());
+    // Days with a strictly decreasing price, the candidates to stop a span
+    let mut higher: ArrayStack<usize> = ArrayStack::new();
+
+    for (day, price) in prices.iter().enumerate() {
+        while let Some(&top) = higher.peek() {
+            if prices[top] > *price {
+                break;
+            }
+            higher.pop();
+        }
+        spans.push(match higher.peek() {
+            Some(&previous) => day - previous,
+            None => day + 1,
+        });
+        higher.push(day);
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brute_next<T: Ord>(values: &[T], found: impl Fn(&T, &T) -> bool) -> Vec<Option<usize>> {
+        (0..values.len())
+            .map(|i| (i + 1..values.len()).find(|&j| found(&values[j], &values[i])))
+            .collect()
+    }
+
+    #[test]
+    fn test_next_greater_and_smaller() {
+        let values = [3, 7, 1, 7, 8, 4, 4, 2, 9, 0];
+        assert_eq!(next_greater(&values), brute_next(&values, |a, b| a > b));
+        assert_eq!(next_smaller(&values), brute_next(&values, |a, b| a < b));
+        assert!(next_greater::<i32>(&[]).is_empty());
+        assert_eq!(next_greater(&[1, 1, 1]), vec![None, None, None]);
+    }
+
+    #[test]
+    fn test_largest_rectangle() {
+        assert_eq!(largest_rectangle(&[6, 2, 5, 4, 5, 1, 6]), 12);
+        assert_eq!(largest_rectangle(&[1, 2, 3, 4, 5]), 9);
+        assert_eq!(largest_rectangle(&[5, 4, 3, 2, 1]), 9);
+        assert_eq!(largest_rectangle(&[3, 3, 3]), 9);
+        assert_eq!(largest_rectangle(&[0, 0]), 0);
+        assert_eq!(largest_rectangle(&[7]), 7);
+        assert_eq!(largest_rectangle(&[usize::MAX, usize::MAX]), usize::MAX);
+        assert_eq!(
+            largest_rectangle(&[usize::MAX / 2, usize::MAX / 2]),
+            usize::MAX - 1
+        );
+    }
+
+    #[test]
+    fn test_largest_rectangle_matches_brute_force() {
+        let heights = [2, 4, 2, 1, 10, 6, 10, 3, 3, 0, 5];
+        let mut expected = 0;
+        for i in 0..heights.len() {
+            let mut lowest = usize::MAX;
+            for (width, height) in heights[i..].iter().enumerate() {
+                lowest = lowest.min(*height);
+                expected = expected.max(lowest * (width + 1));
+            }
+        }
+        assert_eq!(largest_rectangle(&heights), expected);
+    }
+
+    #[test]
+    fn test_stock_span() {
+        assert_eq!(stock_span(&[10, 4, 5, 90, 120, 80]), vec![1, 1, 2, 4, 5, 1]);
+        assert_eq!(stock_span(&[5, 5, 5]), vec![1, 2, 3]);
+        assert!(stock_span::<u32>(&[]).is_empty());
+    }
+}